@@ -0,0 +1,62 @@
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use smooth_bevy_cameras::{LookTransform, LookTransformBundle, LookTransformPlugin, Smoother};
+
+use std::time::Duration;
+
+/// An app with `count` smoothed look transforms in a grid, all looking at the origin.
+fn app_with_cameras(count: usize) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, TransformPlugin, LookTransformPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )));
+    let side = (count as f32).sqrt().ceil() as usize;
+    for i in 0..count {
+        let eye = Vec3::new((i % side) as f32, 2.0, (i / side) as f32);
+        app.world_mut().spawn((
+            LookTransformBundle {
+                transform: LookTransform::new(eye, Vec3::ZERO, Vec3::Y),
+                smoother: Smoother::new(0.9),
+            },
+            Transform::default(),
+        ));
+    }
+    app.update();
+    app
+}
+
+/// Moves every target, so none of the cameras can go to sleep.
+fn move_targets(app: &mut App, frame: u32) {
+    let target = Vec3::new((frame as f32 * 0.1).sin(), 0.0, 0.0);
+    let mut query = app.world_mut().query::<&mut LookTransform>();
+    for mut transform in query.iter_mut(app.world_mut()) {
+        transform.target = target;
+    }
+}
+
+fn smoothing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("look_transform_system");
+    for count in [10, 100, 1000] {
+        group.bench_with_input(BenchmarkId::new("moving", count), &count, |b, &count| {
+            let mut app = app_with_cameras(count);
+            let mut frame = 0;
+            b.iter(|| {
+                frame += 1;
+                move_targets(&mut app, frame);
+                app.update();
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("asleep", count), &count, |b, &count| {
+            let mut app = app_with_cameras(count);
+            for _ in 0..10 {
+                app.update();
+            }
+            b.iter(|| app.update());
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, smoothing);
+criterion_main!(benches);
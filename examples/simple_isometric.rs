@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+use smooth_bevy_cameras::{
+    controllers::isometric::{
+        IsometricCameraBundle, IsometricCameraController, IsometricCameraPlugin,
+    },
+    LookTransformPlugin,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(LookTransformPlugin)
+        .add_plugins(IsometricCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+/// set up a simple 3D scene
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // circular base
+    commands.spawn((
+        Mesh3d(meshes.add(Circle::new(4.0))),
+        MeshMaterial3d(materials.add(Color::WHITE)),
+        Transform::from_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+    ));
+    // cube
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
+        MeshMaterial3d(materials.add(Color::srgb_u8(124, 144, 255))),
+        Transform::from_xyz(0.0, 0.5, 0.0),
+    ));
+    // light
+    commands.spawn((
+        PointLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(4.0, 8.0, 4.0),
+    ));
+
+    commands
+        .spawn(Camera3d::default())
+        .insert(IsometricCameraBundle::new(
+            IsometricCameraController::default(),
+            Vec3::ZERO,
+            10.0,
+        ));
+}
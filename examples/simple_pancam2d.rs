@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use smooth_bevy_cameras::{
+    controllers::pancam2d::{PanCam2dBundle, PanCam2dController, PanCam2dPlugin},
+    LookTransformPlugin,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(LookTransformPlugin)
+        .add_plugins(PanCam2dPlugin::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+/// set up a simple 2D scene
+fn setup(mut commands: Commands) {
+    // a grid of squares
+    for x in -5..=5_i32 {
+        for y in -5..=5 {
+            commands.spawn((
+                Sprite::from_color(
+                    Color::hsl(36.0 * (x + y).rem_euclid(10) as f32, 0.6, 0.6),
+                    Vec2::splat(40.0),
+                ),
+                Transform::from_xyz(60.0 * x as f32, 60.0 * y as f32, 0.0),
+            ));
+        }
+    }
+
+    commands.spawn(Camera2d).insert(PanCam2dBundle::new(
+        PanCam2dController {
+            bounds: Some(Rect::new(-300.0, -300.0, 300.0, 300.0)),
+            ..default()
+        },
+        Vec2::ZERO,
+    ));
+}
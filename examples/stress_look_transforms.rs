@@ -0,0 +1,92 @@
+//! Smooths 1,000 look transforms at once, like an array of security cameras that all track a moving subject. Run with
+//! `--release --features parallel` to smooth them on multiple threads.
+
+use bevy::{
+    diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin},
+    prelude::*,
+};
+use smooth_bevy_cameras::{LookTransform, LookTransformBundle, LookTransformPlugin, Smoother};
+
+const GRID_SIDE: usize = 32;
+const CAMERA_COUNT: usize = 1000;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins((FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin::default()))
+        .add_plugins(LookTransformPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, move_subject)
+        .run();
+}
+
+/// The entity that every security camera tracks.
+#[derive(Component)]
+struct Subject;
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // subject
+    commands.spawn((
+        Subject,
+        Mesh3d(meshes.add(Sphere::new(1.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.9, 0.3, 0.2))),
+    ));
+
+    // security cameras, drawn as cones pointing along their view
+    let cone = meshes.add(
+        Cone::new(0.2, 0.5)
+            .mesh()
+            .build()
+            .rotated_by(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+    );
+    let material = materials.add(Color::srgb(0.8, 0.8, 0.8));
+    for i in 0..CAMERA_COUNT {
+        let x = (i % GRID_SIDE) as f32 - 0.5 * GRID_SIDE as f32;
+        let z = (i / GRID_SIDE) as f32 - 0.5 * GRID_SIDE as f32;
+        commands.spawn((
+            LookTransformBundle {
+                transform: LookTransform::new(
+                    Vec3::new(2.0 * x, 6.0, 2.0 * z),
+                    Vec3::ZERO,
+                    Vec3::Y,
+                ),
+                smoother: Smoother::new(0.95),
+            },
+            Mesh3d(cone.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::default(),
+        ));
+    }
+
+    // light
+    commands.spawn((
+        DirectionalLight::default(),
+        Transform::from_xyz(4.0, 8.0, 4.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+
+    // the real camera, looking at the whole array
+    commands.spawn((
+        Camera3d::default(),
+        Transform::from_xyz(0.0, 60.0, 60.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+}
+
+/// Moves the subject around, and points every security camera at it, so none of them go to sleep.
+fn move_subject(
+    time: Res<Time>,
+    mut subject: Query<&mut Transform, With<Subject>>,
+    mut cameras: Query<&mut LookTransform>,
+) {
+    let t = time.elapsed_secs();
+    let position = Vec3::new(20.0 * (0.5 * t).sin(), 1.0, 20.0 * (0.3 * t).cos());
+    for mut transform in subject.iter_mut() {
+        transform.translation = position;
+    }
+    for mut look_transform in cameras.iter_mut() {
+        look_transform.target = position;
+    }
+}
@@ -0,0 +1,421 @@
+use crate::{
+    clock::CameraTime, controllers::AnyController, ease::Ease, LookTransform, LookTransformSet,
+    Smoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    render::camera::{CameraUpdateSystem, Projection},
+    transform::components::Transform,
+};
+
+/// Drives a render camera from "virtual" cameras with the [`CameraBlend`] resource, cutting or blending between them like
+/// a cutscene director.
+///
+/// Cameras can be switched by hand with the resource, or else by giving them [`VirtualCamera`] priorities.
+pub struct CameraBlendPlugin;
+
+impl Plugin for CameraBlendPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CameraBlend>().add_systems(
+            PostUpdate,
+            // After the virtual cameras are smoothed, and before the render camera is propagated and its projection is
+            // updated.
+            (virtual_camera_director_system, camera_blend_system)
+                .chain()
+                .after(LookTransformSet::Sync)
+                .before(LookTransformSet::Finish)
+                .before(CameraUpdateSystem),
+        );
+    }
+}
+
+/// Makes an entity a candidate for the [`CameraBlend`] render camera. The enabled virtual camera with the highest `priority`
+/// is blended to whenever it changes, so e.g. raising the priority of a cutscene camera takes over the view, and disabling
+/// it hands the view back.
+///
+/// Controllers on virtual cameras only get input while their camera is live. Like any virtual camera, the entity needs a
+/// `LookTransform`, and shouldn't have a `Camera`.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[require(Transform)]
+#[reflect(Component, Default, Debug)]
+pub struct VirtualCamera {
+    pub enabled: bool,
+    /// Higher priorities win. Ties are won by the camera that's already live.
+    pub priority: i32,
+    /// How long blending to this camera takes, in seconds. Zero cuts to it.
+    pub blend_duration: f32,
+    pub blend_easing: Ease,
+}
+
+impl Default for VirtualCamera {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            priority: 0,
+            blend_duration: 1.0,
+            blend_easing: Ease::default(),
+        }
+    }
+}
+
+impl VirtualCamera {
+    pub fn new(priority: i32) -> Self {
+        Self {
+            priority,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_blend(mut self, duration: f32, easing: Ease) -> Self {
+        self.blend_duration = duration;
+        self.blend_easing = easing;
+        self
+    }
+}
+
+/// A view that a blend starts from or arrives at.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct BlendView {
+    transform: LookTransform,
+    /// The perspective field of view, if the camera has one.
+    fov: Option<f32>,
+}
+
+impl BlendView {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            transform: self.transform.lerp(&other.transform, t),
+            fov: match (self.fov, other.fov) {
+                (Some(from), Some(to)) => Some(from + (to - from) * t),
+                (from, to) => to.or(from),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum BlendFrom {
+    /// A live camera, which keeps moving during the blend.
+    Camera(Entity),
+    /// Where an interrupted blend had gotten to.
+    Frozen(BlendView),
+}
+
+#[derive(Clone, Copy, Debug)]
+struct ActiveBlend {
+    from: BlendFrom,
+    easing: Ease,
+    elapsed: f32,
+    duration: f32,
+}
+
+/// Which virtual camera the `render_camera` shows, and the blend toward it in progress.
+///
+/// Virtual cameras are entities with a `LookTransform` and a `Transform`, and optionally a `Smoother`, a controller, and a
+/// `Projection`, but no `Camera`, so that they don't render themselves. The render camera copies the smoothed view of the
+/// live camera every frame, and during a blend, eases from the previous camera's view to it. Both cameras keep moving
+/// during the blend. When both have a perspective `Projection`, the field of view is blended too.
+///
+/// The render camera's own `LookTransform` is overwritten, so it shouldn't have a controller.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct CameraBlend {
+    pub render_camera: Option<Entity>,
+    live: Option<Entity>,
+    blend: Option<ActiveBlend>,
+    last_view: Option<BlendView>,
+}
+
+impl CameraBlend {
+    pub fn new(render_camera: Entity) -> Self {
+        Self {
+            render_camera: Some(render_camera),
+            ..Default::default()
+        }
+    }
+
+    /// The virtual camera being shown or blended to.
+    pub fn live_camera(&self) -> Option<Entity> {
+        self.live
+    }
+
+    pub fn is_blending(&self) -> bool {
+        self.blend.is_some()
+    }
+
+    /// How far through the current blend the render camera is, from `0.0` to `1.0`, before easing. `None` if it isn't
+    /// blending.
+    pub fn progress(&self) -> Option<f32> {
+        self.blend
+            .map(|blend| (blend.elapsed / blend.duration).clamp(0.0, 1.0))
+    }
+
+    /// Switches the render camera to `camera` immediately.
+    pub fn cut_to(&mut self, camera: Entity) {
+        self.live = Some(camera);
+        self.blend = None;
+    }
+
+    /// Blends the render camera from the current view to `camera` over `duration` seconds.
+    ///
+    /// Blending from the middle of another blend starts where that blend had gotten to. Without a live camera, or with a
+    /// `duration` of zero, this cuts instead.
+    pub fn blend_to(&mut self, camera: Entity, duration: f32, easing: Ease) {
+        let from = match (self.blend, self.live, self.last_view) {
+            (Some(_), _, Some(view)) => BlendFrom::Frozen(view),
+            (None, Some(live), _) if live != camera => BlendFrom::Camera(live),
+            _ => return self.cut_to(camera),
+        };
+        if duration <= 0.0 {
+            return self.cut_to(camera);
+        }
+        self.live = Some(camera);
+        self.blend = Some(ActiveBlend {
+            from,
+            easing,
+            elapsed: 0.0,
+            duration,
+        });
+    }
+}
+
+/// Blends to the [`VirtualCamera`] with the highest priority when it changes, and hands the input over to its controllers.
+pub fn virtual_camera_director_system(
+    mut blend: ResMut<CameraBlend>,
+    virtual_cameras: Query<(Entity, &VirtualCamera)>,
+    mut controllers: Query<(Entity, AnyController), With<VirtualCamera>>,
+) {
+    let live = blend.live_camera();
+    let Some((best, virtual_camera)) = virtual_cameras
+        .iter()
+        .filter(|(_, v)| v.enabled)
+        .max_by_key(|(entity, v)| (v.priority, Some(*entity) == live))
+    else {
+        return;
+    };
+    if Some(best) == live {
+        return;
+    }
+
+    blend.blend_to(
+        best,
+        virtual_camera.blend_duration,
+        virtual_camera.blend_easing,
+    );
+    for (entity, mut controllers) in controllers.iter_mut() {
+        controllers.set_enabled(entity == best);
+    }
+}
+
+pub fn camera_blend_system(
+    time: CameraTime,
+    mut blend: ResMut<CameraBlend>,
+    mut cameras: Query<(
+        &mut LookTransform,
+        Option<&mut Smoother>,
+        Option<&mut Projection>,
+    )>,
+    mut transforms: Query<&mut Transform>,
+) {
+    let (Some(render_camera), Some(live)) = (blend.render_camera, blend.live) else {
+        return;
+    };
+
+    let view_of = |entity: Entity| {
+        let (transform, smoother, projection) = cameras.get(entity).ok()?;
+        Some(BlendView {
+            // What the virtual camera would show, if it were rendered.
+            transform: smoother
+                .filter(|s| s.is_enabled())
+                .and_then(|s| s.smoothed())
+                .unwrap_or(*transform),
+            fov: match projection {
+                Some(Projection::Perspective(perspective)) => Some(perspective.fov),
+                _ => None,
+            },
+        })
+    };
+    let Some(to) = view_of(live) else {
+        return;
+    };
+
+    let view = match blend.blend {
+        Some(mut active) => {
+            active.elapsed += time.delta_secs(render_camera);
+            let from = match active.from {
+                BlendFrom::Camera(entity) => view_of(entity),
+                BlendFrom::Frozen(view) => Some(view),
+            };
+            let t = active.easing.sample(active.elapsed / active.duration);
+            blend.blend = (active.elapsed < active.duration).then_some(active);
+            // A camera that was despawned mid-blend is cut away from.
+            from.map_or(to, |from| from.lerp(&to, t))
+        }
+        None => to,
+    };
+    blend.last_view = Some(view);
+
+    let Ok((mut look_transform, smoother, projection)) = cameras.get_mut(render_camera) else {
+        return;
+    };
+    look_transform.set_if_neq(view.transform);
+    if let Some(mut smoother) = smoother {
+        // The virtual cameras are already smoothed.
+        smoother.reset();
+    }
+    // Only write on change, so a still view doesn't update the projection and propagate the transform every frame.
+    if let (Some(fov), Some(mut projection)) = (view.fov, projection) {
+        let stale = matches!(*projection, Projection::Perspective(ref p) if p.fov != fov);
+        if let (true, Projection::Perspective(perspective)) = (stale, projection.as_mut()) {
+            perspective.fov = fov;
+        }
+    }
+    if let Ok(mut transform) = transforms.get_mut(render_camera) {
+        transform.set_if_neq(view.transform.into());
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::{
+        math::Vec3,
+        time::{Real, Time},
+    };
+    use std::time::Duration;
+
+    fn test_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<Time<Real>>()
+            .add_systems(
+                Update,
+                (virtual_camera_director_system, camera_blend_system).chain(),
+            );
+        let render_camera = app
+            .world_mut()
+            .spawn((LookTransform::default(), Transform::default()))
+            .id();
+        app.insert_resource(CameraBlend::new(render_camera));
+        (app, render_camera)
+    }
+
+    fn spawn_virtual_camera(app: &mut App, eye: Vec3, virtual_camera: VirtualCamera) -> Entity {
+        let look_transform = LookTransform::new(eye, Vec3::ZERO, Vec3::Y);
+        app.world_mut().spawn((virtual_camera, look_transform)).id()
+    }
+
+    fn step(app: &mut App, secs: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(secs));
+        app.update();
+    }
+
+    fn eye(app: &App, camera: Entity) -> Vec3 {
+        app.world().get::<LookTransform>(camera).unwrap().eye
+    }
+
+    #[test]
+    fn test_cut_copies_the_view_once() {
+        let (mut app, render_camera) = test_app();
+        let camera = spawn_virtual_camera(
+            &mut app,
+            Vec3::new(0.0, 0.0, 10.0),
+            VirtualCamera::new(0).with_blend(0.0, Ease::Linear),
+        );
+        step(&mut app, 0.1);
+        assert_eq!(
+            app.world().resource::<CameraBlend>().live_camera(),
+            Some(camera)
+        );
+        assert_eq!(eye(&app, render_camera), Vec3::new(0.0, 0.0, 10.0));
+        let transform = app
+            .world()
+            .entity(render_camera)
+            .get_ref::<Transform>()
+            .unwrap();
+        assert_eq!(transform.translation, Vec3::new(0.0, 0.0, 10.0));
+        let last_changed = transform.last_changed();
+
+        step(&mut app, 0.1);
+        let transform = app
+            .world()
+            .entity(render_camera)
+            .get_ref::<Transform>()
+            .unwrap();
+        assert_eq!(transform.last_changed(), last_changed);
+    }
+
+    #[test]
+    fn test_blend_eases_to_the_new_camera() {
+        let (mut app, render_camera) = test_app();
+        spawn_virtual_camera(&mut app, Vec3::new(0.0, 0.0, 10.0), VirtualCamera::new(0));
+        step(&mut app, 0.1);
+        let to = spawn_virtual_camera(
+            &mut app,
+            Vec3::new(10.0, 0.0, 0.0),
+            VirtualCamera::new(1).with_blend(1.0, Ease::Linear),
+        );
+
+        step(&mut app, 0.5);
+        let blend = app.world().resource::<CameraBlend>();
+        assert_eq!(blend.live_camera(), Some(to));
+        assert_eq!(blend.progress(), Some(0.5));
+        assert!(eye(&app, render_camera).abs_diff_eq(Vec3::new(5.0, 0.0, 5.0), 1e-4));
+
+        step(&mut app, 0.6);
+        assert!(!app.world().resource::<CameraBlend>().is_blending());
+        assert_eq!(eye(&app, render_camera), Vec3::new(10.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_interrupted_blend_continues_from_where_it_got_to() {
+        let (mut app, render_camera) = test_app();
+        spawn_virtual_camera(&mut app, Vec3::new(0.0, 0.0, 10.0), VirtualCamera::new(0));
+        step(&mut app, 0.1);
+        let blend = VirtualCamera::new(0).with_blend(1.0, Ease::Linear);
+        spawn_virtual_camera(
+            &mut app,
+            Vec3::new(10.0, 0.0, 0.0),
+            VirtualCamera {
+                priority: 1,
+                ..blend
+            },
+        );
+        step(&mut app, 0.5);
+        let interrupted_at = eye(&app, render_camera);
+
+        let last = spawn_virtual_camera(
+            &mut app,
+            Vec3::new(-10.0, 0.0, 0.0),
+            VirtualCamera {
+                priority: 2,
+                ..blend
+            },
+        );
+        step(&mut app, 0.0);
+        // No jump back to either end of the interrupted blend.
+        assert_eq!(eye(&app, render_camera), interrupted_at);
+        assert_eq!(
+            app.world().resource::<CameraBlend>().live_camera(),
+            Some(last)
+        );
+
+        step(&mut app, 0.5);
+        let halfway = interrupted_at.lerp(Vec3::new(-10.0, 0.0, 0.0), 0.5);
+        assert!(eye(&app, render_camera).abs_diff_eq(halfway, 1e-4));
+        step(&mut app, 0.6);
+        assert_eq!(eye(&app, render_camera), Vec3::new(-10.0, 0.0, 0.0));
+    }
+}
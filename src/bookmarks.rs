@@ -0,0 +1,311 @@
+use crate::{
+    clock::CameraTime,
+    controllers::{
+        AnyController, AnyControllerItem, CameraControlMode, CameraControllerStatus,
+        CameraInputBlocked, ControllerKind,
+    },
+    ease::Ease,
+    LookTransform, LookTransformSet, Smoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    input::prelude::*,
+    render::camera::{Camera, Projection},
+    utils::HashMap,
+};
+
+/// Saves and recalls camera views with the [`CameraBookmarks`] resource.
+///
+/// Unless `override_input_system` is set, Ctrl + Shift + a number key saves the view to that slot, and Ctrl + the number
+/// recalls it.
+#[derive(Default)]
+pub struct CameraBookmarksPlugin {
+    pub override_input_system: bool,
+}
+
+impl CameraBookmarksPlugin {
+    pub fn new(override_input_system: bool) -> Self {
+        Self {
+            override_input_system,
+        }
+    }
+}
+
+impl Plugin for CameraBookmarksPlugin {
+    fn build(&self, app: &mut App) {
+        let app = app.init_resource::<CameraBookmarks>().add_systems(
+            PostUpdate,
+            // Before following and constraints, which still apply to a recalled view.
+            (bookmark_request_system, bookmark_transition_system)
+                .chain()
+                .before(LookTransformSet::Follow),
+        );
+
+        if !self.override_input_system {
+            app.add_systems(Update, default_input_map);
+        }
+    }
+}
+
+/// A saved view: the `LookTransform` and, if the camera had one, its `Projection`.
+#[derive(Clone, Debug)]
+pub struct CameraBookmark {
+    pub transform: LookTransform,
+    pub projection: Option<Projection>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BookmarkRequest {
+    Save(u32),
+    Recall(u32),
+}
+
+/// Numbered slots of saved camera views.
+///
+/// [`save_slot`](Self::save_slot) and [`recall_slot`](Self::recall_slot) take effect in `PostUpdate`, on the `camera` if
+/// set, or else on the camera of the [`CameraControllerStatus`], or else on the only camera with a `LookTransform`.
+///
+/// Recalling a view resets the camera's `Smoother` and [reseeds](crate::controllers::CameraController::reseed) its
+/// controllers, so the view doesn't lag behind or get pulled back by an unfinished zoom. With a `recall_duration`, the
+/// camera eases into the view instead of jumping, until it arrives or the controller gets some input. Controllers that
+/// own the projection's scale, like the 2D pan camera, keep their own zoom.
+#[derive(Resource, Clone, Debug)]
+pub struct CameraBookmarks {
+    pub camera: Option<Entity>,
+    /// How long recalling a view takes, in seconds. Zero jumps straight to it.
+    pub recall_duration: f32,
+    pub recall_easing: Ease,
+    slots: HashMap<u32, CameraBookmark>,
+    requests: Vec<BookmarkRequest>,
+}
+
+impl Default for CameraBookmarks {
+    fn default() -> Self {
+        Self {
+            camera: None,
+            recall_duration: 0.5,
+            recall_easing: Ease::default(),
+            slots: HashMap::default(),
+            requests: Vec::new(),
+        }
+    }
+}
+
+impl CameraBookmarks {
+    /// Saves the camera's current view to slot `n`, replacing any view saved there.
+    pub fn save_slot(&mut self, n: u32) {
+        self.requests.push(BookmarkRequest::Save(n));
+    }
+
+    /// Moves the camera to the view saved in slot `n`, if any.
+    pub fn recall_slot(&mut self, n: u32) {
+        self.requests.push(BookmarkRequest::Recall(n));
+    }
+
+    pub fn get(&self, n: u32) -> Option<&CameraBookmark> {
+        self.slots.get(&n)
+    }
+
+    /// Puts a view in slot `n` directly, e.g. one loaded from a project file.
+    pub fn insert(&mut self, n: u32, bookmark: CameraBookmark) -> Option<CameraBookmark> {
+        self.slots.insert(n, bookmark)
+    }
+
+    pub fn remove(&mut self, n: u32) -> Option<CameraBookmark> {
+        self.slots.remove(&n)
+    }
+
+    /// The saved views, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &CameraBookmark)> {
+        self.slots.iter().map(|(n, b)| (*n, b))
+    }
+}
+
+/// Eases a camera into a recalled [`CameraBookmark`]. It's removed when the camera arrives.
+#[derive(Component, Clone, Debug)]
+pub struct BookmarkTransition {
+    from: LookTransform,
+    to: LookTransform,
+    /// The perspective field of view to ease from and to.
+    fov: Option<(f32, f32)>,
+    elapsed: f32,
+    duration: f32,
+    easing: Ease,
+}
+
+impl BookmarkTransition {
+    /// The eased progress from `0.0` to `1.0`.
+    pub fn progress(&self) -> f32 {
+        self.easing.sample(self.elapsed / self.duration)
+    }
+}
+
+fn reseed_all(controllers: &mut AnyControllerItem, transform: &LookTransform) {
+    for kind in ControllerKind::BUILT_IN {
+        controllers.reseed(kind, transform);
+    }
+}
+
+pub fn default_input_map(
+    keys: Res<ButtonInput<KeyCode>>,
+    input_blocked: Option<Res<CameraInputBlocked>>,
+    mut bookmarks: ResMut<CameraBookmarks>,
+) {
+    if input_blocked.is_some_and(|b| b.is_blocked())
+        || !keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+    {
+        return;
+    }
+    let save = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    const DIGITS: [KeyCode; 10] = [
+        KeyCode::Digit0,
+        KeyCode::Digit1,
+        KeyCode::Digit2,
+        KeyCode::Digit3,
+        KeyCode::Digit4,
+        KeyCode::Digit5,
+        KeyCode::Digit6,
+        KeyCode::Digit7,
+        KeyCode::Digit8,
+        KeyCode::Digit9,
+    ];
+    for (n, key) in (0..).zip(DIGITS) {
+        if keys.just_pressed(key) {
+            if save {
+                bookmarks.save_slot(n);
+            } else {
+                bookmarks.recall_slot(n);
+            }
+        }
+    }
+}
+
+pub fn bookmark_request_system(
+    mut commands: Commands,
+    mut bookmarks: ResMut<CameraBookmarks>,
+    status: Option<Res<CameraControllerStatus>>,
+    mut cameras: Query<
+        (
+            Entity,
+            &mut LookTransform,
+            Option<&mut Projection>,
+            AnyController,
+        ),
+        With<Camera>,
+    >,
+    mut smoothers: Query<&mut Smoother>,
+) {
+    if bookmarks.requests.is_empty() {
+        return;
+    }
+    let requests = std::mem::take(&mut bookmarks.requests);
+
+    let camera = bookmarks
+        .camera
+        .or_else(|| status.and_then(|s| s.entity))
+        .or_else(|| cameras.get_single().ok().map(|(entity, ..)| entity));
+    let Some(Ok((entity, mut transform, mut projection, mut controllers))) =
+        camera.map(|e| cameras.get_mut(e))
+    else {
+        return;
+    };
+
+    for request in requests {
+        match request {
+            BookmarkRequest::Save(n) => {
+                let bookmark = CameraBookmark {
+                    transform: *transform,
+                    projection: projection.as_deref().cloned(),
+                };
+                bookmarks.slots.insert(n, bookmark);
+            }
+            BookmarkRequest::Recall(n) => {
+                let Some(bookmark) = bookmarks.slots.get(&n) else {
+                    continue;
+                };
+
+                // Only a change of FOV can be eased, anything else switches right away.
+                let fov = match (projection.as_deref_mut(), &bookmark.projection) {
+                    (Some(Projection::Perspective(from)), Some(Projection::Perspective(to)))
+                        if bookmarks.recall_duration > 0.0 =>
+                    {
+                        let fov = (from.fov, to.fov);
+                        *from = to.clone();
+                        from.fov = fov.0;
+                        Some(fov)
+                    }
+                    (Some(projection), Some(to)) => {
+                        *projection = to.clone();
+                        None
+                    }
+                    _ => None,
+                };
+
+                if bookmarks.recall_duration > 0.0 {
+                    commands.entity(entity).insert(BookmarkTransition {
+                        from: *transform,
+                        to: bookmark.transform,
+                        fov,
+                        elapsed: 0.0,
+                        duration: bookmarks.recall_duration,
+                        easing: bookmarks.recall_easing,
+                    });
+                } else {
+                    commands.entity(entity).remove::<BookmarkTransition>();
+                    *transform = bookmark.transform;
+                }
+                reseed_all(&mut controllers, &bookmark.transform);
+                if let Ok(mut smoother) = smoothers.get_mut(entity) {
+                    smoother.reset();
+                }
+            }
+        }
+    }
+}
+
+pub fn bookmark_transition_system(
+    mut commands: Commands,
+    time: CameraTime,
+    status: Option<Res<CameraControllerStatus>>,
+    mut cameras: Query<(
+        Entity,
+        &mut BookmarkTransition,
+        &mut LookTransform,
+        Option<&mut Projection>,
+        AnyController,
+    )>,
+    mut smoothers: Query<&mut Smoother>,
+) {
+    for (entity, mut transition, mut transform, projection, mut controllers) in cameras.iter_mut() {
+        // Let the user take over in the middle of the transition.
+        let interrupted = status
+            .as_ref()
+            .is_some_and(|s| s.entity == Some(entity) && s.mode != CameraControlMode::Idle);
+
+        transition.elapsed += time.delta_secs(entity);
+        let done = transition.elapsed >= transition.duration;
+        let t = if done { 1.0 } else { transition.progress() };
+
+        if !interrupted {
+            *transform = transition.from.lerp(&transition.to, t);
+            // Follow the transition exactly instead of lagging behind it.
+            if let Ok(mut smoother) = smoothers.get_mut(entity) {
+                smoother.reset();
+            }
+        }
+        if let (Some((from_fov, to_fov)), Some(mut projection)) = (transition.fov, projection) {
+            if let Projection::Perspective(perspective) = projection.as_mut() {
+                let t = if interrupted { 1.0 } else { t };
+                perspective.fov = from_fov + (to_fov - from_fov) * t;
+            }
+        }
+
+        if done || interrupted {
+            reseed_all(&mut controllers, &transform);
+            commands.entity(entity).remove::<BookmarkTransition>();
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use bevy::{
+    ecs::{prelude::*, system::SystemParam},
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    time::{Real, Time},
+};
+
+/// The clock that moves a camera's controller and smoothing.
+///
+/// By default, cameras follow the schedule's `Time`, which is `Time<Virtual>` outside of the fixed schedules, so pausing or
+/// slowing it down freezes or slows the camera too. Add `CameraClock::Real` to a camera to keep it responsive during a
+/// pause menu, photo mode, or bullet time.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug, PartialEq, Hash)]
+pub enum CameraClock {
+    #[default]
+    Virtual,
+    Real,
+}
+
+impl CameraClock {
+    /// The seconds since the last frame on this clock, given the schedule's `time` and the `real_time`.
+    pub fn delta_secs(&self, time: &Time, real_time: &Time<Real>) -> f32 {
+        match self {
+            Self::Virtual => time.delta_secs(),
+            Self::Real => real_time.delta_secs(),
+        }
+    }
+}
+
+/// The frame time of each camera, by its [`CameraClock`].
+#[derive(SystemParam)]
+pub struct CameraTime<'w, 's> {
+    time: Res<'w, Time>,
+    real_time: Res<'w, Time<Real>>,
+    clocks: Query<'w, 's, &'static CameraClock>,
+}
+
+impl CameraTime<'_, '_> {
+    /// The seconds since the last frame on `camera`'s clock.
+    pub fn delta_secs(&self, camera: Entity) -> f32 {
+        let clock = self.clocks.get(camera).copied().unwrap_or_default();
+        clock.delta_secs(&self.time, &self.real_time)
+    }
+
+    /// Like [`delta_secs`](Self::delta_secs), but on the schedule's `Time` without a camera.
+    pub fn delta_secs_or_default(&self, camera: Option<Entity>) -> f32 {
+        camera.map_or_else(|| self.time.delta_secs(), |camera| self.delta_secs(camera))
+    }
+}
@@ -0,0 +1,427 @@
+use crate::{LookAngles, LookTransform};
+
+use bevy::{
+    ecs::prelude::*,
+    math::{cubic_splines::CubicCurve, prelude::*},
+};
+
+use std::fmt;
+
+/// A rule that a `LookTransform` must satisfy, like a minimum distance between the eye and target.
+///
+/// Constraints in a [`Constraints`] component are applied in [`LookTransformSet::Constrain`](crate::LookTransformSet), after
+/// controllers and entity bindings have written the `LookTransform`, and before it is smoothed.
+pub trait LookTransformConstraint: Send + Sync + 'static {
+    /// Moves `transform` the least it can to satisfy the constraint.
+    fn apply(&self, transform: &mut LookTransform);
+}
+
+/// The constraints of a camera, applied in order.
+#[derive(Component, Default)]
+pub struct Constraints(pub Vec<Box<dyn LookTransformConstraint>>);
+
+impl fmt::Debug for Constraints {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Constraints")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
+
+impl Constraints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, constraint: impl LookTransformConstraint) -> Self {
+        self.push(constraint);
+        self
+    }
+
+    pub fn push(&mut self, constraint: impl LookTransformConstraint) {
+        self.0.push(Box::new(constraint));
+    }
+}
+
+pub fn constraint_system(mut cameras: Query<(&Constraints, &mut LookTransform)>) {
+    for (constraints, mut transform) in cameras.iter_mut() {
+        let mut constrained = *transform;
+        for constraint in constraints.0.iter() {
+            constraint.apply(&mut constrained);
+        }
+        // Only write on change, so idle cameras can stay asleep.
+        if constrained != *transform {
+            *transform = constrained;
+        }
+    }
+}
+
+/// Which end of a `LookTransform` stays put when a constraint rotates or stretches it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ConstraintAnchor {
+    /// The eye moves, like for an orbit camera.
+    #[default]
+    Target,
+    /// The target moves, like for a first person camera.
+    Eye,
+}
+
+impl ConstraintAnchor {
+    fn place(self, transform: &mut LookTransform, look_direction: Vec3, radius: f32) {
+        match self {
+            Self::Target => transform.eye = transform.target - radius * look_direction,
+            Self::Eye => transform.target = transform.eye + radius * look_direction,
+        }
+    }
+}
+
+/// Limits the angle of the look direction above the horizontal, in radians. Negative angles look down.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PitchLimits {
+    pub min: f32,
+    pub max: f32,
+    pub anchor: ConstraintAnchor,
+}
+
+impl LookTransformConstraint for PitchLimits {
+    fn apply(&self, transform: &mut LookTransform) {
+        let Some(look_direction) = transform.look_direction() else {
+            return;
+        };
+        let mut angles = LookAngles::from_vector(look_direction);
+        let pitch = angles.get_pitch();
+        let clamped = pitch.clamp(self.min, self.max);
+        if clamped == pitch {
+            return;
+        }
+        angles.set_pitch(clamped);
+        self.anchor
+            .place(transform, angles.unit_vector(), transform.radius());
+    }
+}
+
+/// Limits the distance between the eye and the target.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RadiusLimits {
+    pub min: f32,
+    pub max: f32,
+    pub anchor: ConstraintAnchor,
+}
+
+impl LookTransformConstraint for RadiusLimits {
+    fn apply(&self, transform: &mut LookTransform) {
+        let Some(look_direction) = transform.look_direction() else {
+            return;
+        };
+        let radius = transform.radius();
+        let clamped = radius.clamp(self.min, self.max);
+        if clamped != radius {
+            self.anchor.place(transform, look_direction, clamped);
+        }
+    }
+}
+
+/// Keeps both the eye and the target inside of an axis-aligned box.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bounds {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl LookTransformConstraint for Bounds {
+    fn apply(&self, transform: &mut LookTransform) {
+        transform.eye = transform.eye.clamp(self.min, self.max);
+        transform.target = transform.target.clamp(self.min, self.max);
+    }
+}
+
+/// Keeps the eye at or above a horizontal plane at `height`, like the ground or water surface.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AboveGround {
+    pub height: f32,
+}
+
+impl LookTransformConstraint for AboveGround {
+    fn apply(&self, transform: &mut LookTransform) {
+        transform.eye.y = transform.eye.y.max(self.height);
+    }
+}
+
+/// A curve or surface that an [`EyeOnPath`] keeps the eye on.
+///
+/// This is implemented for closures like `|point: Vec3| surface.closest_point(point)`.
+pub trait EyePath: Send + Sync + 'static {
+    /// The point on the path closest to `point`.
+    fn closest_point(&self, point: Vec3) -> Vec3;
+}
+
+impl<F: Fn(Vec3) -> Vec3 + Send + Sync + 'static> EyePath for F {
+    fn closest_point(&self, point: Vec3) -> Vec3 {
+        self(point)
+    }
+}
+
+/// A horizontal circle, like a track around a stadium.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HorizontalCircle {
+    pub center: Vec3,
+    pub radius: f32,
+}
+
+impl EyePath for HorizontalCircle {
+    fn closest_point(&self, point: Vec3) -> Vec3 {
+        let offset = (point - self.center).with_y(0.0);
+        // The whole circle is equally close to its center, so any point will do.
+        let direction = offset.try_normalize().unwrap_or(Vec3::X);
+        self.center + self.radius * direction
+    }
+}
+
+/// Straight segments through `points`, like a path along a corridor.
+///
+/// Use [`Polyline::from_curve`] to follow a spline.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Polyline {
+    pub points: Vec<Vec3>,
+    /// Whether the last point connects back to the first.
+    pub closed: bool,
+}
+
+impl Polyline {
+    pub fn new(points: impl IntoIterator<Item = Vec3>) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+            closed: false,
+        }
+    }
+
+    /// Samples `curve` with `subdivisions` segments per curve segment.
+    pub fn from_curve(curve: &CubicCurve<Vec3>, subdivisions: usize) -> Self {
+        Self::new(curve.iter_positions(subdivisions))
+    }
+
+    pub fn closed(mut self) -> Self {
+        self.closed = true;
+        self
+    }
+
+    fn segments(&self) -> impl Iterator<Item = (Vec3, Vec3)> + '_ {
+        let closing = self
+            .closed
+            .then(|| Some((*self.points.last()?, *self.points.first()?)))
+            .flatten();
+        self.points.windows(2).map(|w| (w[0], w[1])).chain(closing)
+    }
+}
+
+impl EyePath for Polyline {
+    fn closest_point(&self, point: Vec3) -> Vec3 {
+        let Some(&first) = self.points.first() else {
+            return point;
+        };
+        self.segments()
+            .map(|(start, end)| {
+                let segment = end - start;
+                let t = (point - start).dot(segment) / segment.length_squared().max(f32::EPSILON);
+                start + t.clamp(0.0, 1.0) * segment
+            })
+            .min_by(|a, b| {
+                a.distance_squared(point)
+                    .total_cmp(&b.distance_squared(point))
+            })
+            .unwrap_or(first)
+    }
+}
+
+/// Keeps the eye on an [`EyePath`], while the target stays free, for guided tours and broadcast-style cameras.
+///
+/// Whatever eye a controller asks for is moved to the closest point on the path, so motion along the path is kept and
+/// motion away from it is dropped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EyeOnPath<P>(pub P);
+
+impl<P: EyePath> LookTransformConstraint for EyeOnPath<P> {
+    fn apply(&self, transform: &mut LookTransform) {
+        transform.eye = self.0.closest_point(transform.eye);
+    }
+}
+
+/// Samples the height of the ground at a point on the XZ plane, for a [`HeightClamp`].
+///
+/// This is implemented for closures like `|x: f32, z: f32| terrain.height_at(x, z)`.
+pub trait HeightSampler: Send + Sync + 'static {
+    fn height(&self, x: f32, z: f32) -> f32;
+}
+
+impl<F: Fn(f32, f32) -> f32 + Send + Sync + 'static> HeightSampler for F {
+    fn height(&self, x: f32, z: f32) -> f32 {
+        self(x, z)
+    }
+}
+
+/// A grid of heights on the XZ plane, sampled with bilinear interpolation. Points outside of the grid use the nearest edge.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HeightField {
+    /// The XZ position of the first sample.
+    pub origin: Vec2,
+    /// The distance between neighboring samples.
+    pub cell_size: f32,
+    /// The number of samples along X and Z.
+    pub dimensions: UVec2,
+    /// The samples in row-major order, with X varying fastest.
+    pub heights: Vec<f32>,
+}
+
+impl HeightField {
+    fn sample(&self, x: u32, z: u32) -> f32 {
+        self.heights[(z * self.dimensions.x + x) as usize]
+    }
+}
+
+impl HeightSampler for HeightField {
+    fn height(&self, x: f32, z: f32) -> f32 {
+        if self.dimensions.cmpeq(UVec2::ZERO).any() {
+            return f32::NEG_INFINITY;
+        }
+        let max = (self.dimensions - 1).as_vec2();
+        let grid = ((Vec2::new(x, z) - self.origin) / self.cell_size).clamp(Vec2::ZERO, max);
+        let low = grid.floor().as_uvec2();
+        let high = grid.ceil().as_uvec2();
+        let t = grid - low.as_vec2();
+
+        let near = self
+            .sample(low.x, low.y)
+            .lerp(self.sample(high.x, low.y), t.x);
+        let far = self
+            .sample(low.x, high.y)
+            .lerp(self.sample(high.x, high.y), t.x);
+        near.lerp(far, t.y)
+    }
+}
+
+/// Keeps the eye at least `min_height` above the ground given by a [`HeightSampler`], so free flying cameras can't dive
+/// into terrain.
+///
+/// Like the other constraints, this is applied in `LookTransformSet::Constrain`. It can also be added to [`Constraints`]
+/// to control the order.
+#[derive(Component)]
+pub struct HeightClamp {
+    pub min_height: f32,
+    pub sampler: Box<dyn HeightSampler>,
+}
+
+impl HeightClamp {
+    pub fn new(min_height: f32, sampler: impl HeightSampler) -> Self {
+        Self {
+            min_height,
+            sampler: Box::new(sampler),
+        }
+    }
+}
+
+impl LookTransformConstraint for HeightClamp {
+    fn apply(&self, transform: &mut LookTransform) {
+        let ground = self.sampler.height(transform.eye.x, transform.eye.z);
+        transform.eye.y = transform.eye.y.max(ground + self.min_height);
+    }
+}
+
+pub fn height_clamp_system(mut cameras: Query<(&HeightClamp, &mut LookTransform)>) {
+    for (clamp, mut transform) in cameras.iter_mut() {
+        let mut constrained = *transform;
+        clamp.apply(&mut constrained);
+        if constrained != *transform {
+            *transform = constrained;
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_pitch_limits_keep_anchor_and_radius() {
+        let mut transform = LookTransform::new(Vec3::new(0.0, 5.0, 0.1), Vec3::ZERO, Vec3::Y);
+        let radius = transform.radius();
+        let limits = PitchLimits {
+            min: -1.0,
+            max: 1.0,
+            anchor: ConstraintAnchor::Target,
+        };
+        limits.apply(&mut transform);
+
+        assert_eq!(transform.target, Vec3::ZERO);
+        assert_relative_eq!(transform.radius(), radius, epsilon = 1e-5);
+        let pitch = LookAngles::from_vector(transform.look_direction().unwrap()).get_pitch();
+        assert_relative_eq!(pitch, -1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_radius_limits_move_eye() {
+        let mut transform = LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y);
+        let limits = RadiusLimits {
+            min: 1.0,
+            max: 4.0,
+            anchor: ConstraintAnchor::Target,
+        };
+        limits.apply(&mut transform);
+        assert_relative_eq!(transform.eye.z, 4.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_eye_on_path_keeps_target() {
+        let path = Polyline::new([
+            Vec3::ZERO,
+            Vec3::new(10.0, 0.0, 0.0),
+            Vec3::new(10.0, 0.0, 10.0),
+        ]);
+        let mut transform =
+            LookTransform::new(Vec3::new(4.0, 2.0, -3.0), Vec3::new(5.0, 0.0, 5.0), Vec3::Y);
+        EyeOnPath(path.clone()).apply(&mut transform);
+        assert_eq!(transform.eye, Vec3::new(4.0, 0.0, 0.0));
+        assert_eq!(transform.target, Vec3::new(5.0, 0.0, 5.0));
+
+        assert_eq!(
+            path.closest_point(Vec3::new(12.0, 1.0, 7.0)),
+            Vec3::new(10.0, 0.0, 7.0)
+        );
+        assert_eq!(
+            path.closed().closest_point(Vec3::new(2.0, 0.0, 3.0)),
+            Vec3::new(2.5, 0.0, 2.5)
+        );
+
+        let circle = HorizontalCircle {
+            center: Vec3::Y,
+            radius: 5.0,
+        };
+        assert!(circle
+            .closest_point(Vec3::new(0.0, 3.0, -2.0))
+            .abs_diff_eq(Vec3::new(0.0, 1.0, -5.0), 1e-6));
+    }
+
+    #[test]
+    fn test_height_field_interpolates_and_clamps_to_edges() {
+        let field = HeightField {
+            origin: Vec2::ZERO,
+            cell_size: 2.0,
+            dimensions: UVec2::new(2, 2),
+            heights: vec![0.0, 1.0, 2.0, 3.0],
+        };
+        assert_relative_eq!(field.height(1.0, 1.0), 1.5);
+        assert_relative_eq!(field.height(-5.0, 10.0), 2.0);
+
+        let mut transform = LookTransform::new(Vec3::new(1.0, 0.0, 1.0), Vec3::X, Vec3::Y);
+        HeightClamp::new(0.5, field).apply(&mut transform);
+        assert_relative_eq!(transform.eye.y, 2.0);
+    }
+}
@@ -1,17 +1,1273 @@
 #[macro_use]
 mod macros {
+    /// Defines an `on_controller_enabled_changed` system for a [`CameraController`](crate::controllers::CameraController).
+    /// [`controller_plugin`](crate::controllers::controller_plugin) adds the same system without the macro.
     #[macro_export]
     macro_rules! define_on_controller_enabled_changed(($ControllerStruct:ty) => {
         fn on_controller_enabled_changed(
-            mut smoothers: Query<(&mut Smoother, &$ControllerStruct), Changed<$ControllerStruct>>,
+            smoothers: Query<
+                (
+                    &mut $crate::Smoother,
+                    &$ControllerStruct,
+                    Option<&$crate::controllers::ActiveController>,
+                ),
+                Changed<$ControllerStruct>,
+            >,
         ) {
-            for (mut smoother, controller) in smoothers.iter_mut() {
-                smoother.set_enabled(controller.enabled);
-            }
+            $crate::controllers::on_controller_enabled_changed::<$ControllerStruct>(smoothers);
         }
     });
 }
 
+pub mod edge_pan;
 pub mod fps;
+#[cfg(feature = "gyro")]
+pub mod gyro;
+pub mod isometric;
+pub mod keyboard;
+pub mod maya;
 pub mod orbit;
+pub mod pancam2d;
 pub mod unreal;
+
+use crate::{
+    clock::CameraClock, recording::ControlEventReplayActive, LookTransform, LookTransformSet,
+    Smoother,
+};
+
+use keyboard::KeyboardNavigation;
+
+use bevy::{
+    app::prelude::*,
+    ecs::{
+        prelude::*,
+        query::QueryData,
+        system::{EntityCommands, SystemParam},
+    },
+    input::{
+        mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+        prelude::*,
+        InputSystem,
+    },
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    render::camera::{Camera, NormalizedRenderTarget, PerspectiveProjection, Projection},
+    time::{Real, Time},
+    transform::components::Transform,
+    window::{PrimaryWindow, Window, WindowFocused, WindowRef},
+};
+
+use std::{f32::consts::PI, marker::PhantomData};
+
+/// Implemented by the built-in controller components, and by custom controllers that want the same plumbing.
+///
+/// A controller is driven by its `Event`s, which an input map sends and a control system applies to the `LookTransform` of
+/// the enabled camera, along with any [`TargetedControlEvent`]s for specific cameras. [`controller_plugin`] registers both
+/// events and keeps the `Smoother` in step with `enabled`, so a custom controller's plugin only needs to add those two
+/// systems. [`ControlEventBatches`] sorts the events out by camera.
+///
+/// # Control systems
+///
+/// Each built-in controller's `control_system` hands the events for a camera to the `apply_events` function of its module,
+/// which is the math of the control system without the ECS. That way custom input maps can be unit tested, and scripts or
+/// replays can drive a controller without an `App`. The control system then only writes the `LookTransform` if
+/// `apply_events` changed it, so an idle camera's `Smoother` can go to sleep.
+pub trait CameraController: Component + Copy {
+    /// Which controller this is, for [`ActiveController`]. Custom controllers use [`ControllerKind::Custom`].
+    const KIND: ControllerKind;
+
+    /// The events that the controller's control system applies.
+    ///
+    /// Variants carry the amounts to move by in one frame, in the controller's own units, with sensitivities already
+    /// applied, so any input source can send them. The constructors turn raw device input into events using the
+    /// controller's sensitivities, for custom input maps.
+    type Event: Event + Clone;
+
+    /// Whether the controller takes input. Disabled controllers also don't smooth their camera.
+    fn enabled(&self) -> bool;
+
+    /// The lag weight of the `Smoother` that the controller's bundle is created with.
+    fn smoothing_weight(&self) -> f32;
+
+    /// Drops any state the controller carries between frames, like an unfinished zoom, so it continues from `transform`
+    /// without a jump. Called when the controller becomes the [`ActiveController`].
+    fn reseed(&mut self, _transform: &LookTransform) {}
+}
+
+/// Identifies a built-in controller type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Debug, PartialEq, Hash)]
+pub enum ControllerKind {
+    Fps,
+    Isometric,
+    Maya,
+    Orbit,
+    PanCam2d,
+    Unreal,
+    /// A controller from outside of this crate, with an ID that is unique among the custom controllers of an app.
+    Custom(u32),
+}
+
+impl ControllerKind {
+    /// Every controller in this crate.
+    pub const BUILT_IN: [Self; 6] = [
+        Self::Fps,
+        Self::Isometric,
+        Self::Maya,
+        Self::Orbit,
+        Self::PanCam2d,
+        Self::Unreal,
+    ];
+}
+
+/// Add this to a camera with more than one controller to choose which of them is in control.
+///
+/// Only the active controller is ever enabled, so they don't fight over the `LookTransform`. When this changes, the newly
+/// active controller is [reseeded](CameraController::reseed) and takes over the `Smoother` lag weight, and the camera stays
+/// enabled or disabled as it was.
+#[derive(Clone, Component, Copy, Debug, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Debug, PartialEq)]
+pub struct ActiveController(pub ControllerKind);
+
+/// Assembles the components of a controlled camera, as an alternative to the fixed arguments of a bundle's `new`. Create
+/// one with a bundle's `builder`, like [`orbit::OrbitCameraBundle::builder`].
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use smooth_bevy_cameras::{controllers::orbit::OrbitCameraBundle, Smoother};
+/// fn spawn_camera(mut commands: Commands) {
+///     OrbitCameraBundle::builder()
+///         .eye(Vec3::new(0.0, 2.0, 5.0))
+///         .smoother(Smoother::new(0.95))
+///         .with(Name::new("Editor Camera"))
+///         .insert_into(&mut commands.spawn(Camera3d::default()));
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CameraBundleBuilder<C, B = ()> {
+    controller: C,
+    eye: Vec3,
+    target: Vec3,
+    up: Vec3,
+    smoother: Option<Smoother>,
+    projection: Option<Projection>,
+    insert_transform: bool,
+    extra: B,
+}
+
+impl<C: CameraController> CameraBundleBuilder<C> {
+    pub fn new(controller: C, eye: Vec3, target: Vec3, up: Vec3) -> Self {
+        Self {
+            controller,
+            eye,
+            target,
+            up,
+            smoother: None,
+            projection: None,
+            insert_transform: true,
+            extra: (),
+        }
+    }
+}
+
+impl<C: CameraController, B: Bundle> CameraBundleBuilder<C, B> {
+    pub fn controller(mut self, controller: C) -> Self {
+        self.controller = controller;
+        self
+    }
+
+    pub fn eye(mut self, eye: Vec3) -> Self {
+        self.eye = eye;
+        self
+    }
+
+    pub fn target(mut self, target: Vec3) -> Self {
+        self.target = target;
+        self
+    }
+
+    pub fn up(mut self, up: Vec3) -> Self {
+        self.up = up;
+        self
+    }
+
+    /// Overrides the `Smoother` that is otherwise created from the controller's smoothing weight.
+    pub fn smoother(mut self, smoother: Smoother) -> Self {
+        self.smoother = Some(smoother);
+        self
+    }
+
+    pub fn projection(mut self, projection: impl Into<Projection>) -> Self {
+        self.projection = Some(projection.into());
+        self
+    }
+
+    /// Leaves the entity's `Transform` alone, instead of making it consistent with the `LookTransform` to start.
+    pub fn without_transform(mut self) -> Self {
+        self.insert_transform = false;
+        self
+    }
+
+    /// Inserts `extra` along with the camera components.
+    pub fn with<E: Bundle>(self, extra: E) -> CameraBundleBuilder<C, (B, E)> {
+        CameraBundleBuilder {
+            controller: self.controller,
+            eye: self.eye,
+            target: self.target,
+            up: self.up,
+            smoother: self.smoother,
+            projection: self.projection,
+            insert_transform: self.insert_transform,
+            extra: (self.extra, extra),
+        }
+    }
+
+    pub fn insert_into(self, entity: &mut EntityCommands) {
+        let look_transform = LookTransform::new(self.eye, self.target, self.up);
+        let smoother = self
+            .smoother
+            .unwrap_or_else(|| Smoother::new(self.controller.smoothing_weight()));
+        entity.insert((self.controller, look_transform, smoother, self.extra));
+
+        if self.insert_transform {
+            // The eye and target can coincide when a followed target is not known yet.
+            let transform = match look_transform.look_direction() {
+                Some(_) => Transform::from(look_transform),
+                None => Transform::from_translation(self.eye),
+            };
+            entity.insert(transform);
+        }
+        if let Some(projection) = self.projection {
+            entity.insert(projection);
+        }
+    }
+}
+
+/// Registers the [`CameraController::Event`] of `C` and a system that enables or disables the `Smoother` of each camera
+/// along with its controller, plus everything shared by all controllers. Every controller plugin adds this first.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use smooth_bevy_cameras::controllers::{controller_plugin, orbit::OrbitCameraController};
+/// App::new().add_plugins(controller_plugin::<OrbitCameraController>());
+/// ```
+pub fn controller_plugin<C: CameraController>() -> ControllerPlugin<C> {
+    ControllerPlugin {
+        marker: PhantomData,
+    }
+}
+
+/// See [`controller_plugin`].
+pub struct ControllerPlugin<C> {
+    marker: PhantomData<fn() -> C>,
+}
+
+impl<C: CameraController> Plugin for ControllerPlugin<C> {
+    fn build(&self, app: &mut App) {
+        build_shared(app);
+        app.add_event::<C::Event>()
+            .add_event::<TargetedControlEvent<C::Event>>()
+            .add_systems(
+                PreUpdate,
+                (
+                    on_controller_enabled_changed::<C>.after(camera_control_event_system),
+                    drop_events_on_focus_lost::<C>.after(InputSystem),
+                ),
+            );
+    }
+}
+
+/// A controller event for a specific `camera`, e.g. from scripted sequences or editor tooling.
+///
+/// Plain controller events go to whichever camera has the input this frame, so they can end up on another camera, or
+/// nowhere, while controllers are being switched. These are always applied to `camera`, even when its controller is
+/// disabled or input is blocked, since they don't come from the user.
+#[derive(Clone, Copy, Debug, Event, PartialEq)]
+pub struct TargetedControlEvent<E: Event> {
+    pub camera: Entity,
+    pub event: E,
+}
+
+impl<E: Event> TargetedControlEvent<E> {
+    pub fn new(camera: Entity, event: E) -> Self {
+        Self { camera, event }
+    }
+}
+
+/// One frame of a controller's events, sorted out by camera: the plain events go to the camera with the input, and each
+/// [`TargetedControlEvent`] to its own camera.
+pub struct ControlEventBatches<E> {
+    input_camera: Option<Entity>,
+    batches: Vec<(Entity, Vec<E>)>,
+}
+
+impl<E: Event + Clone> ControlEventBatches<E> {
+    /// Drains both readers. Plain events are dropped if no camera has the input.
+    pub fn read(
+        events: &mut EventReader<E>,
+        targeted: &mut EventReader<TargetedControlEvent<E>>,
+        input_camera: Option<Entity>,
+    ) -> Self {
+        let mut batches: Vec<(Entity, Vec<E>)> = Vec::new();
+        match input_camera {
+            Some(camera) => batches.push((camera, events.read().cloned().collect())),
+            None => events.clear(),
+        }
+        for TargetedControlEvent { camera, event } in targeted.read() {
+            match batches.iter_mut().find(|(c, _)| c == camera) {
+                Some((_, batch)) => batch.push(event.clone()),
+                None => batches.push((*camera, vec![event.clone()])),
+            }
+        }
+        Self {
+            input_camera,
+            batches,
+        }
+    }
+
+    /// Takes the events for `camera`. This is `None` if there's nothing to do for it, but the camera with the input always
+    /// gets its batch, even if empty, so it can update the [`CameraControllerStatus`].
+    pub fn take(&mut self, camera: Entity) -> Option<Vec<E>> {
+        let i = self.batches.iter().position(|(c, _)| *c == camera)?;
+        Some(self.batches.swap_remove(i).1)
+    }
+
+    pub fn is_input_camera(&self, camera: Entity) -> bool {
+        self.input_camera == Some(camera)
+    }
+}
+
+/// Enables or disables the `Smoother` of each camera whose `C` controller was enabled or disabled.
+pub fn on_controller_enabled_changed<C: CameraController>(
+    mut smoothers: Query<(&mut Smoother, &C, Option<&ActiveController>), Changed<C>>,
+) {
+    for (mut smoother, controller, active) in smoothers.iter_mut() {
+        // Only the active controller of a shared camera decides whether it's smoothed.
+        if active.is_some_and(|a| a.0 != C::KIND) {
+            continue;
+        }
+        smoother.set_enabled(controller.enabled());
+    }
+}
+
+/// Adds the events, resources, and systems shared by all of the built-in controller plugins. This only does the work once, so
+/// every plugin can call it.
+pub(crate) fn build_shared(app: &mut App) {
+    app.init_resource::<CameraInputBlocked>()
+        .init_resource::<CameraControllerToggle>()
+        .init_resource::<CameraControllerStatus>()
+        .init_resource::<ViewportFocus>()
+        .init_resource::<WindowFocus>();
+
+    if app
+        .world()
+        .contains_resource::<Events<CameraControlEvent>>()
+    {
+        return;
+    }
+    app.add_event::<CameraControlEvent>()
+        // Normally added by `WindowPlugin`, which headless apps may not have.
+        .add_event::<WindowFocused>()
+        .add_systems(
+            PreUpdate,
+            (
+                camera_control_event_system,
+                active_controller_system.before(camera_control_event_system),
+                viewport_focus_system.after(InputSystem),
+                window_focus_system.after(InputSystem),
+                window_focus_change_system.after(InputSystem),
+                edge_pan::edge_pan_system,
+            ),
+        )
+        .add_systems(
+            PostUpdate,
+            (input_activity_system, interactive_smoothing_system)
+                .chain()
+                .before(LookTransformSet::Sync),
+        );
+}
+
+/// The per-camera data that decides whether a controller receives input. See [`InputRouting`].
+#[derive(QueryData)]
+pub struct InputRoute {
+    pub entity: Entity,
+    pub viewport_input: Has<ViewportInput>,
+    pub window_input: Option<&'static WindowInput>,
+    pub player: Option<&'static PlayerInput>,
+    pub clock: Option<&'static CameraClock>,
+}
+
+/// Decides which cameras the built-in input maps and control systems apply to.
+///
+/// Each system still only controls one camera at a time: the first enabled controller that is accepted here.
+#[derive(SystemParam)]
+pub struct InputRouting<'w> {
+    input_blocked: Res<'w, CameraInputBlocked>,
+    toggle: Res<'w, CameraControllerToggle>,
+    replay_active: Option<Res<'w, ControlEventReplayActive>>,
+    viewport_focus: Res<'w, ViewportFocus>,
+    window_focus: Res<'w, WindowFocus>,
+    keyboard_navigation: Option<Res<'w, KeyboardNavigation>>,
+    time: Res<'w, Time>,
+    real_time: Res<'w, Time<Real>>,
+}
+
+impl InputRouting<'_> {
+    /// Whether live input should be ignored, because of [`CameraInputBlocked`], a disabled [`CameraControllerToggle`], or
+    /// a running replay.
+    pub fn is_blocked(&self) -> bool {
+        self.input_blocked.is_blocked()
+            || !self.toggle.enabled
+            || self.replay_active.as_ref().is_some_and(|r| r.0)
+    }
+
+    /// Whether the arrow keys belong to an enabled [`KeyboardNavigation`] profile, so input maps should leave them alone.
+    pub fn arrows_reserved(&self) -> bool {
+        self.keyboard_navigation.as_ref().is_some_and(|n| n.enabled)
+    }
+
+    /// The seconds since the last frame on the routed camera's [`CameraClock`], to turn speeds into distances.
+    pub fn delta_secs(&self, route: &InputRouteItem) -> f32 {
+        let clock = route.clock.copied().unwrap_or_default();
+        clock.delta_secs(&self.time, &self.real_time)
+    }
+
+    /// Whether the keyboard and mouse input maps may control the routed camera. Cameras bound to a gamepad with
+    /// [`PlayerInput`] never are.
+    pub fn accepts(&self, route: &InputRouteItem) -> bool {
+        self.toggle.enabled
+            && route.player.and_then(PlayerInput::gamepad).is_none()
+            && (!route.viewport_input || self.viewport_focus.entity == Some(route.entity))
+            && route
+                .window_input
+                .is_none_or(|w| self.window_focus.contains(w.0))
+    }
+}
+
+/// Opts a camera into viewport-aware input: its controller only receives input while the cursor is inside of the camera's
+/// `Camera::viewport`, or during a drag that started there. This lets multi-viewport editors route input to a single pane.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct ViewportInput;
+
+/// The [`ViewportInput`] camera whose viewport contains the cursor, if any.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Resource, Default, Debug, PartialEq)]
+pub struct ViewportFocus {
+    pub entity: Option<Entity>,
+}
+
+pub fn viewport_focus_system(
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut focus: ResMut<ViewportFocus>,
+    cameras: Query<(Entity, &Camera), With<ViewportInput>>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+) {
+    // Keep the focus for the whole drag, even if the cursor leaves the viewport.
+    let dragging = mouse_buttons.get_pressed().len() > mouse_buttons.get_just_pressed().len();
+    if dragging {
+        return;
+    }
+
+    let primary_window = primary_window.get_single().ok();
+    let under_cursor = cameras
+        .iter()
+        .filter(|(_, camera)| camera.is_active)
+        .filter_map(|(entity, camera)| {
+            let Some(NormalizedRenderTarget::Window(window_ref)) =
+                camera.target.normalize(primary_window)
+            else {
+                return None;
+            };
+            let cursor = windows
+                .get(window_ref.entity())
+                .ok()?
+                .physical_cursor_position()?;
+            let viewport = camera.physical_viewport_rect()?.as_rect();
+            viewport.contains(cursor).then_some((camera.order, entity))
+        })
+        .max_by_key(|(order, _)| *order)
+        .map(|(_, entity)| entity);
+
+    focus.set_if_neq(ViewportFocus {
+        entity: under_cursor,
+    });
+}
+
+/// Binds a camera's controller to a window, for multi-window editors: it only receives input while that window contains the
+/// cursor, or has focus while the cursor is outside of every window, or during a drag that started there.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct WindowInput(pub WindowRef);
+
+/// The window that input currently belongs to, for [`WindowInput`] cameras.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Resource, Default, Debug, PartialEq)]
+pub struct WindowFocus {
+    pub entity: Option<Entity>,
+    /// Whether `entity` is the `PrimaryWindow`.
+    pub is_primary: bool,
+}
+
+impl WindowFocus {
+    pub fn contains(&self, window: WindowRef) -> bool {
+        match window {
+            WindowRef::Primary => self.entity.is_some() && self.is_primary,
+            WindowRef::Entity(entity) => self.entity == Some(entity),
+        }
+    }
+}
+
+pub fn window_focus_system(
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut focus: ResMut<WindowFocus>,
+    windows: Query<(Entity, &Window, Has<PrimaryWindow>)>,
+) {
+    // Keep the window for the whole drag, even if the cursor leaves it.
+    let dragging = mouse_buttons.get_pressed().len() > mouse_buttons.get_just_pressed().len();
+    if dragging {
+        return;
+    }
+
+    let hovered = windows
+        .iter()
+        .find(|(_, window, _)| window.cursor_position().is_some());
+    let focused = || windows.iter().find(|(_, window, _)| window.focused);
+    let (entity, is_primary) = hovered
+        .or_else(focused)
+        .map_or((None, false), |(entity, _, is_primary)| {
+            (Some(entity), is_primary)
+        });
+
+    focus.set_if_neq(WindowFocus { entity, is_primary });
+}
+
+/// Assigns a camera to one player's input device, for split-screen games. See
+/// [`spawn_layout`](crate::split_screen::spawn_layout).
+///
+/// Cameras bound to a gamepad are skipped by the keyboard and mouse input maps, so those control the camera without one.
+/// Input maps that read gamepads send each gamepad's input to its own camera with [`TargetedControlEvent`]s, like the
+/// [`FlickStick`](fps::FlickStick) does.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub enum PlayerInput {
+    #[default]
+    KeyboardMouse,
+    /// The entity of a `Gamepad`.
+    Gamepad(Entity),
+}
+
+impl PlayerInput {
+    pub fn gamepad(&self) -> Option<Entity> {
+        match *self {
+            Self::KeyboardMouse => None,
+            Self::Gamepad(gamepad) => Some(gamepad),
+        }
+    }
+}
+
+/// Switches built-in controllers on and off by camera entity, regardless of the controller type.
+#[derive(Clone, Copy, Debug, Event, PartialEq, Eq)]
+pub enum CameraControlEvent {
+    /// Enables every controller on the entity.
+    Enable(Entity),
+    /// Disables every controller on the entity.
+    Disable(Entity),
+    /// Enables every controller on the entity and disables the controllers on all other entities.
+    SetExclusive(Entity),
+}
+
+/// Every built-in controller that may be on an entity.
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct AnyController {
+    pub fps: Option<&'static mut fps::FpsCameraController>,
+    pub isometric: Option<&'static mut isometric::IsometricCameraController>,
+    pub maya: Option<&'static mut maya::MayaCameraController>,
+    pub orbit: Option<&'static mut orbit::OrbitCameraController>,
+    pub pancam2d: Option<&'static mut pancam2d::PanCam2dController>,
+    pub unreal: Option<&'static mut unreal::UnrealCameraController>,
+    pub active: Option<&'static ActiveController>,
+}
+
+impl AnyControllerItem<'_> {
+    /// Sets the `enabled` flag of every controller, except that only the [`ActiveController`] is ever enabled. Only real
+    /// changes are written, because a change resets the controller's smoother.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        let active = self.active.map(|a| a.0);
+        for kind in ControllerKind::BUILT_IN {
+            self.set_enabled_of(kind, enabled && active.is_none_or(|a| a == kind));
+        }
+    }
+
+    /// The `enabled` flag of the controller of the given kind, or `None` if the entity doesn't have one.
+    pub fn enabled_of(&self, kind: ControllerKind) -> Option<bool> {
+        match kind {
+            ControllerKind::Fps => self.fps.as_ref().map(|c| c.enabled),
+            ControllerKind::Isometric => self.isometric.as_ref().map(|c| c.enabled),
+            ControllerKind::Maya => self.maya.as_ref().map(|c| c.enabled),
+            ControllerKind::Orbit => self.orbit.as_ref().map(|c| c.enabled),
+            ControllerKind::PanCam2d => self.pancam2d.as_ref().map(|c| c.enabled),
+            ControllerKind::Unreal => self.unreal.as_ref().map(|c| c.enabled),
+            ControllerKind::Custom(_) => None,
+        }
+    }
+
+    /// Sets the `enabled` flag of the controller of the given kind, if the entity has one, ignoring the
+    /// [`ActiveController`]. Only real changes are written.
+    pub fn set_enabled_of(&mut self, kind: ControllerKind, enabled: bool) {
+        macro_rules! set {
+            ($controller:expr) => {
+                if let Some(c) = $controller.as_mut().filter(|c| c.enabled != enabled) {
+                    c.enabled = enabled;
+                }
+            };
+        }
+        match kind {
+            ControllerKind::Fps => set!(self.fps),
+            ControllerKind::Isometric => set!(self.isometric),
+            ControllerKind::Maya => set!(self.maya),
+            ControllerKind::Orbit => set!(self.orbit),
+            ControllerKind::PanCam2d => set!(self.pancam2d),
+            ControllerKind::Unreal => set!(self.unreal),
+            ControllerKind::Custom(_) => (),
+        }
+    }
+
+    /// Whether any of the controllers are enabled.
+    pub fn any_enabled(&self) -> bool {
+        self.fps.as_ref().is_some_and(|c| c.enabled)
+            || self.isometric.as_ref().is_some_and(|c| c.enabled)
+            || self.maya.as_ref().is_some_and(|c| c.enabled)
+            || self.orbit.as_ref().is_some_and(|c| c.enabled)
+            || self.pancam2d.as_ref().is_some_and(|c| c.enabled)
+            || self.unreal.as_ref().is_some_and(|c| c.enabled)
+    }
+
+    /// [Reseeds](CameraController::reseed) the controller of the given kind, returning its smoothing weight, or `None` if
+    /// the entity doesn't have one.
+    pub fn reseed(&mut self, kind: ControllerKind, transform: &LookTransform) -> Option<f32> {
+        fn reseed<C: CameraController>(c: &mut Mut<C>, transform: &LookTransform) -> f32 {
+            c.reseed(transform);
+            c.smoothing_weight()
+        }
+        match kind {
+            ControllerKind::Fps => self.fps.as_mut().map(|c| reseed(c, transform)),
+            ControllerKind::Isometric => self.isometric.as_mut().map(|c| reseed(c, transform)),
+            ControllerKind::Maya => self.maya.as_mut().map(|c| reseed(c, transform)),
+            ControllerKind::Orbit => self.orbit.as_mut().map(|c| reseed(c, transform)),
+            ControllerKind::PanCam2d => self.pancam2d.as_mut().map(|c| reseed(c, transform)),
+            ControllerKind::Unreal => self.unreal.as_mut().map(|c| reseed(c, transform)),
+            ControllerKind::Custom(_) => None,
+        }
+    }
+}
+
+/// Drops the live `C` events that haven't been applied yet when a window loses focus. [`TargetedControlEvent`]s are kept,
+/// since they don't come from the user.
+pub fn drop_events_on_focus_lost<C: CameraController>(
+    mut focus_events: EventReader<WindowFocused>,
+    mut events: ResMut<Events<C::Event>>,
+) {
+    if focus_events.read().any(|e| !e.focused) {
+        events.clear();
+    }
+}
+
+/// Keeps the camera from moving on its own when a window loses focus in the middle of a drag, and from jumping when it
+/// gets focus back.
+///
+/// On focus loss, every held key and mouse button is released, since the window won't hear about them coming back up, and
+/// the controllers are [reseeded](CameraController::reseed) to drop their input state, like a zoom in progress. On focus
+/// gain, the mouse motion of that frame and the next is thrown away, because it holds the whole trip the cursor made while
+/// away.
+pub fn window_focus_change_system(
+    mut focus_events: EventReader<WindowFocused>,
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    mut mouse_buttons: ResMut<ButtonInput<MouseButton>>,
+    mut mouse_motion: ResMut<Events<MouseMotion>>,
+    mut cameras: Query<(&LookTransform, AnyController)>,
+    mut skip_motion_frames: Local<u8>,
+) {
+    for event in focus_events.read() {
+        if event.focused {
+            *skip_motion_frames = 2;
+        } else {
+            keyboard.release_all();
+            mouse_buttons.release_all();
+            for (transform, mut controllers) in cameras.iter_mut() {
+                for kind in ControllerKind::BUILT_IN {
+                    controllers.reseed(kind, transform);
+                }
+            }
+        }
+    }
+
+    if *skip_motion_frames > 0 {
+        *skip_motion_frames -= 1;
+        mouse_motion.clear();
+    }
+}
+
+/// Hands control of a camera over to its new [`ActiveController`].
+pub fn active_controller_system(
+    mut commands: Commands,
+    mut cameras: Query<
+        (Entity, &ActiveController, &LookTransform, AnyController),
+        Changed<ActiveController>,
+    >,
+    mut smoothers: Query<&mut Smoother>,
+) {
+    for (entity, active, transform, mut controllers) in cameras.iter_mut() {
+        let enabled = controllers.any_enabled();
+        if let Some(weight) = controllers.reseed(active.0, transform) {
+            if let Ok(mut smoother) = smoothers.get_mut(entity) {
+                smoother.set_lag_weight(weight);
+            }
+        }
+        controllers.set_enabled(enabled);
+        // An unfinished view snap would keep moving the camera.
+        commands.entity(entity).remove::<orbit::SnappingToView>();
+    }
+}
+
+pub fn camera_control_event_system(
+    mut events: EventReader<CameraControlEvent>,
+    mut cameras: Query<(Entity, AnyController)>,
+) {
+    for event in events.read() {
+        for (entity, mut controllers) in cameras.iter_mut() {
+            match *event {
+                CameraControlEvent::Enable(e) if e == entity => controllers.set_enabled(true),
+                CameraControlEvent::Disable(e) if e == entity => controllers.set_enabled(false),
+                CameraControlEvent::SetExclusive(e) => controllers.set_enabled(e == entity),
+                _ => (),
+            }
+        }
+    }
+}
+
+/// While this resource is set, all of the built-in `default_input_map` systems ignore input.
+///
+/// This is the contract for crates that own the mouse for a while, like transform gizmos: set it when a drag starts and clear
+/// it when the drag ends, so the same mouse buttons don't also move the camera.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub struct CameraInputBlocked(pub bool);
+
+impl CameraInputBlocked {
+    pub fn is_blocked(&self) -> bool {
+        self.0
+    }
+}
+
+/// Freezes every built-in controller while `enabled` is false, e.g. during dialogs or pause menus. Unlike
+/// [`CameraInputBlocked`], this also stops the control systems, so nothing the controllers started (like a smoothed zoom)
+/// keeps moving the camera. The controllers' own `enabled` flags are left alone.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Resource, Default, Debug, PartialEq)]
+pub struct CameraControllerToggle {
+    pub enabled: bool,
+}
+
+impl Default for CameraControllerToggle {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// Filters the summed mouse motion of a frame before a controller turns it into events.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub struct MouseDeltaFilter {
+    /// Motions shorter than this many pixels are ignored, to hide sensor noise.
+    pub dead_zone: f32,
+    /// Motions are clamped to this many pixels, so the huge jump reported when the window regains focus or the cursor is
+    /// warped doesn't whip the camera around.
+    pub max_delta: f32,
+    /// Low-pass filters the motion over time, to tame a jittery high-DPI mouse. Unlike the `Smoother`, this only delays
+    /// mouse rotation and panning, not the rest of the camera's motion.
+    pub smoothing: MouseSmoothing,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    state: MouseSmoothingState,
+}
+
+impl Default for MouseDeltaFilter {
+    fn default() -> Self {
+        Self {
+            dead_zone: 0.0,
+            max_delta: 250.0,
+            smoothing: MouseSmoothing::Off,
+            state: MouseSmoothingState::default(),
+        }
+    }
+}
+
+impl MouseDeltaFilter {
+    pub fn with_smoothing(mut self, smoothing: MouseSmoothing) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Applies the dead zone and the clamp to one frame's `delta`, without `smoothing`.
+    pub fn apply(&self, delta: Vec2) -> Vec2 {
+        if delta.length() < self.dead_zone {
+            return Vec2::ZERO;
+        }
+        delta.clamp_length_max(self.max_delta)
+    }
+
+    /// Like [`MouseDeltaFilter::apply`], then smooths `delta` with the previous frames. This should be called every frame
+    /// that the controller has the input, even without motion, so the smoothed motion comes to a stop.
+    pub fn filter(&mut self, delta: Vec2, dt: f32) -> Vec2 {
+        let delta = self.apply(delta);
+        match self.smoothing {
+            MouseSmoothing::Off => delta,
+            MouseSmoothing::Window { frames } => self.state.window(delta, frames),
+            MouseSmoothing::OneEuro {
+                min_cutoff,
+                beta,
+                derivative_cutoff,
+            } => self
+                .state
+                .one_euro(delta, dt, min_cutoff, beta, derivative_cutoff),
+        }
+    }
+
+    /// Forgets the motion of previous frames.
+    pub fn reset(&mut self) {
+        self.state = MouseSmoothingState::default();
+    }
+}
+
+/// The most frames that [`MouseSmoothing::Window`] can average.
+pub const MAX_MOUSE_SMOOTHING_WINDOW: usize = 16;
+
+/// How a [`MouseDeltaFilter`] smooths mouse motion over time.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum MouseSmoothing {
+    #[default]
+    Off,
+    /// Averages the motion of the last `frames` frames, up to [`MAX_MOUSE_SMOOTHING_WINDOW`]. Every pixel of motion is
+    /// still applied, but spread over the window.
+    Window { frames: usize },
+    /// The [1€ filter](https://gery.casiez.net/1euro/), which smooths slow, precise motion heavily and fast motion
+    /// barely, so that flicks don't lag.
+    OneEuro {
+        /// The cutoff frequency in Hz at rest. Lower values remove more jitter.
+        min_cutoff: f32,
+        /// How fast the cutoff frequency rises with the speed of the mouse. Higher values lag less during fast motion.
+        beta: f32,
+        /// The cutoff frequency in Hz for estimating the speed of the mouse.
+        derivative_cutoff: f32,
+    },
+}
+
+impl MouseSmoothing {
+    /// A [`MouseSmoothing::OneEuro`] tuned for mouse motion in pixels.
+    pub const ONE_EURO: Self = Self::OneEuro {
+        min_cutoff: 1.0,
+        beta: 0.007,
+        derivative_cutoff: 1.0,
+    };
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct MouseSmoothingState {
+    /// The latest frames, for [`MouseSmoothing::Window`].
+    window: [Vec2; MAX_MOUSE_SMOOTHING_WINDOW],
+    next: usize,
+    /// How far the filtered cursor trails the real one, and the filtered cursor speed in pixels per second, for
+    /// [`MouseSmoothing::OneEuro`].
+    lag: Vec2,
+    velocity: Vec2,
+}
+
+impl MouseSmoothingState {
+    fn window(&mut self, delta: Vec2, frames: usize) -> Vec2 {
+        let frames = frames.clamp(1, MAX_MOUSE_SMOOTHING_WINDOW);
+        self.window[self.next % frames] = delta;
+        self.next = (self.next + 1) % frames;
+        self.window[..frames].iter().sum::<Vec2>() / frames as f32
+    }
+
+    fn one_euro(
+        &mut self,
+        delta: Vec2,
+        dt: f32,
+        min_cutoff: f32,
+        beta: f32,
+        derivative_cutoff: f32,
+    ) -> Vec2 {
+        if dt <= 0.0 {
+            return delta;
+        }
+        let smoothing_factor = |cutoff: f32| {
+            let time_constant = 1.0 / (2.0 * PI * cutoff.max(f32::EPSILON));
+            1.0 / (1.0 + time_constant / dt)
+        };
+        self.velocity = self
+            .velocity
+            .lerp(delta / dt, smoothing_factor(derivative_cutoff));
+        let cutoff = min_cutoff + beta * self.velocity.length();
+        // The filtered cursor moves part of the way toward the real one, which is only tracked relative to it, so that
+        // precision isn't lost as the cursor travels.
+        let remaining = self.lag + delta;
+        // Catch up on the last hundredth of a pixel at once, so the motion comes to a stop.
+        if remaining.length_squared() < 1e-4 {
+            self.lag = Vec2::ZERO;
+            return remaining;
+        }
+        let filtered = smoothing_factor(cutoff) * remaining;
+        self.lag = remaining - filtered;
+        filtered
+    }
+}
+
+/// Flips the horizontal and/or vertical direction of an input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub struct InvertAxes {
+    pub invert_x: bool,
+    pub invert_y: bool,
+}
+
+impl InvertAxes {
+    pub const NONE: Self = Self::new(false, false);
+    pub const Y: Self = Self::new(false, true);
+
+    pub const fn new(invert_x: bool, invert_y: bool) -> Self {
+        Self { invert_x, invert_y }
+    }
+
+    pub fn apply(&self, delta: Vec2) -> Vec2 {
+        Vec2::new(
+            if self.invert_x { -delta.x } else { delta.x },
+            if self.invert_y { -delta.y } else { delta.y },
+        )
+    }
+}
+
+/// Which mouse axes a controller flips, e.g. for an inverted look or "natural" scrolling, so that input maps don't need
+/// to be replaced for it. This is applied by the controllers' `*_from_mouse` and `*_from_wheel` event constructors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub struct AxisInversion {
+    pub rotate: InvertAxes,
+    pub pan: InvertAxes,
+    /// Flips the direction of the mouse wheel, for zooming or moving forward.
+    pub zoom: bool,
+}
+
+impl AxisInversion {
+    /// The wheel scrolling of `lines`, flipped if `zoom` is set.
+    pub fn apply_zoom(&self, lines: f32) -> f32 {
+        if self.zoom {
+            -lines
+        } else {
+            lines
+        }
+    }
+}
+
+/// A modifier key, matching either the left or right key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Debug, PartialEq, Hash)]
+pub enum ModifierKey {
+    Ctrl,
+    Shift,
+    Alt,
+    Super,
+}
+
+impl ModifierKey {
+    pub fn keys(self) -> [KeyCode; 2] {
+        match self {
+            Self::Ctrl => [KeyCode::ControlLeft, KeyCode::ControlRight],
+            Self::Shift => [KeyCode::ShiftLeft, KeyCode::ShiftRight],
+            Self::Alt => [KeyCode::AltLeft, KeyCode::AltRight],
+            Self::Super => [KeyCode::SuperLeft, KeyCode::SuperRight],
+        }
+    }
+
+    pub fn is_pressed(self, keyboard: &ButtonInput<KeyCode>) -> bool {
+        keyboard.any_pressed(self.keys())
+    }
+}
+
+/// A mouse button and/or a modifier key that must be held for mouse motion to trigger an action. With neither, the action
+/// is never triggered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub struct DragTrigger {
+    pub button: Option<MouseButton>,
+    pub modifier: Option<ModifierKey>,
+}
+
+impl DragTrigger {
+    pub fn button(button: MouseButton) -> Self {
+        Self {
+            button: Some(button),
+            modifier: None,
+        }
+    }
+
+    pub fn modifier(modifier: ModifierKey) -> Self {
+        Self {
+            button: None,
+            modifier: Some(modifier),
+        }
+    }
+
+    pub fn with_modifier(mut self, modifier: ModifierKey) -> Self {
+        self.modifier = Some(modifier);
+        self
+    }
+
+    pub fn is_active(
+        &self,
+        mouse_buttons: &ButtonInput<MouseButton>,
+        keyboard: &ButtonInput<KeyCode>,
+    ) -> bool {
+        (self.button.is_some() || self.modifier.is_some())
+            && self.button.is_none_or(|b| mouse_buttons.pressed(b))
+            && self.modifier.is_none_or(|m| m.is_pressed(keyboard))
+    }
+
+    /// How many inputs must be held. When two triggers are active at once, the more specific one wins, so e.g. Shift +
+    /// Middle can pan while Middle alone orbits.
+    pub fn specificity(&self) -> u32 {
+        self.button.is_some() as u32 + self.modifier.is_some() as u32
+    }
+}
+
+/// The default `pixels_per_line` of the built-in controllers. Browsers report about 100 pixels per wheel notch, while native
+/// trackpads report roughly half that.
+pub const DEFAULT_PIXELS_PER_LINE: f32 = if cfg!(target_arch = "wasm32") {
+    100.0
+} else {
+    53.0
+};
+
+/// How many lines one wheel notch reports in [`MouseScrollUnit::Line`]s. Browsers that scroll by lines (like Firefox) report 3
+/// per notch, where native platforms report 1.
+const LINES_PER_NOTCH: f32 = if cfg!(target_arch = "wasm32") {
+    3.0
+} else {
+    1.0
+};
+
+/// The scroll of a mouse wheel event in wheel notches, consistent between native wheels, trackpads, and browsers. Pixel
+/// deltas are converted with `pixels_per_line`, e.g. [`DEFAULT_PIXELS_PER_LINE`].
+pub fn scroll_lines(event: &MouseWheel, pixels_per_line: f32) -> Vec2 {
+    let delta = Vec2::new(event.x, event.y);
+    match event.unit {
+        MouseScrollUnit::Line => delta / LINES_PER_NOTCH,
+        MouseScrollUnit::Pixel => delta / pixels_per_line,
+    }
+}
+
+/// The horizontal field of view of a perspective projection, in radians.
+pub fn horizontal_fov(perspective: &PerspectiveProjection) -> f32 {
+    2.0 * ((0.5 * perspective.fov).tan() * perspective.aspect_ratio).atan()
+}
+
+/// The factor that mouse rotation sensitivity is scaled by when a controller compensates for the field of view.
+///
+/// With a `reference_horizontal_fov`, a given mouse movement always sweeps the same fraction of the view, so wide aspect
+/// ratios, multi-monitor setups, and animated FOV changes don't change how the mouse feels on screen. Orthographic
+/// projections and `None` are not compensated.
+pub fn fov_sensitivity_scale(
+    reference_horizontal_fov: Option<f32>,
+    projection: Option<&Projection>,
+) -> f32 {
+    match (reference_horizontal_fov, projection) {
+        (Some(reference), Some(Projection::Perspective(perspective))) if reference > 0.0 => {
+            horizontal_fov(perspective) / reference
+        }
+        _ => 1.0,
+    }
+}
+
+/// Smooths an orthographic scale toward `goal` with the lag weight of the camera's `Smoother`, so zooming by scale feels
+/// the same as moving the eye.
+pub fn smooth_scale(scale: f32, goal: f32, smoother: Option<&Smoother>) -> f32 {
+    let lag_weight = smoother
+        .filter(|s| s.is_enabled())
+        .map_or(0.0, |s| s.lag_weight().x);
+
+    let smoothed = scale * lag_weight + goal * (1.0 - lag_weight);
+    if (smoothed - goal).abs() <= 1e-4 * goal {
+        goal
+    } else {
+        smoothed
+    }
+}
+
+/// Slowly turns a camera around its target once there has been no input for a while, like a turntable or a spectator cam
+/// that keeps the scene moving on its own.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub struct AutoRotate {
+    /// Radians per second around the up vector, counterclockwise when seen from above.
+    pub speed: f32,
+    /// Seconds without input before the rotation starts.
+    pub idle_delay: f32,
+}
+
+impl Default for AutoRotate {
+    fn default() -> Self {
+        Self {
+            speed: 0.2,
+            idle_delay: 3.0,
+        }
+    }
+}
+
+impl AutoRotate {
+    pub fn new(speed: f32, idle_delay: f32) -> Self {
+        Self { speed, idle_delay }
+    }
+
+    /// The angle to turn by over the next `dt` seconds, after `idle_time` seconds without input.
+    pub fn angle(&self, idle_time: f32, dt: f32) -> f32 {
+        if idle_time >= self.idle_delay {
+            self.speed * dt
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Whether the controller of this camera applied any input in the last frame, according to the
+/// [`CameraControllerStatus`]. It's added along with [`InteractiveSmoothing`], or can be added by itself.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct InputActivity(pub bool);
+
+/// Swaps the `Smoother` lag weight of a camera for `lag_weight` while its controller has input, and back once it stops, to
+/// be responsive while interacting and smooth while coasting. A `lag_weight` of `0.0` bypasses smoothing.
+///
+/// Only exponential smoothing uses the lag weight, so springs are unaffected.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[require(InputActivity)]
+#[reflect(Component, Debug)]
+pub struct InteractiveSmoothing {
+    pub lag_weight: f32,
+    /// The lag weight to restore, while interacting.
+    coasting_lag_weight: Option<Vec3>,
+}
+
+impl InteractiveSmoothing {
+    pub fn new(lag_weight: f32) -> Self {
+        Self {
+            lag_weight,
+            coasting_lag_weight: None,
+        }
+    }
+}
+
+pub fn input_activity_system(
+    status: Res<CameraControllerStatus>,
+    mut cameras: Query<(Entity, &mut InputActivity)>,
+) {
+    for (entity, mut activity) in cameras.iter_mut() {
+        let active = status.entity == Some(entity) && status.mode != CameraControlMode::Idle;
+        activity.set_if_neq(InputActivity(active));
+    }
+}
+
+pub fn interactive_smoothing_system(
+    mut cameras: Query<
+        (&InputActivity, &mut InteractiveSmoothing, &mut Smoother),
+        Changed<InputActivity>,
+    >,
+) {
+    for (activity, mut interactive, mut smoother) in cameras.iter_mut() {
+        if activity.0 {
+            interactive
+                .coasting_lag_weight
+                .get_or_insert(smoother.lag_weight());
+            smoother.set_lag_weight(interactive.lag_weight);
+        } else if let Some(lag_weight) = interactive.coasting_lag_weight.take() {
+            smoother.set_lag_weight_per_axis(lag_weight);
+        }
+    }
+}
+
+/// What the active built-in controller is doing, for UI like an editor's camera speed readout.
+///
+/// This is written by the control systems themselves, so it reflects runtime modulation (like the Unreal controller's
+/// wheel-adjusted speed) in the same frame.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Resource, Default, Debug, PartialEq)]
+pub struct CameraControllerStatus {
+    /// The camera being controlled.
+    pub entity: Option<Entity>,
+    pub mode: CameraControlMode,
+    /// The controller's movement speed in units per second, or zero for controllers without free movement.
+    pub speed: f32,
+    /// The distance from the eye to the target, or the orthographic scale for controllers that zoom by scaling.
+    pub zoom: f32,
+}
+
+/// The kind of motion a controller applied in the last frame.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub enum CameraControlMode {
+    #[default]
+    Idle,
+    Look,
+    Fly,
+    Orbit,
+    Pan,
+    Zoom,
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mouse_smoothing_keeps_all_motion() {
+        let dt = 1.0 / 60.0;
+        let flick = Vec2::new(40.0, -20.0);
+        for smoothing in [
+            MouseSmoothing::Window { frames: 4 },
+            MouseSmoothing::ONE_EURO,
+        ] {
+            let mut filter = MouseDeltaFilter::default().with_smoothing(smoothing);
+            let first = filter.filter(flick, dt);
+            assert!(first.length() < flick.length());
+
+            let mut total = first;
+            let mut last = first;
+            for _ in 0..600 {
+                last = filter.filter(Vec2::ZERO, dt);
+                total += last;
+            }
+            // The motion is delayed, not lost, and comes to a stop.
+            assert!(total.abs_diff_eq(flick, 1e-2), "{smoothing:?}: {total}");
+            assert_eq!(last, Vec2::ZERO);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use bevy::{
+    ecs::prelude::*,
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    render::camera::{Camera, NormalizedRenderTarget},
+    window::{PrimaryWindow, Window},
+};
+
+/// Add this to a camera to pan it while the cursor is near the edge of the window, like in RTS games.
+///
+/// Controllers that support it (orbit and isometric) read [`EdgePan::velocity`] in their `default_input_map`, so it is
+/// subject to the same input routing as the rest of their input.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct EdgePan {
+    pub enabled: bool,
+    /// How close to the edge of the window the cursor must be to start panning, in logical pixels.
+    pub margin: f32,
+    /// The panning speed with the cursor at the very edge, in world units per second.
+    pub speed: f32,
+    pub curve: EdgePanCurve,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    direction: Vec2,
+}
+
+impl Default for EdgePan {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            margin: 20.0,
+            speed: 10.0,
+            curve: EdgePanCurve::Linear,
+            direction: Vec2::ZERO,
+        }
+    }
+}
+
+impl EdgePan {
+    /// The direction and strength of the pan this frame, with X pointing right and Y pointing up the screen. Each component
+    /// is in `[-1, 1]`.
+    pub fn direction(&self) -> Vec2 {
+        self.direction
+    }
+
+    /// The pan velocity in world units per second along the screen axes.
+    pub fn velocity(&self) -> Vec2 {
+        self.speed * self.direction
+    }
+}
+
+/// How the [`EdgePan`] speed ramps up as the cursor gets closer to the edge.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum EdgePanCurve {
+    /// Full speed anywhere inside of the margin.
+    Constant,
+    #[default]
+    Linear,
+    /// Slow near the inside of the margin, for finer control.
+    Quadratic,
+}
+
+impl EdgePanCurve {
+    /// Maps how deep into the margin the cursor is, from `0.0` to `1.0`, to a speed factor.
+    pub fn evaluate(self, depth: f32) -> f32 {
+        let depth = depth.clamp(0.0, 1.0);
+        match self {
+            Self::Constant => depth.ceil(),
+            Self::Linear => depth,
+            Self::Quadratic => depth * depth,
+        }
+    }
+}
+
+/// The [`EdgePan::direction`] for a cursor at `cursor` in a window of `size`, both in logical pixels with Y pointing down.
+pub fn edge_pan_direction(cursor: Vec2, size: Vec2, margin: f32, curve: EdgePanCurve) -> Vec2 {
+    if margin <= 0.0 {
+        return Vec2::ZERO;
+    }
+    let depth = |distance_to_edge: f32| curve.evaluate(1.0 - distance_to_edge / margin);
+    Vec2::new(
+        depth(size.x - cursor.x) - depth(cursor.x),
+        depth(cursor.y) - depth(size.y - cursor.y),
+    )
+}
+
+pub fn edge_pan_system(
+    mut cameras: Query<(&mut EdgePan, Option<&Camera>)>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+) {
+    let primary_window = primary_window.get_single().ok();
+    for (mut edge_pan, camera) in cameras.iter_mut() {
+        let window_entity = match camera.and_then(|c| c.target.normalize(primary_window)) {
+            Some(NormalizedRenderTarget::Window(window_ref)) => Some(window_ref.entity()),
+            Some(_) => None,
+            None => primary_window,
+        };
+
+        // Panning while the window is in the background would be a surprise when switching back to it.
+        let direction = window_entity
+            .and_then(|e| windows.get(e).ok())
+            .filter(|w| edge_pan.enabled && w.focused)
+            .and_then(|w| Some((w.cursor_position()?, w.size())))
+            .map_or(Vec2::ZERO, |(cursor, size)| {
+                edge_pan_direction(cursor, size, edge_pan.margin, edge_pan.curve)
+            });
+
+        if edge_pan.direction != direction {
+            edge_pan.direction = direction;
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_pan_away_from_edges() {
+        let size = Vec2::new(800.0, 600.0);
+        let direction = edge_pan_direction(size / 2.0, size, 20.0, EdgePanCurve::Linear);
+        assert_eq!(direction, Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_pan_ramps_toward_edge() {
+        let size = Vec2::new(800.0, 600.0);
+        let top_right = edge_pan_direction(Vec2::new(800.0, 0.0), size, 20.0, EdgePanCurve::Linear);
+        assert_eq!(top_right, Vec2::new(1.0, 1.0));
+
+        let half_left =
+            edge_pan_direction(Vec2::new(10.0, 300.0), size, 20.0, EdgePanCurve::Linear);
+        assert_eq!(half_left, Vec2::new(-0.5, 0.0));
+        let quadratic =
+            edge_pan_direction(Vec2::new(10.0, 300.0), size, 20.0, EdgePanCurve::Quadratic);
+        assert_eq!(quadratic, Vec2::new(-0.25, 0.0));
+    }
+}
@@ -1,38 +1,102 @@
-use crate::{LookAngles, LookTransform, LookTransformBundle, Smoother};
+use crate::{
+    clock::CameraTime,
+    controllers::{
+        controller_plugin, fov_sensitivity_scale, scroll_lines, AxisInversion, CameraBundleBuilder,
+        CameraControlMode, CameraController, CameraControllerStatus, CameraInputBlocked,
+        ControlEventBatches, ControllerKind, InputRoute, InputRouting, MouseDeltaFilter,
+        PlayerInput, TargetedControlEvent, DEFAULT_PIXELS_PER_LINE,
+    },
+    cursor::{grab_cursor, is_cursor_grabbed, release_cursor},
+    ease::Ease,
+    xr::XrDriven,
+    LookAngles, LookTransform, LookTransformBundle, LookTransformSet, Smoother,
+};
 
 use bevy::{
     app::prelude::*,
-    ecs::prelude::*,
-    input::{mouse::MouseMotion, prelude::*},
+    ecs::{
+        prelude::*,
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+    },
+    input::{
+        mouse::{MouseMotion, MouseWheel},
+        prelude::*,
+    },
     math::prelude::*,
     prelude::ReflectDefault,
     reflect::Reflect,
-    time::Time,
+    render::camera::{PerspectiveProjection, Projection},
     transform::components::Transform,
+    window::{PrimaryWindow, Window},
 };
 
-#[derive(Default)]
+use std::f32::consts::{PI, TAU};
+
 pub struct FpsCameraPlugin {
     pub override_input_system: bool,
+    /// The schedule that the control systems run in. Defaults to `Update`.
+    pub control_schedule: InternedScheduleLabel,
+    /// The schedule that the default input map runs in. Defaults to `Update`. In the same schedule as the control
+    /// systems, the input map runs first, so input is applied in the frame that it arrives.
+    pub input_schedule: InternedScheduleLabel,
+}
+
+impl Default for FpsCameraPlugin {
+    fn default() -> Self {
+        Self::new(false)
+    }
 }
 
 impl FpsCameraPlugin {
     pub fn new(override_input_system: bool) -> Self {
         Self {
             override_input_system,
+            control_schedule: Update.intern(),
+            input_schedule: Update.intern(),
         }
     }
+
+    /// Runs the control systems in `schedule`, e.g. `FixedUpdate` to move the camera in step with gameplay. Add a
+    /// [`FixedInterpolation`](crate::FixedInterpolation) to the camera to keep it smooth between steps.
+    pub fn with_control_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.control_schedule = schedule.intern();
+        self
+    }
+
+    /// Runs the default input map in `schedule`, e.g. `PreUpdate` after input handling, for apps that pace their own
+    /// frames.
+    pub fn with_input_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.input_schedule = schedule.intern();
+        self
+    }
 }
 
 impl Plugin for FpsCameraPlugin {
     fn build(&self, app: &mut App) {
         let app = app
-            .add_systems(PreUpdate, on_controller_enabled_changed)
-            .add_systems(Update, control_system)
-            .add_event::<ControlEvent>();
+            .add_plugins(controller_plugin::<FpsCameraController>())
+            .add_systems(self.control_schedule, control_system)
+            .add_systems(
+                PostUpdate,
+                (head_bob_system, strafe_tilt_system)
+                    .chain()
+                    .after(LookTransformSet::Sync)
+                    .before(LookTransformSet::Finish),
+            );
 
         if !self.override_input_system {
-            app.add_systems(Update, default_input_map);
+            app.add_systems(
+                self.input_schedule,
+                (
+                    cursor_capture_system,
+                    default_input_map,
+                    aim_input_map,
+                    flick_stick_input_map,
+                    aim_motion_input_map,
+                )
+                    .chain()
+                    .before(control_system),
+            );
         }
     }
 }
@@ -45,6 +109,15 @@ pub struct FpsCameraBundle {
 }
 
 impl FpsCameraBundle {
+    pub fn builder() -> CameraBundleBuilder<FpsCameraController> {
+        CameraBundleBuilder::new(
+            FpsCameraController::default(),
+            Vec3::ZERO,
+            -Vec3::Z,
+            Vec3::Y,
+        )
+    }
+
     pub fn new(controller: FpsCameraController, eye: Vec3, target: Vec3, up: Vec3) -> Self {
         // Make sure the transform is consistent with the controller to start.
         let transform = Transform::from_translation(eye).looking_at(target, up);
@@ -61,115 +134,1096 @@ impl FpsCameraBundle {
 }
 
 /// Your typical first-person camera controller.
+///
+/// Smoothing the look direction adds some mouse latency. To smooth only movement, build the camera with a
+/// [`Smoother::with_instant_look`].
+///
+/// The controller keeps some state between frames in private fields, so start from `FpsCameraController::default()` and
+/// set the fields to change, rather than using a struct literal.
 #[derive(Clone, Component, Copy, Debug, Reflect)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[reflect(Component, Default, Debug)]
 pub struct FpsCameraController {
     pub enabled: bool,
+    /// Radians per pixel of mouse motion, for yaw (X) and pitch (Y).
     pub mouse_rotate_sensitivity: Vec2,
+    /// World units per second that the movement keys move.
     pub translate_sensitivity: f32,
+    /// Which way the movement keys move.
+    pub movement_mode: MovementMode,
+    /// What the mouse wheel does.
+    pub wheel_mode: FpsWheelMode,
+    /// How far each line of scrolling dollies, in world units, for [`FpsWheelMode::Dolly`].
+    pub wheel_dolly_sensitivity: f32,
+    /// How much each line of scrolling changes `translate_sensitivity`, for [`FpsWheelMode::Speed`].
+    pub wheel_speed_sensitivity: f32,
+    pub pixels_per_line: f32,
     pub smoothing_weight: f32,
+    /// When set, `mouse_rotate_sensitivity` is tuned for this horizontal FOV (radians) and scaled to match the camera's
+    /// current one. See [`fov_sensitivity_scale`].
+    pub fov_compensation: Option<f32>,
+    /// Cleans up mouse motion before it's used.
+    pub mouse_delta_filter: MouseDeltaFilter,
+    /// Flips mouse and wheel directions.
+    pub invert: AxisInversion,
+    /// Only rotate with the mouse while the cursor is captured. Clicking in the window captures the cursor, and Escape
+    /// releases it.
+    pub mouse_look_requires_capture: bool,
+    /// The yaw of the look direction in radians, like [`LookAngles`]. The control system keeps this and `pitch` up to
+    /// date, and while the controller is enabled, writing them turns the camera the next time it runs, e.g. to face an
+    /// objective.
+    pub yaw: f32,
+    pub pitch: f32,
+    /// The angles that the control system last wrote, to tell external writes apart.
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    synced_angles: Option<Vec2>,
+}
+
+impl CameraController for FpsCameraController {
+    const KIND: ControllerKind = ControllerKind::Fps;
+    type Event = ControlEvent;
+
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn smoothing_weight(&self) -> f32 {
+        self.smoothing_weight
+    }
+
+    fn reseed(&mut self, transform: &LookTransform) {
+        self.synced_angles = None;
+        self.mouse_delta_filter.reset();
+        if let Some(direction) = transform.look_direction() {
+            self.sync_angles(LookAngles::from_vector(direction));
+        }
+    }
 }
 
 impl Default for FpsCameraController {
     fn default() -> Self {
         Self {
             enabled: true,
-            mouse_rotate_sensitivity: Vec2::splat(0.2),
+            mouse_rotate_sensitivity: Vec2::splat(0.0033),
             translate_sensitivity: 2.0,
+            movement_mode: MovementMode::Planar,
+            wheel_mode: FpsWheelMode::Dolly,
+            wheel_dolly_sensitivity: 0.5,
+            wheel_speed_sensitivity: 0.5,
+            pixels_per_line: DEFAULT_PIXELS_PER_LINE,
             smoothing_weight: 0.9,
+            fov_compensation: None,
+            mouse_delta_filter: MouseDeltaFilter::default(),
+            invert: AxisInversion::default(),
+            mouse_look_requires_capture: false,
+            yaw: 0.0,
+            pitch: 0.0,
+            synced_angles: None,
         }
     }
 }
 
-#[derive(Event)]
+impl FpsCameraController {
+    pub fn look_angles(&self) -> LookAngles {
+        let mut angles = LookAngles::default();
+        angles.set_yaw(self.yaw);
+        angles.set_pitch(self.pitch);
+        angles
+    }
+
+    /// Turns the camera to look in `direction`, by writing `yaw` and `pitch`.
+    pub fn look_to(&mut self, direction: Vec3) {
+        let angles = LookAngles::from_vector(direction);
+        self.yaw = angles.get_yaw();
+        self.pitch = angles.get_pitch();
+    }
+
+    /// Whether `yaw` or `pitch` were written since the control system last ran.
+    fn angles_written(&self) -> bool {
+        self.synced_angles
+            .is_some_and(|synced| synced != Vec2::new(self.yaw, self.pitch))
+    }
+
+    fn sync_angles(&mut self, angles: LookAngles) {
+        self.yaw = angles.get_yaw();
+        self.pitch = angles.get_pitch();
+        self.synced_angles = Some(Vec2::new(self.yaw, self.pitch));
+    }
+}
+
+/// How an [`FpsCameraController`] translates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum MovementMode {
+    /// Forward and sideways movement stays on the XZ plane, and up and down follow the Y axis, like walking.
+    #[default]
+    Planar,
+    /// Movement follows the pitched view, so forward goes where the camera looks and up is the camera's up, like noclip.
+    FreeFly,
+}
+
+/// What the mouse wheel does for an [`FpsCameraController`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum FpsWheelMode {
+    Nothing,
+    /// Moves the eye along the look direction.
+    #[default]
+    Dolly,
+    /// Changes the movement speed, like the Unreal controller does while a mouse button is held.
+    Speed,
+}
+
+/// One frame of input for an [`FpsCameraController`], as described on [`CameraController::Event`].
+#[derive(Clone, Copy, Debug, Event, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum ControlEvent {
+    /// Turns by yaw (X) and pitch (Y) angles in radians.
     Rotate(Vec2),
+    /// Moves the eye left (X), up (Y), and forward (Z) by world space distances, along the axes of the controller's
+    /// [`MovementMode`].
     TranslateEye(Vec3),
+    /// Moves the eye forward along the look direction, including its pitch, by a world space distance.
+    Dolly(f32),
+    /// Holds the [`AimZoom`] zoomed in for this frame.
+    Aim,
+}
+
+impl ControlEvent {
+    /// Turns by a mouse motion of `cursor_delta` pixels, compensating for the camera's `projection` if the controller has
+    /// `fov_compensation`.
+    pub fn rotate_from_mouse(
+        controller: &FpsCameraController,
+        projection: Option<&Projection>,
+        cursor_delta: Vec2,
+    ) -> Self {
+        let scale = fov_sensitivity_scale(controller.fov_compensation, projection);
+        Self::Rotate(
+            scale
+                * controller.mouse_rotate_sensitivity
+                * controller.invert.rotate.apply(cursor_delta),
+        )
+    }
+
+    /// Moves in a `direction` like [`ControlEvent::TranslateEye`], at the controller's `translate_sensitivity` speed for
+    /// `dt` seconds.
+    pub fn translate_from_keys(controller: &FpsCameraController, direction: Vec3, dt: f32) -> Self {
+        Self::TranslateEye(dt * controller.translate_sensitivity * direction)
+    }
+
+    /// Dollies by `lines` of mouse wheel scrolling, where positive lines move forward.
+    pub fn dolly_from_wheel(controller: &FpsCameraController, lines: f32) -> Self {
+        Self::Dolly(controller.wheel_dolly_sensitivity * controller.invert.apply_zoom(lines))
+    }
+}
+
+pub fn cursor_capture_system(
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    controllers: Query<&FpsCameraController>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    input_blocked: Res<CameraInputBlocked>,
+) {
+    if !controllers
+        .iter()
+        .any(|c| c.enabled && c.mouse_look_requires_capture)
+    {
+        return;
+    }
+    let Ok(mut window) = windows.get_single_mut() else {
+        return;
+    };
+
+    if keyboard.just_pressed(KeyCode::Escape) {
+        release_cursor(&mut window);
+    } else if mouse_buttons.just_pressed(MouseButton::Left) && !input_blocked.is_blocked() {
+        grab_cursor(&mut window);
+    }
 }
 
-define_on_controller_enabled_changed!(FpsCameraController);
+/// The camera components that [`default_input_map`] reads.
+type InputCamera = (
+    &'static mut FpsCameraController,
+    Option<&'static Projection>,
+    Option<&'static AimZoom>,
+    Option<&'static mut Dash>,
+    InputRoute,
+);
 
 pub fn default_input_map(
     mut events: EventWriter<ControlEvent>,
     keyboard: Res<ButtonInput<KeyCode>>,
     mut mouse_motion_events: EventReader<MouseMotion>,
-    controllers: Query<&FpsCameraController>,
+    mut mouse_wheel_reader: EventReader<MouseWheel>,
+    mut controllers: Query<InputCamera>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    routing: InputRouting,
 ) {
+    if routing.is_blocked() {
+        mouse_motion_events.clear();
+        mouse_wheel_reader.clear();
+        return;
+    }
+
     // Can only control one camera at a time.
-    let controller = if let Some(controller) = controllers.iter().find(|c| c.enabled) {
+    let (mut controller, projection, aim_zoom, dash, route) = if let Some(controller) = controllers
+        .iter_mut()
+        .find(|(c, .., route)| c.enabled && routing.accepts(route))
+    {
         controller
     } else {
         return;
     };
-    let FpsCameraController {
-        translate_sensitivity,
-        mouse_rotate_sensitivity,
-        ..
-    } = *controller;
+    let mouse_look_requires_capture = controller.mouse_look_requires_capture;
 
     let mut cursor_delta = Vec2::ZERO;
     for event in mouse_motion_events.read() {
         cursor_delta += event.delta;
     }
+    let dt = routing.delta_secs(&route);
+    // The filter's state is the controller's own bookkeeping, so don't let it look like a user change.
+    let cursor_delta = controller
+        .bypass_change_detection()
+        .mouse_delta_filter
+        .filter(cursor_delta, dt);
 
-    events.send(ControlEvent::Rotate(
-        mouse_rotate_sensitivity * cursor_delta,
-    ));
+    let mouse_look =
+        !mouse_look_requires_capture || windows.get_single().is_ok_and(is_cursor_grabbed);
+    if mouse_look && cursor_delta != Vec2::ZERO {
+        let scale = aim_zoom.map_or(1.0, |a| a.sensitivity_scale(&controller, projection));
+        events.send(ControlEvent::rotate_from_mouse(
+            &controller,
+            projection,
+            scale * cursor_delta,
+        ));
+    }
 
-    for (key, dir) in [
+    let movement_keys = [
         (KeyCode::KeyW, Vec3::Z),
         (KeyCode::KeyA, Vec3::X),
         (KeyCode::KeyS, -Vec3::Z),
         (KeyCode::KeyD, -Vec3::X),
         (KeyCode::ShiftLeft, -Vec3::Y),
         (KeyCode::Space, Vec3::Y),
-    ]
-    .iter()
-    .cloned()
-    {
-        if keyboard.pressed(key) {
-            events.send(ControlEvent::TranslateEye(translate_sensitivity * dir));
+    ];
+    let speed_scale = dash.map_or(1.0, |mut dash| {
+        let tapped = movement_keys
+            .iter()
+            .find(|(key, _)| keyboard.just_pressed(*key))
+            .map(|&(_, dir)| dir);
+        dash.update(tapped, dt)
+    });
+    for (key, dir) in movement_keys {
+        // Shift + arrows pan with a keyboard navigation profile, so they shouldn't also move down.
+        let shift_pans = key == KeyCode::ShiftLeft
+            && routing.arrows_reserved()
+            && keyboard.any_pressed([
+                KeyCode::ArrowUp,
+                KeyCode::ArrowLeft,
+                KeyCode::ArrowDown,
+                KeyCode::ArrowRight,
+            ]);
+        if keyboard.pressed(key) && !shift_pans {
+            events.send(ControlEvent::translate_from_keys(
+                &controller,
+                dir,
+                speed_scale * dt,
+            ));
+        }
+    }
+
+    for event in mouse_wheel_reader.read() {
+        let lines = scroll_lines(event, controller.pixels_per_line).y;
+        if lines == 0.0 {
+            continue;
+        }
+        match controller.wheel_mode {
+            FpsWheelMode::Nothing => (),
+            FpsWheelMode::Dolly => {
+                events.send(ControlEvent::dolly_from_wheel(&controller, lines));
+            }
+            FpsWheelMode::Speed => {
+                let speed =
+                    controller.translate_sensitivity + controller.wheel_speed_sensitivity * lines;
+                controller.translate_sensitivity = speed.max(0.01);
+            }
         }
     }
 }
 
+/// Rotation for the [`FpsCameraController`] from motion sources other than the mouse, like a gyro or head tracking.
+///
+/// This is the slot for alternative input: a source system adds its yaw (X, positive to the right) and pitch (Y, positive
+/// down, like the mouse) in radians to the camera's `AimMotion` before [`aim_motion_input_map`], which turns it into a
+/// [`ControlEvent::Rotate`] and clears it. The [`AimZoom`] sensitivity scale is applied along the way.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct AimMotion(pub Vec2);
+
+/// Sends the [`AimMotion`] of the input camera as a [`ControlEvent::Rotate`].
+pub fn aim_motion_input_map(
+    mut events: EventWriter<ControlEvent>,
+    mut controllers: Query<(&FpsCameraController, &mut AimMotion, InputRoute)>,
+    aim_zooms: Query<(&AimZoom, Option<&Projection>)>,
+    routing: InputRouting,
+) {
+    let input_camera = controllers
+        .iter()
+        .find(|(c, .., route)| c.enabled && routing.accepts(route))
+        .map(|(.., route)| route.entity)
+        .filter(|_| !routing.is_blocked());
+    for (controller, mut motion, route) in controllers.iter_mut() {
+        // Motion that isn't sent is dropped, so it doesn't jump the camera once it has the input.
+        let motion = std::mem::take(&mut motion.bypass_change_detection().0);
+        if input_camera != Some(route.entity) || motion == Vec2::ZERO {
+            continue;
+        }
+        let scale = aim_zooms.get(route.entity).map_or(1.0, |(a, projection)| {
+            a.sensitivity_scale(controller, projection)
+        });
+        events.send(ControlEvent::Rotate(scale * motion));
+    }
+}
+
+/// Sends [`ControlEvent::Aim`] while the [`AimZoom`] button is held.
+pub fn aim_input_map(
+    mut events: EventWriter<ControlEvent>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    controllers: Query<(&FpsCameraController, Option<&AimZoom>, InputRoute)>,
+    routing: InputRouting,
+) {
+    if routing.is_blocked() {
+        return;
+    }
+    let aim_zoom = controllers
+        .iter()
+        .find(|(c, _, route)| c.enabled && routing.accepts(route))
+        .and_then(|(_, aim_zoom, _)| aim_zoom);
+    if aim_zoom.is_some_and(|a| mouse_buttons.pressed(a.button)) {
+        events.send(ControlEvent::Aim);
+    }
+}
+
 pub fn control_system(
     mut events: EventReader<ControlEvent>,
-    mut cameras: Query<(&FpsCameraController, &mut LookTransform)>,
-    time: Res<Time>,
+    mut targeted_events: EventReader<TargetedControlEvent<ControlEvent>>,
+    mut cameras: Query<(
+        &mut FpsCameraController,
+        &mut LookTransform,
+        Option<&AimZoom>,
+        InputRoute,
+    )>,
+    mut projections: Query<&mut Projection>,
+    mut status: ResMut<CameraControllerStatus>,
+    routing: InputRouting,
 ) {
-    // Can only control one camera at a time.
-    let mut transform = if let Some((_, transform)) = cameras.iter_mut().find(|c| c.0.enabled) {
-        transform
-    } else {
-        return;
-    };
+    // Only one camera has the input at a time.
+    let input_camera = cameras
+        .iter()
+        .find(|(c, _, _, route)| c.enabled && routing.accepts(route))
+        .map(|(.., route)| route.entity);
+    let mut batches = ControlEventBatches::read(&mut events, &mut targeted_events, input_camera);
+
+    for (mut controller, mut transform, aim_zoom, route) in cameras.iter_mut() {
+        let entity = route.entity;
+        // The angles are the controller's own bookkeeping, so don't let syncing them look like a user change.
+        let controller = controller.bypass_change_detection();
+        if controller.enabled && controller.angles_written() {
+            transform.target =
+                transform.eye + transform.radius() * controller.look_angles().unit_vector();
+        }
+        let Some(events) = batches.take(entity) else {
+            sync_look_angles(controller, &transform);
+            continue;
+        };
 
-    let look_vector = transform.look_direction().unwrap();
+        let aiming = events.contains(&ControlEvent::Aim);
+        let mut look_transform = *transform;
+        let mode = apply_events(controller, &mut look_transform, &events);
+        transform.set_if_neq(look_transform);
+
+        if let (Some(aim_zoom), Ok(projection)) = (aim_zoom, projections.get_mut(entity)) {
+            aim_zoom.zoom(projection, aiming);
+        }
+        sync_look_angles(controller, &transform);
+
+        if batches.is_input_camera(entity) {
+            status.set_if_neq(CameraControllerStatus {
+                entity: Some(entity),
+                mode,
+                speed: controller.translate_sensitivity,
+                zoom: transform.radius(),
+            });
+        }
+    }
+}
+
+/// Applies one frame of `events` to `transform`, and returns the kind of motion.
+///
+/// The math of the [`control_system`], without the ECS; see [`CameraController`](super::CameraController#control-systems).
+/// [`ControlEvent::Aim`] only affects the [`AimZoom`], so it's ignored.
+pub fn apply_events(
+    controller: &FpsCameraController,
+    transform: &mut LookTransform,
+    events: &[ControlEvent],
+) -> CameraControlMode {
+    // Without a look direction, e.g. because of NaNs, there's nothing to move from.
+    let Some(look_vector) = transform.look_direction() else {
+        return CameraControlMode::Idle;
+    };
     let mut look_angles = LookAngles::from_vector(look_vector);
+    // Taken before moving, so walking up to the target can't collapse the view.
+    let radius = transform.radius();
 
     let yaw_rot = Quat::from_axis_angle(Vec3::Y, look_angles.get_yaw());
     let rot_x = yaw_rot * Vec3::X;
-    let rot_y = yaw_rot * Vec3::Y;
-    let rot_z = yaw_rot * Vec3::Z;
+    let (rot_y, rot_z) = match controller.movement_mode {
+        MovementMode::Planar => (yaw_rot * Vec3::Y, yaw_rot * Vec3::Z),
+        MovementMode::FreeFly => (look_vector.cross(rot_x).normalize(), look_vector),
+    };
 
-    let dt = time.delta_secs();
-    for event in events.read() {
-        match event {
+    let mut mode = CameraControlMode::Idle;
+    for event in events {
+        match *event {
             ControlEvent::Rotate(delta) => {
+                mode = CameraControlMode::Look;
                 // Rotates with pitch and yaw.
-                look_angles.add_yaw(dt * -delta.x);
-                look_angles.add_pitch(dt * -delta.y);
+                look_angles.add_yaw(-delta.x);
+                look_angles.add_pitch(-delta.y);
             }
             ControlEvent::TranslateEye(delta) => {
+                mode = CameraControlMode::Fly;
                 // Translates up/down (Y) left/right (X) and forward/back (Z).
-                transform.eye += dt * delta.x * rot_x + dt * delta.y * rot_y + dt * delta.z * rot_z;
+                transform.eye += delta.x * rot_x + delta.y * rot_y + delta.z * rot_z;
+            }
+            ControlEvent::Dolly(distance) => {
+                mode = CameraControlMode::Fly;
+                transform.eye += distance * look_vector;
+            }
+            ControlEvent::Aim => {}
+        }
+    }
+
+    // Aiming alone doesn't move the camera.
+    if mode != CameraControlMode::Idle {
+        look_angles.assert_not_looking_up();
+
+        transform.target = transform.eye + radius * look_angles.unit_vector();
+    }
+    mode
+}
+
+/// Copies the look direction into the controller's `yaw` and `pitch`.
+fn sync_look_angles(controller: &mut FpsCameraController, transform: &LookTransform) {
+    let Some(direction) = transform.look_direction() else {
+        return;
+    };
+    let angles = LookAngles::from_vector(direction);
+    if controller.synced_angles != Some(Vec2::new(angles.get_yaw(), angles.get_pitch())) {
+        controller.sync_angles(angles);
+    }
+}
+
+/// Optional aim-down-sights zoom for the [`FpsCameraController`]: while `button` is held, a perspective `Projection`'s field
+/// of view eases from `normal_fov` to `zoomed_fov`, and back when it's released.
+///
+/// Unless the controller already has `fov_compensation`, mouse sensitivity is scaled down with the zoom, so the view turns
+/// by the same fraction of the screen.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct AimZoom {
+    pub button: MouseButton,
+    /// The vertical field of view when not aiming, in radians. This should match the `Projection`.
+    pub normal_fov: f32,
+    /// The vertical field of view while aiming, in radians.
+    pub zoomed_fov: f32,
+    /// How slowly the field of view follows the button, like a [`Smoother`] lag weight.
+    pub smoothing_weight: f32,
+    /// Whether to scale mouse sensitivity with the zoom.
+    pub scale_sensitivity: bool,
+}
+
+impl Default for AimZoom {
+    fn default() -> Self {
+        Self {
+            button: MouseButton::Right,
+            normal_fov: PerspectiveProjection::default().fov,
+            zoomed_fov: PerspectiveProjection::default().fov / 3.0,
+            smoothing_weight: 0.8,
+            scale_sensitivity: true,
+        }
+    }
+}
+
+impl AimZoom {
+    /// The factor that mouse motion is scaled by at the camera's current field of view.
+    pub fn sensitivity_scale(
+        &self,
+        controller: &FpsCameraController,
+        projection: Option<&Projection>,
+    ) -> f32 {
+        match projection {
+            Some(Projection::Perspective(perspective))
+                if self.scale_sensitivity && controller.fov_compensation.is_none() =>
+            {
+                (0.5 * perspective.fov).tan() / (0.5 * self.normal_fov).tan()
             }
+            _ => 1.0,
         }
     }
 
-    look_angles.assert_not_looking_up();
+    /// Eases the field of view one frame toward zoomed or normal.
+    fn zoom(&self, mut projection: Mut<Projection>, aiming: bool) {
+        let Projection::Perspective(perspective) = projection.bypass_change_detection() else {
+            return;
+        };
+        let goal = if aiming {
+            self.zoomed_fov
+        } else {
+            self.normal_fov
+        };
+        if perspective.fov == goal {
+            return;
+        }
+        let mut fov =
+            self.smoothing_weight * perspective.fov + (1.0 - self.smoothing_weight) * goal;
+        if (fov - goal).abs() <= 1e-4 * goal {
+            fov = goal;
+        }
+        perspective.fov = fov;
+        projection.set_changed();
+    }
+}
 
-    transform.target = transform.eye + transform.radius() * look_angles.unit_vector();
+/// Optional head-bob for the [`FpsCameraController`]. The bob is added to the smoothed `Transform`, so it never feeds back
+/// into the `LookTransform`.
+///
+/// The amplitude scales with the horizontal speed of the camera, reaching its full value at `reference_speed`. This relies on
+/// the `Transform` being rewritten by the `LookTransformPlugin` every frame, so the entity needs an enabled `Smoother`.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct HeadBob {
+    pub enabled: bool,
+    /// Peak vertical offset in world units.
+    pub vertical_amplitude: f32,
+    /// Peak side-to-side offset in world units.
+    pub lateral_amplitude: f32,
+    /// Footsteps per second at `reference_speed`. The vertical bob happens once per step, the lateral sway once per two.
+    pub frequency: f32,
+    /// Horizontal speed (units per second) at which the bob reaches full amplitude.
+    pub reference_speed: f32,
+    phase: f32,
+    last_position: Option<Vec3>,
+    applied_offset: Vec3,
+}
+
+impl Default for HeadBob {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            vertical_amplitude: 0.05,
+            lateral_amplitude: 0.03,
+            frequency: 2.0,
+            reference_speed: 2.0,
+            phase: 0.0,
+            last_position: None,
+            applied_offset: Vec3::ZERO,
+        }
+    }
+}
+
+impl HeadBob {
+    /// The offset for a given intensity in `[0, 1]`, relative to the camera's right and up axes.
+    fn offset(&self, intensity: f32) -> Vec2 {
+        Vec2::new(
+            self.lateral_amplitude * self.phase.sin(),
+            self.vertical_amplitude * self.phase.sin().abs(),
+        ) * intensity
+    }
+}
+
+pub fn head_bob_system(
+    time: CameraTime,
+    mut cameras: Query<
+        (
+            Entity,
+            &FpsCameraController,
+            &LookTransform,
+            &mut HeadBob,
+            &mut Transform,
+        ),
+        Without<XrDriven>,
+    >,
+) {
+    for (entity, controller, look_transform, mut head_bob, mut transform) in cameras.iter_mut() {
+        let dt = time.delta_secs(entity);
+        // A sleeping smoother leaves last frame's bob in the `Transform`, so take it back out.
+        let position = match head_bob.last_position {
+            Some(last) if transform.translation == last + head_bob.applied_offset => last,
+            _ => transform.translation,
+        };
+        let last_position = head_bob.last_position.replace(position);
+        transform.translation = position;
+        head_bob.applied_offset = Vec3::ZERO;
+
+        if !controller.enabled || !head_bob.enabled || dt <= 0.0 {
+            continue;
+        }
+
+        let up = look_transform.up.normalize_or_zero();
+        let velocity = (position - last_position.unwrap_or(position)) / dt;
+        let horizontal_speed = (velocity - velocity.dot(up) * up).length();
+        let intensity = (horizontal_speed / head_bob.reference_speed).min(1.0);
+
+        // One step is half of a period of the vertical |sin|.
+        head_bob.phase = (head_bob.phase + dt * intensity * head_bob.frequency * TAU / 2.0) % TAU;
+
+        let offset = head_bob.offset(intensity);
+        let right = transform.right();
+        head_bob.applied_offset = offset.x * right + offset.y * up;
+        transform.translation += head_bob.applied_offset;
+    }
+}
+
+/// Optional strafing tilt for the [`FpsCameraController`]: the camera rolls into sideways movement, like leaning into a
+/// strafe in many shooters. Like [`HeadBob`], the roll is added to the smoothed `Transform`, so it never feeds back into the
+/// `LookTransform`.
+///
+/// The velocity comes from the smoothed eye position, and the roll reaches `max_angle` at `reference_speed`.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct StrafeTilt {
+    pub enabled: bool,
+    /// The largest roll in radians, toward the direction of movement.
+    pub max_angle: f32,
+    /// Sideways speed (units per second) at which the roll reaches `max_angle`.
+    pub reference_speed: f32,
+    /// How quickly the roll follows the sideways speed, and returns to level, per second.
+    pub return_speed: f32,
+    angle: f32,
+    last_eye: Option<Vec3>,
+    last_rotation: Option<Quat>,
+}
+
+impl Default for StrafeTilt {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_angle: 2.5f32.to_radians(),
+            reference_speed: 2.0,
+            return_speed: 8.0,
+            angle: 0.0,
+            last_eye: None,
+            last_rotation: None,
+        }
+    }
+}
+
+impl StrafeTilt {
+    /// The current roll in radians, positive when leaning right.
+    pub fn angle(&self) -> f32 {
+        self.angle
+    }
+}
+
+pub fn strafe_tilt_system(
+    time: CameraTime,
+    mut cameras: Query<
+        (
+            Entity,
+            &FpsCameraController,
+            &LookTransform,
+            &mut StrafeTilt,
+            &mut Transform,
+        ),
+        Without<XrDriven>,
+    >,
+    smoothers: Query<&Smoother>,
+) {
+    for (entity, controller, look_transform, mut tilt, mut transform) in cameras.iter_mut() {
+        let dt = time.delta_secs(entity);
+        if dt <= 0.0 {
+            continue;
+        }
+        // A sleeping smoother leaves last frame's roll in the `Transform`, so take it back out.
+        let rotation = match tilt.last_rotation {
+            Some(last) if transform.rotation == last => last * Quat::from_rotation_z(tilt.angle),
+            _ => transform.rotation,
+        };
+
+        let eye = smoothers
+            .get(entity)
+            .ok()
+            .and_then(Smoother::smoothed)
+            .map_or(look_transform.eye, |smoothed| smoothed.eye);
+        let velocity = (eye - tilt.last_eye.unwrap_or(eye)) / dt;
+        tilt.last_eye = Some(eye);
+
+        let goal = if controller.enabled && tilt.enabled && tilt.reference_speed > 0.0 {
+            let sideways_speed = velocity.dot(rotation * Vec3::X);
+            tilt.max_angle * (sideways_speed / tilt.reference_speed).clamp(-1.0, 1.0)
+        } else {
+            0.0
+        };
+        let follow = 1.0 - (-tilt.return_speed * dt).exp();
+        tilt.angle += (goal - tilt.angle) * follow;
+
+        let tilted = rotation * Quat::from_rotation_z(-tilt.angle);
+        if transform.rotation != tilted {
+            transform.rotation = tilted;
+        }
+        tilt.last_rotation = Some(tilted);
+    }
+}
+
+/// Flick stick aiming with a gamepad's right stick for the [`FpsCameraController`], as popular with controller players.
+///
+/// Pushing the stick to the edge flicks the camera to face the direction it points in, relative to the current view, over
+/// `flick_duration` seconds. Rotating the stick while it's held then keeps turning by as much as the stick turns. Only the
+/// yaw is controlled, so pitch is left to other input, like a gyro or the mouse.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct FlickStick {
+    pub enabled: bool,
+    /// How far the stick must be pushed, from `0.0` to `1.0`, to flick and keep turning.
+    pub threshold: f32,
+    /// How long a flick takes, in seconds. It eases out, so most of the turn happens right away.
+    pub flick_duration: f32,
+    last_angle: Option<f32>,
+    flick_angle: f32,
+    flick_turned: f32,
+    flick_elapsed: f32,
+}
+
+impl Default for FlickStick {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            threshold: 0.9,
+            flick_duration: 0.1,
+            last_angle: None,
+            flick_angle: 0.0,
+            flick_turned: 0.0,
+            flick_elapsed: 0.0,
+        }
+    }
+}
+
+impl FlickStick {
+    /// Processes the stick position for a frame of `dt` seconds, and returns the angle to turn by in radians, positive to
+    /// the right.
+    pub fn update(&mut self, stick: Vec2, dt: f32) -> f32 {
+        let mut turn = 0.0;
+        if self.enabled && stick.length() >= self.threshold {
+            // Clockwise from forward, like the turn.
+            let angle = stick.x.atan2(stick.y);
+            match self.last_angle {
+                Some(last_angle) => {
+                    turn += (angle - last_angle + PI).rem_euclid(TAU) - PI;
+                }
+                None => {
+                    // Finish any flick that's still going before starting the next one.
+                    turn += self.flick_angle - self.flick_turned;
+                    self.flick_angle = angle;
+                    self.flick_turned = 0.0;
+                    self.flick_elapsed = 0.0;
+                }
+            }
+            self.last_angle = Some(angle);
+        } else {
+            self.last_angle = None;
+        }
+
+        if self.flick_angle != 0.0 {
+            self.flick_elapsed += dt;
+            let t = if self.flick_duration > 0.0 {
+                self.flick_elapsed / self.flick_duration
+            } else {
+                1.0
+            };
+            let turned = self.flick_angle * Ease::CubicOut.sample(t);
+            turn += turned - self.flick_turned;
+            self.flick_turned = turned;
+            if t >= 1.0 {
+                self.flick_angle = 0.0;
+                self.flick_turned = 0.0;
+            }
+        }
+        turn
+    }
+
+    /// Whether a flick is still turning the camera.
+    pub fn is_flicking(&self) -> bool {
+        self.flick_angle != 0.0
+    }
+}
+
+/// Turns the cameras with a [`FlickStick`] with the right stick of a gamepad: a camera with a [`PlayerInput::Gamepad`] uses
+/// that gamepad, and the input camera uses the first gamepad that isn't assigned to another camera.
+pub fn flick_stick_input_map(
+    mut events: EventWriter<ControlEvent>,
+    mut targeted_events: EventWriter<TargetedControlEvent<ControlEvent>>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    mut controllers: Query<(&FpsCameraController, &mut FlickStick, InputRoute)>,
+    routing: InputRouting,
+) {
+    if routing.is_blocked() {
+        return;
+    }
+    let input_camera = controllers
+        .iter()
+        .find(|(c, _, route)| c.enabled && routing.accepts(route))
+        .map(|(.., route)| route.entity);
+    let assigned: Vec<Entity> = controllers
+        .iter()
+        .filter_map(|(.., route)| route.player.and_then(PlayerInput::gamepad))
+        .collect();
+
+    for (controller, mut flick_stick, route) in controllers.iter_mut() {
+        let player_gamepad = route.player.and_then(PlayerInput::gamepad);
+        let gamepad = match player_gamepad {
+            Some(gamepad) => gamepads.get(gamepad).ok(),
+            None if input_camera == Some(route.entity) => gamepads
+                .iter()
+                .find(|(gamepad, _)| !assigned.contains(gamepad)),
+            None => continue,
+        };
+        if !controller.enabled {
+            continue;
+        }
+        let dt = routing.delta_secs(&route);
+        let stick = gamepad.map_or(Vec2::ZERO, |(_, gamepad)| gamepad.right_stick());
+        let turn = flick_stick.update(stick, dt);
+        if turn == 0.0 {
+            continue;
+        }
+        let event = ControlEvent::Rotate(Vec2::new(turn, 0.0));
+        match player_gamepad {
+            // Plain events only go to the input camera.
+            Some(_) => {
+                targeted_events.send(TargetedControlEvent::new(route.entity, event));
+            }
+            None => {
+                events.send(event);
+            }
+        }
+    }
+}
+
+/// Optional double-tap dash for the [`FpsCameraController`], to cross large scenes quickly: tapping a movement key twice
+/// within `tap_window` seconds multiplies the movement speed by `speed_multiplier` for `duration` seconds. Another dash can
+/// start `cooldown` seconds after one ends.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct Dash {
+    pub enabled: bool,
+    /// Which movement keys dash when double tapped.
+    pub directions: DashDirections,
+    /// The longest time between the two taps, in seconds.
+    pub tap_window: f32,
+    pub speed_multiplier: f32,
+    pub duration: f32,
+    pub cooldown: f32,
+    last_tap: Option<Vec3>,
+    since_tap: f32,
+    dash_remaining: f32,
+    cooldown_remaining: f32,
+}
+
+impl Default for Dash {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            directions: DashDirections::default(),
+            tap_window: 0.3,
+            speed_multiplier: 4.0,
+            duration: 0.5,
+            cooldown: 1.0,
+            last_tap: None,
+            since_tap: 0.0,
+            dash_remaining: 0.0,
+            cooldown_remaining: 0.0,
+        }
+    }
+}
+
+impl Dash {
+    /// Processes the movement direction whose key was `tapped` this frame, like [`ControlEvent::TranslateEye`], for a
+    /// frame of `dt` seconds, and returns the factor to multiply the movement speed by.
+    pub fn update(&mut self, tapped: Option<Vec3>, dt: f32) -> f32 {
+        self.dash_remaining = (self.dash_remaining - dt).max(0.0);
+        self.cooldown_remaining = (self.cooldown_remaining - dt).max(0.0);
+        self.since_tap += dt;
+
+        if let Some(direction) = tapped.filter(|_| self.enabled) {
+            let double_tap = self.last_tap == Some(direction) && self.since_tap <= self.tap_window;
+            if double_tap && self.directions.allows(direction) && self.cooldown_remaining == 0.0 {
+                self.dash_remaining = self.duration;
+                self.cooldown_remaining = self.duration + self.cooldown;
+                // A third tap starts counting again.
+                self.last_tap = None;
+            } else {
+                self.last_tap = Some(direction);
+                self.since_tap = 0.0;
+            }
+        }
+
+        if self.is_dashing() {
+            self.speed_multiplier
+        } else {
+            1.0
+        }
+    }
+
+    /// Whether a dash is still speeding up movement.
+    pub fn is_dashing(&self) -> bool {
+        self.enabled && self.dash_remaining > 0.0
+    }
+}
+
+/// The movement directions that a [`Dash`] works in. By default only horizontal movement dashes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub struct DashDirections {
+    pub forward: bool,
+    pub back: bool,
+    pub left: bool,
+    pub right: bool,
+    pub up: bool,
+    pub down: bool,
+}
+
+impl Default for DashDirections {
+    fn default() -> Self {
+        Self {
+            forward: true,
+            back: true,
+            left: true,
+            right: true,
+            up: false,
+            down: false,
+        }
+    }
+}
+
+impl DashDirections {
+    pub fn all() -> Self {
+        Self {
+            up: true,
+            down: true,
+            ..Default::default()
+        }
+    }
+
+    /// Whether a movement `direction`, like [`ControlEvent::TranslateEye`], dashes.
+    pub fn allows(&self, direction: Vec3) -> bool {
+        (direction.z > 0.0 && self.forward)
+            || (direction.z < 0.0 && self.back)
+            || (direction.x > 0.0 && self.left)
+            || (direction.x < 0.0 && self.right)
+            || (direction.y > 0.0 && self.up)
+            || (direction.y < 0.0 && self.down)
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    #[test]
+    fn test_flick_stick_flicks_then_follows_rotation() {
+        let mut flick_stick = FlickStick::default();
+        let dt = 0.02;
+
+        // Flick to the right, which turns a quarter turn over the flick duration.
+        let mut turned = flick_stick.update(Vec2::X, dt);
+        assert!(turned > 0.0 && turned < FRAC_PI_2);
+        while flick_stick.is_flicking() {
+            turned += flick_stick.update(Vec2::X, dt);
+        }
+        assert_relative_eq!(turned, FRAC_PI_2, epsilon = 1e-5);
+
+        // Rotating the stick back toward forward keeps turning by the same angle.
+        let back = flick_stick.update(Vec2::new(1.0, 1.0).normalize(), dt);
+        assert_relative_eq!(back, -FRAC_PI_2 / 2.0, epsilon = 1e-5);
+
+        // Releasing the stick stops turning.
+        assert_eq!(flick_stick.update(Vec2::ZERO, dt), 0.0);
+    }
+
+    #[test]
+    fn test_dash_on_double_tap_with_cooldown() {
+        let mut dash = Dash::default();
+        let dt = 0.0625;
+
+        // Taps that are too slow, or in different directions, don't dash.
+        assert_eq!(dash.update(Some(Vec3::Z), dt), 1.0);
+        for _ in 0..10 {
+            dash.update(None, dt);
+        }
+        assert_eq!(dash.update(Some(Vec3::Z), dt), 1.0);
+        assert_eq!(dash.update(Some(-Vec3::X), dt), 1.0);
+
+        // A quick second tap dashes for the duration.
+        assert_eq!(dash.update(Some(-Vec3::X), dt), dash.speed_multiplier);
+        let mut frames = 1;
+        while dash.update(None, dt) > 1.0 {
+            frames += 1;
+        }
+        assert_eq!(frames, 8);
+
+        // Not again until the cooldown is over.
+        dash.update(Some(Vec3::Z), dt);
+        assert_eq!(dash.update(Some(Vec3::Z), dt), 1.0);
+        for _ in 0..20 {
+            dash.update(None, dt);
+        }
+        dash.update(Some(Vec3::Z), dt);
+        assert_eq!(dash.update(Some(Vec3::Z), dt), dash.speed_multiplier);
+
+        // Up isn't a dash direction by default.
+        let mut dash = Dash::default();
+        dash.update(Some(Vec3::Y), dt);
+        assert_eq!(dash.update(Some(Vec3::Y), dt), 1.0);
+    }
+
+    #[test]
+    fn test_apply_events_turns_and_moves_without_an_app() {
+        let controller = FpsCameraController::default();
+        let mut transform = LookTransform::new(Vec3::ZERO, -Vec3::Z, Vec3::Y);
+        let events = [
+            ControlEvent::Rotate(Vec2::new(FRAC_PI_4, 0.0)),
+            ControlEvent::TranslateEye(Vec3::new(0.0, 0.0, 1.0)),
+        ];
+        let mode = apply_events(&controller, &mut transform, &events);
+
+        assert_eq!(mode, CameraControlMode::Fly);
+        // Moves along the direction it faced at the start of the frame, and turns an eighth of a turn to the right.
+        assert!(
+            transform.eye.abs_diff_eq(-Vec3::Z, 1e-5),
+            "{}",
+            transform.eye
+        );
+        let look_direction = transform.look_direction().unwrap();
+        assert!(look_direction.abs_diff_eq(Vec3::new(1.0, 0.0, -1.0).normalize(), 1e-5));
+    }
 }
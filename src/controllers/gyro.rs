@@ -0,0 +1,123 @@
+use crate::{
+    clock::CameraTime,
+    controllers::fps::{aim_motion_input_map, AimMotion, FpsCameraPlugin},
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::{prelude::*, schedule::ScheduleLabel},
+    input::prelude::*,
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+};
+
+/// Aims the [`FpsCameraController`](crate::controllers::fps::FpsCameraController) with a gamepad's gyro, like on the Steam
+/// Deck or a DualSense, for precise aiming on top of the sticks.
+///
+/// Bevy doesn't read gyros, so the app feeds the [`GyroReading`] from its platform's sensor API every frame. The cameras
+/// with a [`GyroAim`] then turn through their [`AimMotion`], in the input schedule of the [`FpsCameraPlugin`].
+pub struct GyroAimPlugin;
+
+impl Plugin for GyroAimPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GyroReading>();
+    }
+
+    // After every plugin is built, so the `FpsCameraPlugin` can be added in any order.
+    fn finish(&self, app: &mut App) {
+        let input_schedule = app
+            .get_added_plugins::<FpsCameraPlugin>()
+            .first()
+            .map_or(Update.intern(), |fps| fps.input_schedule);
+        app.add_systems(input_schedule, gyro_aim_system.before(aim_motion_input_map));
+    }
+}
+
+/// The latest angular velocity of the gamepad's gyro in radians per second, in the gamepad's frame: X to the right, Y up,
+/// and Z toward the player. Positive values turn counterclockwise about each axis, so `+y` turns the gamepad left.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Resource, Default, Debug, PartialEq)]
+pub struct GyroReading {
+    pub angular_velocity: Vec3,
+}
+
+/// Which of the gamepad's rotations turns the camera left and right.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum GyroYawAxis {
+    /// Turning the gamepad like a steering wheel held flat.
+    #[default]
+    Yaw,
+    /// Tilting the gamepad sideways like a steering wheel.
+    Roll,
+    /// Both, so it doesn't matter how the gamepad is held.
+    YawAndRoll,
+}
+
+/// Turns the camera with the [`GyroReading`]. See [`GyroAimPlugin`].
+#[derive(Clone, Component, Copy, Debug, PartialEq, Reflect)]
+#[require(AimMotion)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct GyroAim {
+    pub enabled: bool,
+    /// How many radians the camera turns for each radian the gamepad turns, for yaw (X) and pitch (Y).
+    pub sensitivity: Vec2,
+    pub yaw_axis: GyroYawAxis,
+    pub invert_pitch: bool,
+    /// Each press of this button on any gamepad turns the gyro off or back on, e.g. to re-center the gamepad.
+    pub toggle_button: Option<GamepadButton>,
+}
+
+impl Default for GyroAim {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            sensitivity: Vec2::splat(1.5),
+            yaw_axis: GyroYawAxis::default(),
+            invert_pitch: false,
+            toggle_button: None,
+        }
+    }
+}
+
+impl GyroAim {
+    /// The camera's yaw (positive right) and pitch (positive down) in radians, for the gamepad turning at
+    /// `angular_velocity` for `dt` seconds.
+    pub fn aim_motion(&self, angular_velocity: Vec3, dt: f32) -> Vec2 {
+        let yaw = match self.yaw_axis {
+            GyroYawAxis::Yaw => angular_velocity.y,
+            GyroYawAxis::Roll => angular_velocity.z,
+            GyroYawAxis::YawAndRoll => angular_velocity.y + angular_velocity.z,
+        };
+        let pitch = if self.invert_pitch {
+            angular_velocity.x
+        } else {
+            -angular_velocity.x
+        };
+        // Turning the gamepad left turns the camera left.
+        dt * self.sensitivity * Vec2::new(-yaw, pitch)
+    }
+}
+
+/// Handles the [`GyroAim::toggle_button`], and adds the gyro's rotation to each enabled camera's [`AimMotion`].
+pub fn gyro_aim_system(
+    time: CameraTime,
+    reading: Res<GyroReading>,
+    gamepads: Query<&Gamepad>,
+    mut cameras: Query<(Entity, &mut GyroAim, &mut AimMotion)>,
+) {
+    for (entity, mut gyro_aim, mut motion) in cameras.iter_mut() {
+        if let Some(button) = gyro_aim.toggle_button {
+            if gamepads.iter().any(|g| g.just_pressed(button)) {
+                gyro_aim.enabled = !gyro_aim.enabled;
+            }
+        }
+        if gyro_aim.enabled {
+            let dt = time.delta_secs(entity);
+            motion.0 += gyro_aim.aim_motion(reading.angular_velocity, dt);
+        }
+    }
+}
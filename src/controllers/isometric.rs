@@ -0,0 +1,452 @@
+use crate::{
+    clock::CameraTime,
+    controllers::{
+        controller_plugin, edge_pan::EdgePan, scroll_lines, smooth_scale, CameraBundleBuilder,
+        CameraControlMode, CameraController, CameraControllerStatus, ControlEventBatches,
+        ControllerKind, InputRoute, InputRouting, TargetedControlEvent, DEFAULT_PIXELS_PER_LINE,
+    },
+    LookAngles, LookTransform, LookTransformBundle, LookTransformSet, Smoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::{bundle::Bundle, prelude::*},
+    input::{
+        mouse::{MouseMotion, MouseWheel},
+        prelude::*,
+    },
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    render::camera::{CameraUpdateSystem, Projection},
+    transform::components::Transform,
+};
+
+use std::f32::consts::PI;
+
+#[derive(Default)]
+pub struct IsometricCameraPlugin {
+    pub override_input_system: bool,
+}
+
+impl IsometricCameraPlugin {
+    pub fn new(override_input_system: bool) -> Self {
+        Self {
+            override_input_system,
+        }
+    }
+}
+
+impl Plugin for IsometricCameraPlugin {
+    fn build(&self, app: &mut App) {
+        let app = app
+            .add_plugins(controller_plugin::<IsometricCameraController>())
+            .add_systems(Update, control_system)
+            .add_systems(
+                PostUpdate,
+                ortho_scale_system
+                    .after(LookTransformSet::Sync)
+                    .before(CameraUpdateSystem),
+            );
+
+        if !self.override_input_system {
+            app.add_systems(Update, default_input_map);
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct IsometricCameraBundle {
+    controller: IsometricCameraController,
+    look_transform: LookTransformBundle,
+    transform: Transform,
+}
+
+impl IsometricCameraBundle {
+    /// Starts with the eye 10 units away from the origin, at the default fixed angles.
+    pub fn builder() -> CameraBundleBuilder<IsometricCameraController> {
+        let controller = IsometricCameraController::default();
+        let eye = 10.0 * controller.look_angles().unit_vector();
+        CameraBundleBuilder::new(controller, eye, Vec3::ZERO, Vec3::Y)
+    }
+
+    /// Places the eye at `distance` from `target`, at the controller's fixed angles.
+    pub fn new(controller: IsometricCameraController, target: Vec3, distance: f32) -> Self {
+        let eye = target + distance * controller.look_angles().unit_vector();
+        // Make sure the transform is consistent with the controller to start.
+        let transform = Transform::from_translation(eye).looking_at(target, Vec3::Y);
+
+        Self {
+            controller,
+            look_transform: LookTransformBundle {
+                transform: LookTransform::new(eye, target, Vec3::Y),
+                smoother: Smoother::new(controller.smoothing_weight),
+            },
+            transform,
+        }
+    }
+}
+
+/// A game camera with fixed viewing angles, like in isometric strategy games. It pans along the ground plane, rotates in
+/// discrete steps, and zooms according to its [`IsometricZoom`].
+///
+/// Changing `yaw` or `pitch` at runtime animates the camera to the new angles at `rotation_speed`.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct IsometricCameraController {
+    pub enabled: bool,
+    /// The angle in radians about the Y axis of the direction from the target to the eye.
+    pub yaw: f32,
+    /// The angle in radians that the camera looks down at the ground.
+    pub pitch: f32,
+    /// The yaw that each press of Q/E rotates by.
+    pub rotation_step: f32,
+    /// How fast the camera turns toward `yaw` and `pitch`, in radians per second.
+    pub rotation_speed: f32,
+    /// World units per second that the pan keys move.
+    pub keyboard_pan_speed: f32,
+    /// World units per pixel of mouse motion.
+    pub mouse_translate_sensitivity: Vec2,
+    pub mouse_wheel_zoom_sensitivity: f32,
+    pub pixels_per_line: f32,
+    pub smoothing_weight: f32,
+    pub zoom: IsometricZoom,
+    /// The orthographic scale that the projection is smoothed toward, for [`IsometricZoom::OrthoScale`].
+    pub scale: f32,
+    /// The lower limit of the eye distance or orthographic scale, depending on the [`IsometricZoom`].
+    pub min_zoom: f32,
+    /// The upper limit of the eye distance or orthographic scale, depending on the [`IsometricZoom`].
+    pub max_zoom: f32,
+}
+
+impl CameraController for IsometricCameraController {
+    const KIND: ControllerKind = ControllerKind::Isometric;
+    type Event = ControlEvent;
+
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn smoothing_weight(&self) -> f32 {
+        self.smoothing_weight
+    }
+
+    /// Takes the angles of the current view, rather than turning back to the old ones.
+    fn reseed(&mut self, transform: &LookTransform) {
+        if let Some(look_direction) = transform.look_direction() {
+            let angles = LookAngles::from_vector(-look_direction);
+            self.yaw = angles.get_yaw();
+            self.pitch = angles.get_pitch();
+        }
+    }
+}
+
+impl Default for IsometricCameraController {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            yaw: PI / 4.0,
+            pitch: PI / 6.0,
+            rotation_step: PI / 2.0,
+            rotation_speed: PI,
+            keyboard_pan_speed: 10.0,
+            mouse_translate_sensitivity: Vec2::splat(0.0083),
+            mouse_wheel_zoom_sensitivity: 0.2,
+            pixels_per_line: DEFAULT_PIXELS_PER_LINE,
+            smoothing_weight: 0.8,
+            zoom: IsometricZoom::Dolly,
+            scale: 1.0,
+            min_zoom: 0.01,
+            max_zoom: 1000.0,
+        }
+    }
+}
+
+impl IsometricCameraController {
+    /// The direction from the target to the eye at the controller's fixed angles.
+    pub fn look_angles(&self) -> LookAngles {
+        let mut angles = LookAngles::default();
+        angles.set_yaw(self.yaw);
+        angles.set_pitch(self.pitch);
+        angles
+    }
+}
+
+/// How an [`IsometricCameraController`] zooms.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum IsometricZoom {
+    /// Moves the eye toward or away from the target.
+    #[default]
+    Dolly,
+    /// Scales an orthographic `Projection`, keeping the eye distance fixed.
+    OrthoScale,
+}
+
+/// One frame of input for an [`IsometricCameraController`], as described on [`CameraController::Event`].
+#[derive(Clone, Copy, Debug, Event, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ControlEvent {
+    /// Moves the target and eye along the ground, right (X) and forward (Y), by world space distances.
+    Pan(Vec2),
+    /// Rotates the yaw by a number of `rotation_step`s.
+    Rotate(i32),
+    /// Multiplies the eye distance or orthographic scale, depending on the [`IsometricZoom`].
+    Zoom(f32),
+}
+
+impl ControlEvent {
+    /// Pans in a `direction` along the ground at the controller's `keyboard_pan_speed` for `dt` seconds.
+    pub fn pan_from_keys(controller: &IsometricCameraController, direction: Vec2, dt: f32) -> Self {
+        Self::Pan(dt * controller.keyboard_pan_speed * direction.normalize_or_zero())
+    }
+
+    /// Drags the ground along with a mouse motion of `cursor_delta` pixels.
+    pub fn pan_from_mouse(controller: &IsometricCameraController, cursor_delta: Vec2) -> Self {
+        // The cursor's Y points down the screen.
+        Self::Pan(
+            controller.mouse_translate_sensitivity * Vec2::new(-cursor_delta.x, cursor_delta.y),
+        )
+    }
+
+    /// Zooms by `lines` of mouse wheel scrolling, where positive lines zoom in.
+    pub fn zoom_from_wheel(controller: &IsometricCameraController, lines: f32) -> Self {
+        Self::Zoom(1.0 - lines * controller.mouse_wheel_zoom_sensitivity)
+    }
+}
+
+pub fn default_input_map(
+    mut events: EventWriter<ControlEvent>,
+    mut mouse_wheel_reader: EventReader<MouseWheel>,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    controllers: Query<(&IsometricCameraController, Option<&EdgePan>, InputRoute)>,
+    routing: InputRouting,
+) {
+    if routing.is_blocked() {
+        mouse_wheel_reader.clear();
+        mouse_motion_events.clear();
+        return;
+    }
+
+    // Can only control one camera at a time.
+    let (controller, edge_pan, route) = if let Some(controller) = controllers
+        .iter()
+        .find(|(c, _, route)| c.enabled && routing.accepts(route))
+    {
+        controller
+    } else {
+        return;
+    };
+    let pixels_per_line = controller.pixels_per_line;
+
+    let mut cursor_delta = Vec2::ZERO;
+    for event in mouse_motion_events.read() {
+        cursor_delta += event.delta;
+    }
+
+    let dt = routing.delta_secs(&route);
+    let mut pan = Vec2::ZERO;
+    for (key, dir) in [
+        (KeyCode::KeyW, Vec2::Y),
+        (KeyCode::KeyA, -Vec2::X),
+        (KeyCode::KeyS, -Vec2::Y),
+        (KeyCode::KeyD, Vec2::X),
+        (KeyCode::ArrowUp, Vec2::Y),
+        (KeyCode::ArrowLeft, -Vec2::X),
+        (KeyCode::ArrowDown, -Vec2::Y),
+        (KeyCode::ArrowRight, Vec2::X),
+    ]
+    .iter()
+    .cloned()
+    {
+        let arrow = matches!(
+            key,
+            KeyCode::ArrowUp | KeyCode::ArrowLeft | KeyCode::ArrowDown | KeyCode::ArrowRight
+        );
+        if keyboard.pressed(key) && !(arrow && routing.arrows_reserved()) {
+            pan += dir;
+        }
+    }
+    if pan != Vec2::ZERO {
+        events.send(ControlEvent::pan_from_keys(controller, pan, dt));
+    }
+    if let Some(velocity) = edge_pan.map(EdgePan::velocity) {
+        if velocity != Vec2::ZERO {
+            events.send(ControlEvent::Pan(dt * velocity));
+        }
+    }
+    if mouse_buttons.pressed(MouseButton::Middle) && cursor_delta != Vec2::ZERO {
+        events.send(ControlEvent::pan_from_mouse(controller, cursor_delta));
+    }
+
+    let mut steps = 0;
+    if keyboard.just_pressed(KeyCode::KeyQ) {
+        steps += 1;
+    }
+    if keyboard.just_pressed(KeyCode::KeyE) {
+        steps -= 1;
+    }
+    if steps != 0 {
+        events.send(ControlEvent::Rotate(steps));
+    }
+
+    for event in mouse_wheel_reader.read() {
+        let scroll_amount = scroll_lines(event, pixels_per_line).y;
+        if scroll_amount != 0.0 {
+            events.send(ControlEvent::zoom_from_wheel(controller, scroll_amount));
+        }
+    }
+}
+
+pub fn control_system(
+    time: CameraTime,
+    mut events: EventReader<ControlEvent>,
+    mut targeted_events: EventReader<TargetedControlEvent<ControlEvent>>,
+    mut cameras: Query<(
+        &mut IsometricCameraController,
+        &mut LookTransform,
+        InputRoute,
+    )>,
+    mut status: ResMut<CameraControllerStatus>,
+    routing: InputRouting,
+) {
+    // Only one camera has the input at a time.
+    let input_camera = cameras
+        .iter()
+        .find(|(c, _, route)| c.enabled && routing.accepts(route))
+        .map(|(.., route)| route.entity);
+    let mut batches = ControlEventBatches::read(&mut events, &mut targeted_events, input_camera);
+
+    for (mut controller, mut transform, route) in cameras.iter_mut() {
+        let entity = route.entity;
+        let dt = time.delta_secs(entity);
+        let Some(events) = batches.take(entity) else {
+            continue;
+        };
+
+        let mut look_transform = *transform;
+        // The angles and scale are the controller's own state, so don't let them look like a user change.
+        let control_mode = apply_events(
+            controller.bypass_change_detection(),
+            &mut look_transform,
+            &events,
+            dt,
+        );
+        transform.set_if_neq(look_transform);
+
+        if batches.is_input_camera(entity) {
+            status.set_if_neq(CameraControllerStatus {
+                entity: Some(entity),
+                mode: control_mode,
+                speed: 0.0,
+                zoom: match controller.zoom {
+                    IsometricZoom::Dolly => transform.radius(),
+                    IsometricZoom::OrthoScale => controller.scale,
+                },
+            });
+        }
+    }
+}
+
+/// Applies one frame of `events`, `dt` seconds long, to `transform`, and returns the kind of motion. Without events, this
+/// keeps turning toward the controller's fixed angles.
+///
+/// The math of the [`control_system`], without the ECS; see [`CameraController`](super::CameraController#control-systems).
+pub fn apply_events(
+    controller: &mut IsometricCameraController,
+    transform: &mut LookTransform,
+    events: &[ControlEvent],
+    dt: f32,
+) -> CameraControlMode {
+    let Some(look_direction) = transform.look_direction() else {
+        return CameraControlMode::Idle;
+    };
+
+    let mut look_angles = LookAngles::from_vector(-look_direction);
+    let goal_angles = controller.look_angles();
+    // The angles recovered from the look direction are only accurate to about a milliradian.
+    let turning = angle_difference(look_angles.get_yaw(), goal_angles.get_yaw()).abs() > 1e-3
+        || (goal_angles.get_pitch() - look_angles.get_pitch()).abs() > 1e-3;
+    if events.is_empty() && !turning {
+        return CameraControlMode::Idle;
+    }
+
+    let mut radius = transform.radius();
+    let forward = -(Quat::from_rotation_y(look_angles.get_yaw()) * Vec3::Z);
+    let right = forward.cross(Vec3::Y);
+
+    let mut control_mode = if turning {
+        CameraControlMode::Orbit
+    } else {
+        CameraControlMode::Idle
+    };
+    for event in events {
+        match *event {
+            ControlEvent::Pan(delta) => {
+                control_mode = CameraControlMode::Pan;
+                let translation = delta.x * right + delta.y * forward;
+                transform.target += translation;
+                transform.eye += translation;
+            }
+            ControlEvent::Rotate(steps) => {
+                control_mode = CameraControlMode::Orbit;
+                controller.yaw =
+                    (controller.yaw + steps as f32 * controller.rotation_step) % (2.0 * PI);
+            }
+            ControlEvent::Zoom(scalar) => {
+                control_mode = CameraControlMode::Zoom;
+                let (min, max) = (controller.min_zoom, controller.max_zoom);
+                match controller.zoom {
+                    IsometricZoom::Dolly => radius = (radius * scalar).clamp(min, max),
+                    IsometricZoom::OrthoScale => {
+                        controller.scale = (controller.scale * scalar).clamp(min, max);
+                    }
+                }
+            }
+        }
+    }
+
+    // Turn toward the fixed angles at a constant speed, the short way around.
+    let goal_angles = controller.look_angles();
+    let max_turn = controller.rotation_speed * dt;
+    let yaw_turn = angle_difference(look_angles.get_yaw(), goal_angles.get_yaw());
+    let pitch_turn = goal_angles.get_pitch() - look_angles.get_pitch();
+    look_angles.add_yaw(yaw_turn.clamp(-max_turn, max_turn));
+    look_angles.add_pitch(pitch_turn.clamp(-max_turn, max_turn));
+
+    transform.eye = transform.target + radius * look_angles.unit_vector();
+    control_mode
+}
+
+/// The signed angle that turns `from` into `to`, in `[-PI, PI)`.
+fn angle_difference(from: f32, to: f32) -> f32 {
+    (to - from + PI).rem_euclid(2.0 * PI) - PI
+}
+
+/// Smooths an orthographic `Projection` toward [`IsometricCameraController::scale`], for [`IsometricZoom::OrthoScale`].
+pub fn ortho_scale_system(
+    mut cameras: Query<(
+        &IsometricCameraController,
+        &mut Projection,
+        Option<&Smoother>,
+    )>,
+) {
+    for (controller, mut projection, smoother) in cameras.iter_mut() {
+        if controller.zoom != IsometricZoom::OrthoScale {
+            continue;
+        }
+        let Projection::Orthographic(ortho) = projection.as_ref() else {
+            continue;
+        };
+        let scale = smooth_scale(ortho.scale, controller.scale, smoother);
+        if ortho.scale != scale {
+            if let Projection::Orthographic(ortho) = projection.as_mut() {
+                ortho.scale = scale;
+            }
+        }
+    }
+}
@@ -0,0 +1,435 @@
+use crate::{
+    controllers::{
+        fps::{self, FpsCameraController},
+        isometric::{self, IsometricCameraController},
+        maya::{self, MayaCameraController},
+        orbit::{self, OrbitCameraController},
+        pancam2d::{self, PanCam2dController},
+        unreal::{self, UnrealCameraController},
+        InputRoute, InputRouting,
+    },
+    LookTransform,
+};
+
+use bevy::{
+    app::prelude::*, ecs::prelude::*, input::prelude::*, math::prelude::*, prelude::ReflectDefault,
+    reflect::Reflect, render::camera::OrthographicProjection,
+};
+
+/// An accessibility profile that lets every built-in controller be driven with the keyboard alone:
+///
+/// - Arrows: Rotate the camera, or step the isometric camera around
+/// - Shift + arrows: Pan the camera
+/// - +/-: Zoom in/out
+/// - `[`/`]`: Change the [`NavigationSpeedScale`]
+///
+/// While the [`KeyboardNavigation`] is enabled, the default input maps leave the arrows to this profile, so they don't
+/// also pan the isometric camera, step the orbit camera, or let Shift move the FPS camera down.
+pub struct KeyboardNavigationPlugin;
+
+impl Plugin for KeyboardNavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<KeyboardNavigation>()
+            .init_resource::<NavigationSpeedScale>()
+            .add_systems(Update, speed_scale_input_system);
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Only the controller plugins that were added have events to send.
+        macro_rules! add_if_added {
+            ($controller:ident, $system:ident) => {
+                if app
+                    .world()
+                    .contains_resource::<Events<$controller::ControlEvent>>()
+                {
+                    app.add_systems(
+                        Update,
+                        $system
+                            .after(speed_scale_input_system)
+                            .before($controller::control_system),
+                    );
+                }
+            };
+        }
+        add_if_added!(fps, fps_keyboard_map);
+        add_if_added!(isometric, isometric_keyboard_map);
+        add_if_added!(maya, maya_keyboard_map);
+        add_if_added!(orbit, orbit_keyboard_map);
+        add_if_added!(pancam2d, pancam2d_keyboard_map);
+        add_if_added!(unreal, unreal_keyboard_map);
+    }
+}
+
+/// Speeds of the [`KeyboardNavigationPlugin`], before the [`NavigationSpeedScale`].
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Resource, Default, Debug, PartialEq)]
+pub struct KeyboardNavigation {
+    pub enabled: bool,
+    /// Radians per second while an arrow is held.
+    pub angular_speed: f32,
+    /// How far the view pans per second, as a fraction of the distance to the target, or of the view height in 2D. The FPS,
+    /// isometric, and Unreal controllers pan at their own keyboard speeds instead.
+    pub pan_speed: f32,
+    /// How quickly +/- zoom, as the natural log of the zoom factor per second. The FPS and Unreal controllers move forward
+    /// and backward at their own keyboard speeds instead.
+    pub zoom_speed: f32,
+    /// How much each press of `[` or `]` divides or multiplies the [`NavigationSpeedScale`] by.
+    pub speed_step: f32,
+}
+
+impl Default for KeyboardNavigation {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            angular_speed: 1.5,
+            pan_speed: 0.5,
+            zoom_speed: 1.0,
+            speed_step: 1.25,
+        }
+    }
+}
+
+impl KeyboardNavigation {
+    /// The held keys, as a rotation (X right, Y up) or a pan (the same axes), and a zoom (positive in), each in `[-1, 1]`.
+    fn directions(keyboard: &ButtonInput<KeyCode>) -> (Vec2, Vec2, f32) {
+        let mut arrows = Vec2::ZERO;
+        for (key, dir) in [
+            (KeyCode::ArrowUp, Vec2::Y),
+            (KeyCode::ArrowLeft, -Vec2::X),
+            (KeyCode::ArrowDown, -Vec2::Y),
+            (KeyCode::ArrowRight, Vec2::X),
+        ] {
+            if keyboard.pressed(key) {
+                arrows += dir;
+            }
+        }
+        let mut zoom = 0.0;
+        if keyboard.any_pressed([KeyCode::Equal, KeyCode::NumpadAdd]) {
+            zoom += 1.0;
+        }
+        if keyboard.any_pressed([KeyCode::Minus, KeyCode::NumpadSubtract]) {
+            zoom -= 1.0;
+        }
+        if keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+            (Vec2::ZERO, arrows, zoom)
+        } else {
+            (arrows, Vec2::ZERO, zoom)
+        }
+    }
+}
+
+/// Scales the speed of all keyboard navigation, so it can be slowed down for users who need more time to react.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Resource, Default, Debug, PartialEq)]
+pub struct NavigationSpeedScale(pub f32);
+
+impl Default for NavigationSpeedScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// The held keys, scaled by the speeds of the [`KeyboardNavigation`], if it's enabled and any are held.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct KeyboardMotion {
+    /// Radians per second, X right and Y up.
+    rotate: Vec2,
+    /// X right and Y up, scaled by the [`NavigationSpeedScale`] but not by a pan speed, since some controllers have their own.
+    pan: Vec2,
+    /// The arrows with or without Shift, scaled like `pan`.
+    arrows: Vec2,
+    /// Natural log of the zoom factor per second, positive in.
+    zoom: f32,
+}
+
+impl KeyboardMotion {
+    fn read(
+        navigation: &KeyboardNavigation,
+        speed_scale: &NavigationSpeedScale,
+        keyboard: &ButtonInput<KeyCode>,
+    ) -> Option<Self> {
+        if !navigation.enabled {
+            return None;
+        }
+        let (rotate, pan, zoom) = KeyboardNavigation::directions(keyboard);
+        if rotate == Vec2::ZERO && pan == Vec2::ZERO && zoom == 0.0 {
+            return None;
+        }
+        let scale = speed_scale.0;
+        Some(Self {
+            rotate: scale * navigation.angular_speed * rotate,
+            pan: scale * pan,
+            arrows: scale * (rotate + pan),
+            zoom: scale * navigation.zoom_speed * zoom,
+        })
+    }
+
+    /// The zoom factor to multiply a distance or scale by over `dt` seconds.
+    fn zoom_scalar(&self, dt: f32) -> f32 {
+        (-dt * self.zoom).exp()
+    }
+}
+
+/// Changes the [`NavigationSpeedScale`] with `[` and `]`.
+pub fn speed_scale_input_system(
+    navigation: Res<KeyboardNavigation>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut speed_scale: ResMut<NavigationSpeedScale>,
+) {
+    if !navigation.enabled || navigation.speed_step <= 0.0 {
+        return;
+    }
+    if keyboard.just_pressed(KeyCode::BracketLeft) {
+        speed_scale.0 /= navigation.speed_step;
+    }
+    if keyboard.just_pressed(KeyCode::BracketRight) {
+        speed_scale.0 *= navigation.speed_step;
+    }
+}
+
+pub fn fps_keyboard_map(
+    navigation: Res<KeyboardNavigation>,
+    speed_scale: Res<NavigationSpeedScale>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut events: EventWriter<fps::ControlEvent>,
+    controllers: Query<(&FpsCameraController, InputRoute)>,
+    routing: InputRouting,
+) {
+    let Some(motion) = KeyboardMotion::read(&navigation, &speed_scale, &keyboard) else {
+        return;
+    };
+    if routing.is_blocked() {
+        return;
+    }
+    // Can only control one camera at a time.
+    let Some((controller, route)) = controllers
+        .iter()
+        .find(|(c, route)| c.enabled && routing.accepts(route))
+    else {
+        return;
+    };
+    let dt = routing.delta_secs(&route);
+    // Like a mouse motion, where Y points down.
+    if motion.rotate != Vec2::ZERO {
+        events.send(fps::ControlEvent::Rotate(
+            dt * motion.rotate * Vec2::new(1.0, -1.0),
+        ));
+    }
+    // +X moves left, like A, and +/- move forward and backward.
+    let direction = Vec3::new(
+        -motion.pan.x,
+        motion.pan.y,
+        speed_scale.0 * motion.zoom.signum(),
+    );
+    if direction != Vec3::ZERO {
+        events.send(fps::ControlEvent::translate_from_keys(
+            controller, direction, dt,
+        ));
+    }
+}
+
+pub fn isometric_keyboard_map(
+    navigation: Res<KeyboardNavigation>,
+    speed_scale: Res<NavigationSpeedScale>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut events: EventWriter<isometric::ControlEvent>,
+    controllers: Query<(&IsometricCameraController, InputRoute)>,
+    routing: InputRouting,
+) {
+    let Some(motion) = KeyboardMotion::read(&navigation, &speed_scale, &keyboard) else {
+        return;
+    };
+    if routing.is_blocked() {
+        return;
+    }
+    // Can only control one camera at a time.
+    let Some((controller, route)) = controllers
+        .iter()
+        .find(|(c, route)| c.enabled && routing.accepts(route))
+    else {
+        return;
+    };
+    let dt = routing.delta_secs(&route);
+    // The isometric camera turns in steps, like with Q/E.
+    if motion.rotate.x != 0.0 {
+        let mut steps = 0;
+        if keyboard.just_pressed(KeyCode::ArrowLeft) {
+            steps += 1;
+        }
+        if keyboard.just_pressed(KeyCode::ArrowRight) {
+            steps -= 1;
+        }
+        if steps != 0 {
+            events.send(isometric::ControlEvent::Rotate(steps));
+        }
+    }
+    if motion.pan != Vec2::ZERO {
+        events.send(isometric::ControlEvent::Pan(
+            dt * controller.keyboard_pan_speed * motion.pan,
+        ));
+    }
+    if motion.zoom != 0.0 {
+        events.send(isometric::ControlEvent::Zoom(motion.zoom_scalar(dt)));
+    }
+}
+
+pub fn maya_keyboard_map(
+    navigation: Res<KeyboardNavigation>,
+    speed_scale: Res<NavigationSpeedScale>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut events: EventWriter<maya::ControlEvent>,
+    controllers: Query<(&MayaCameraController, InputRoute)>,
+    routing: InputRouting,
+) {
+    let Some(motion) = KeyboardMotion::read(&navigation, &speed_scale, &keyboard) else {
+        return;
+    };
+    if routing.is_blocked() {
+        return;
+    }
+    // Can only control one camera at a time.
+    let Some((_, route)) = controllers
+        .iter()
+        .find(|(c, route)| c.enabled && routing.accepts(route))
+    else {
+        return;
+    };
+    let dt = routing.delta_secs(&route);
+    // Like a mouse drag, where Y points down.
+    if motion.rotate != Vec2::ZERO {
+        events.send(maya::ControlEvent::Tumble(
+            dt * motion.rotate * Vec2::new(1.0, -1.0),
+        ));
+    }
+    // Pans are in distances to the target per second, and move the view the opposite way of a mouse drag.
+    if motion.pan != Vec2::ZERO {
+        events.send(maya::ControlEvent::Pan(
+            dt * navigation.pan_speed * motion.pan * Vec2::new(-1.0, 1.0),
+        ));
+    }
+    if motion.zoom != 0.0 {
+        events.send(maya::ControlEvent::Dolly(motion.zoom_scalar(dt)));
+    }
+}
+
+pub fn orbit_keyboard_map(
+    navigation: Res<KeyboardNavigation>,
+    speed_scale: Res<NavigationSpeedScale>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut events: EventWriter<orbit::ControlEvent>,
+    controllers: Query<(&OrbitCameraController, &LookTransform, InputRoute)>,
+    routing: InputRouting,
+) {
+    let Some(motion) = KeyboardMotion::read(&navigation, &speed_scale, &keyboard) else {
+        return;
+    };
+    if routing.is_blocked() {
+        return;
+    }
+    // Can only control one camera at a time.
+    let Some((controller, transform, route)) = controllers
+        .iter()
+        .find(|(c, _, route)| c.enabled && routing.accepts(route))
+    else {
+        return;
+    };
+    let dt = routing.delta_secs(&route);
+    // The same directions as the orbit controller's own arrow steps.
+    if motion.rotate != Vec2::ZERO {
+        events.send(orbit::ControlEvent::Orbit(
+            dt * motion.rotate * Vec2::new(1.0, -1.0),
+        ));
+    }
+    if motion.pan != Vec2::ZERO {
+        // The control system scales pans by the radius over `pan_reference_radius`, so undo that.
+        let distance = controller
+            .pan_reference_radius
+            .unwrap_or(transform.radius());
+        events.send(orbit::ControlEvent::TranslateTarget(
+            dt * navigation.pan_speed * distance * motion.pan * Vec2::new(-1.0, 1.0),
+        ));
+    }
+    if motion.zoom != 0.0 {
+        events.send(orbit::ControlEvent::Zoom(motion.zoom_scalar(dt)));
+    }
+}
+
+pub fn pancam2d_keyboard_map(
+    navigation: Res<KeyboardNavigation>,
+    speed_scale: Res<NavigationSpeedScale>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut events: EventWriter<pancam2d::ControlEvent>,
+    controllers: Query<(&PanCam2dController, &OrthographicProjection, InputRoute)>,
+    routing: InputRouting,
+) {
+    let Some(motion) = KeyboardMotion::read(&navigation, &speed_scale, &keyboard) else {
+        return;
+    };
+    if routing.is_blocked() {
+        return;
+    }
+    // Can only control one camera at a time.
+    let Some((_, projection, route)) = controllers
+        .iter()
+        .find(|(c, _, route)| c.enabled && routing.accepts(route))
+    else {
+        return;
+    };
+    let dt = routing.delta_secs(&route);
+    // A 2D camera can't rotate, so the arrows pan with or without Shift.
+    if motion.arrows != Vec2::ZERO {
+        let view_height = projection.area.height();
+        events.send(pancam2d::ControlEvent::Pan(
+            dt * navigation.pan_speed * view_height * motion.arrows,
+        ));
+    }
+    if motion.zoom != 0.0 {
+        events.send(pancam2d::ControlEvent::Zoom {
+            scalar: motion.zoom_scalar(dt),
+            about: Vec2::ZERO,
+        });
+    }
+}
+
+pub fn unreal_keyboard_map(
+    navigation: Res<KeyboardNavigation>,
+    speed_scale: Res<NavigationSpeedScale>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut events: EventWriter<unreal::ControlEvent>,
+    controllers: Query<(&UnrealCameraController, InputRoute)>,
+    routing: InputRouting,
+) {
+    let Some(motion) = KeyboardMotion::read(&navigation, &speed_scale, &keyboard) else {
+        return;
+    };
+    if routing.is_blocked() {
+        return;
+    }
+    // Can only control one camera at a time.
+    let Some((controller, route)) = controllers
+        .iter()
+        .find(|(c, route)| c.enabled && routing.accepts(route))
+    else {
+        return;
+    };
+    let dt = routing.delta_secs(&route);
+    let speed = dt * controller.keyboard_mvmt_sensitivity;
+    // Like a mouse drag, where Y points down.
+    if motion.rotate != Vec2::ZERO {
+        events.send(unreal::ControlEvent::Rotate(
+            dt * motion.rotate * Vec2::new(1.0, -1.0),
+        ));
+    }
+    // Positive X pans left, like A.
+    if motion.pan != Vec2::ZERO {
+        events.send(unreal::ControlEvent::TranslateEye(
+            speed * motion.pan * Vec2::new(-1.0, 1.0),
+        ));
+    }
+    if motion.zoom != 0.0 {
+        events.send(unreal::ControlEvent::Locomotion(Vec2::new(
+            0.0,
+            speed * speed_scale.0 * motion.zoom.signum(),
+        )));
+    }
+}
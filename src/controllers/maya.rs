@@ -0,0 +1,354 @@
+use crate::{
+    controllers::{
+        controller_plugin, scroll_lines, CameraBundleBuilder, CameraControlMode, CameraController,
+        CameraControllerStatus, ControlEventBatches, ControllerKind, InputRoute, InputRouting,
+        TargetedControlEvent, DEFAULT_PIXELS_PER_LINE,
+    },
+    framing::{bounding_sphere, entity_bounding_sphere, framing_distance},
+    LookTransform, LookTransformBundle, Smoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::{bundle::Bundle, prelude::*},
+    input::{
+        mouse::{MouseMotion, MouseWheel},
+        prelude::*,
+    },
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    render::{camera::Projection, primitives::Aabb},
+    transform::components::{GlobalTransform, Transform},
+};
+
+#[derive(Default)]
+pub struct MayaCameraPlugin {
+    pub override_input_system: bool,
+}
+
+impl MayaCameraPlugin {
+    pub fn new(override_input_system: bool) -> Self {
+        Self {
+            override_input_system,
+        }
+    }
+}
+
+impl Plugin for MayaCameraPlugin {
+    fn build(&self, app: &mut App) {
+        let app = app
+            .add_plugins(controller_plugin::<MayaCameraController>())
+            .add_systems(Update, control_system);
+
+        if !self.override_input_system {
+            app.add_systems(Update, (default_input_map, frame_input_map));
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct MayaCameraBundle {
+    controller: MayaCameraController,
+    look_transform: LookTransformBundle,
+    transform: Transform,
+}
+
+impl MayaCameraBundle {
+    pub fn builder() -> CameraBundleBuilder<MayaCameraController> {
+        CameraBundleBuilder::new(
+            MayaCameraController::default(),
+            Vec3::Z,
+            Vec3::ZERO,
+            Vec3::Y,
+        )
+    }
+
+    pub fn new(controller: MayaCameraController, eye: Vec3, target: Vec3, up: Vec3) -> Self {
+        // Make sure the transform is consistent with the controller to start.
+        let transform = Transform::from_translation(eye).looking_at(target, up);
+
+        Self {
+            controller,
+            look_transform: LookTransformBundle {
+                transform: LookTransform::new(eye, target, up),
+                smoother: Smoother::new(controller.smoothing_weight),
+            },
+            transform,
+        }
+    }
+}
+
+/// A camera controlled like the viewports of Maya and other DCC tools, where every mouse gesture is modified by Alt.
+///
+/// Tumbling rotates around the `pivot`, which can differ from the `LookTransform` target.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct MayaCameraController {
+    pub enabled: bool,
+    /// Radians per pixel of mouse motion, for yaw (X) and pitch (Y).
+    pub mouse_rotate_sensitivity: Vec2,
+    /// Pan distance per pixel of mouse motion and unit of distance to the target, so panning keeps pace with the cursor
+    /// at any zoom.
+    pub mouse_translate_sensitivity: Vec2,
+    pub mouse_dolly_sensitivity: f32,
+    pub mouse_wheel_zoom_sensitivity: f32,
+    pub pixels_per_line: f32,
+    pub smoothing_weight: f32,
+    /// The point that tumbling rotates around. `None` uses the `LookTransform` target.
+    pub pivot: Option<Vec3>,
+}
+
+impl CameraController for MayaCameraController {
+    const KIND: ControllerKind = ControllerKind::Maya;
+    type Event = ControlEvent;
+
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn smoothing_weight(&self) -> f32 {
+        self.smoothing_weight
+    }
+
+    fn reseed(&mut self, _transform: &LookTransform) {
+        self.pivot = None;
+    }
+}
+
+impl Default for MayaCameraController {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            mouse_rotate_sensitivity: Vec2::splat(0.0033),
+            mouse_translate_sensitivity: Vec2::splat(0.00033),
+            mouse_dolly_sensitivity: 0.005,
+            mouse_wheel_zoom_sensitivity: 0.2,
+            pixels_per_line: DEFAULT_PIXELS_PER_LINE,
+            smoothing_weight: 0.6,
+            pivot: None,
+        }
+    }
+}
+
+/// Marks the entities that pressing F frames.
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct Selected;
+
+/// One frame of input for a [`MayaCameraController`], as described on [`CameraController::Event`].
+#[derive(Clone, Copy, Debug, Event, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ControlEvent {
+    /// Rotates the eye and target around the pivot, by yaw (X) and pitch (Y) angles in radians.
+    Tumble(Vec2),
+    /// Moves the eye, target, and pivot along the view plane, by distances in units of the distance to the target.
+    Pan(Vec2),
+    /// Multiplies the distance from the eye to the target.
+    Dolly(f32),
+    /// Looks at `center` from `distance` away, and makes it the pivot.
+    Frame { center: Vec3, distance: f32 },
+}
+
+impl ControlEvent {
+    /// Tumbles by a mouse motion of `cursor_delta` pixels.
+    pub fn tumble_from_mouse(controller: &MayaCameraController, cursor_delta: Vec2) -> Self {
+        Self::Tumble(controller.mouse_rotate_sensitivity * cursor_delta)
+    }
+
+    /// Pans by a mouse motion of `cursor_delta` pixels.
+    pub fn pan_from_mouse(controller: &MayaCameraController, cursor_delta: Vec2) -> Self {
+        Self::Pan(controller.mouse_translate_sensitivity * cursor_delta)
+    }
+
+    /// Dollies by a mouse motion of `cursor_delta` pixels, where dragging right or down dollies in.
+    pub fn dolly_from_mouse(controller: &MayaCameraController, cursor_delta: Vec2) -> Self {
+        let scalar = 1.0 - (cursor_delta.x + cursor_delta.y) * controller.mouse_dolly_sensitivity;
+        Self::Dolly(scalar.max(0.1))
+    }
+
+    /// Dollies by `lines` of mouse wheel scrolling, where positive lines dolly in.
+    pub fn dolly_from_wheel(controller: &MayaCameraController, lines: f32) -> Self {
+        Self::Dolly(1.0 - lines * controller.mouse_wheel_zoom_sensitivity)
+    }
+}
+
+pub fn default_input_map(
+    mut events: EventWriter<ControlEvent>,
+    mut mouse_wheel_reader: EventReader<MouseWheel>,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    controllers: Query<(&MayaCameraController, InputRoute)>,
+    routing: InputRouting,
+) {
+    if routing.is_blocked() {
+        mouse_wheel_reader.clear();
+        mouse_motion_events.clear();
+        return;
+    }
+
+    // Can only control one camera at a time.
+    let (controller, _) = if let Some(controller) = controllers
+        .iter()
+        .find(|(c, route)| c.enabled && routing.accepts(route))
+    {
+        controller
+    } else {
+        return;
+    };
+    let pixels_per_line = controller.pixels_per_line;
+
+    let mut cursor_delta = Vec2::ZERO;
+    for event in mouse_motion_events.read() {
+        cursor_delta += event.delta;
+    }
+
+    if keyboard.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]) && cursor_delta != Vec2::ZERO {
+        if mouse_buttons.pressed(MouseButton::Left) {
+            events.send(ControlEvent::tumble_from_mouse(controller, cursor_delta));
+        } else if mouse_buttons.pressed(MouseButton::Middle) {
+            events.send(ControlEvent::pan_from_mouse(controller, cursor_delta));
+        } else if mouse_buttons.pressed(MouseButton::Right) {
+            events.send(ControlEvent::dolly_from_mouse(controller, cursor_delta));
+        }
+    }
+
+    for event in mouse_wheel_reader.read() {
+        let scroll_amount = scroll_lines(event, pixels_per_line).y;
+        if scroll_amount != 0.0 {
+            events.send(ControlEvent::dolly_from_wheel(controller, scroll_amount));
+        }
+    }
+}
+
+/// Frames the [`Selected`] entities when F is pressed.
+pub fn frame_input_map(
+    mut events: EventWriter<ControlEvent>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    controllers: Query<(&MayaCameraController, Option<&Projection>, InputRoute)>,
+    selected: Query<(&GlobalTransform, Option<&Aabb>), With<Selected>>,
+    routing: InputRouting,
+) {
+    if routing.is_blocked() || !keyboard.just_pressed(KeyCode::KeyF) {
+        return;
+    }
+
+    // Can only control one camera at a time.
+    let (_, projection, _) = if let Some(controller) = controllers
+        .iter()
+        .find(|(c, _, route)| c.enabled && routing.accepts(route))
+    {
+        controller
+    } else {
+        return;
+    };
+
+    let spheres = selected
+        .iter()
+        .map(|(transform, aabb)| entity_bounding_sphere(transform, aabb));
+    if let Some((center, radius)) = bounding_sphere(spheres) {
+        // Leave a little room around the selection, and don't get too close to a single point.
+        let distance = framing_distance(1.1 * radius.max(0.5), projection);
+        events.send(ControlEvent::Frame { center, distance });
+    }
+}
+
+pub fn control_system(
+    mut events: EventReader<ControlEvent>,
+    mut targeted_events: EventReader<TargetedControlEvent<ControlEvent>>,
+    mut cameras: Query<(&mut MayaCameraController, &mut LookTransform, InputRoute)>,
+    mut status: ResMut<CameraControllerStatus>,
+    routing: InputRouting,
+) {
+    // Only one camera has the input at a time.
+    let input_camera = cameras
+        .iter()
+        .find(|(c, _, route)| c.enabled && routing.accepts(route))
+        .map(|(.., route)| route.entity);
+    let mut batches = ControlEventBatches::read(&mut events, &mut targeted_events, input_camera);
+
+    for (mut controller, mut transform, route) in cameras.iter_mut() {
+        let entity = route.entity;
+        let Some(events) = batches.take(entity) else {
+            continue;
+        };
+
+        let mut look_transform = *transform;
+        // The pivot is the controller's own state, so don't let it look like a user change.
+        let control_mode = apply_events(
+            controller.bypass_change_detection(),
+            &mut look_transform,
+            &events,
+        );
+        transform.set_if_neq(look_transform);
+
+        if batches.is_input_camera(entity) {
+            status.set_if_neq(CameraControllerStatus {
+                entity: Some(entity),
+                mode: control_mode,
+                speed: 0.0,
+                zoom: transform.radius(),
+            });
+        }
+    }
+}
+
+/// Applies one frame of `events` to `transform`, and returns the kind of motion.
+///
+/// The math of the [`control_system`], without the ECS; see [`CameraController`](super::CameraController#control-systems).
+pub fn apply_events(
+    controller: &mut MayaCameraController,
+    transform: &mut LookTransform,
+    events: &[ControlEvent],
+) -> CameraControlMode {
+    let Some(mut look_direction) = transform.look_direction() else {
+        return CameraControlMode::Idle;
+    };
+
+    let mut control_mode = CameraControlMode::Idle;
+    for event in events {
+        let right = look_direction.cross(transform.up).normalize();
+        let up = right.cross(look_direction);
+        match *event {
+            ControlEvent::Tumble(delta) => {
+                control_mode = CameraControlMode::Orbit;
+                let yaw = Quat::from_axis_angle(transform.up, -delta.x);
+                let pitch = Quat::from_axis_angle(right, -delta.y);
+                // Don't tumble over the poles, where the view would flip.
+                let rotation = if ((yaw * pitch) * look_direction).dot(transform.up).abs() < 0.99 {
+                    yaw * pitch
+                } else {
+                    yaw
+                };
+
+                let pivot = controller.pivot.unwrap_or(transform.target);
+                transform.eye = pivot + rotation * (transform.eye - pivot);
+                transform.target = pivot + rotation * (transform.target - pivot);
+                look_direction = rotation * look_direction;
+            }
+            ControlEvent::Pan(delta) => {
+                control_mode = CameraControlMode::Pan;
+                let translation = transform.radius() * (-delta.x * right + delta.y * up);
+                transform.eye += translation;
+                transform.target += translation;
+                if let Some(pivot) = controller.pivot.as_mut() {
+                    *pivot += translation;
+                }
+            }
+            ControlEvent::Dolly(scalar) => {
+                control_mode = CameraControlMode::Zoom;
+                let radius = (scalar * transform.radius()).clamp(0.001, 1000000.0);
+                transform.eye = transform.target - radius * look_direction;
+            }
+            ControlEvent::Frame { center, distance } => {
+                control_mode = CameraControlMode::Fly;
+                transform.target = center;
+                transform.eye = center - distance * look_direction;
+                controller.pivot = Some(center);
+            }
+        }
+    }
+    control_mode
+}
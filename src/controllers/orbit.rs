@@ -1,41 +1,92 @@
-use crate::{LookAngles, LookTransform, LookTransformBundle, Smoother};
+use crate::{
+    clock::CameraTime,
+    controllers::{
+        controller_plugin, edge_pan::EdgePan, scroll_lines, AutoRotate, AxisInversion,
+        CameraBundleBuilder, CameraControlMode, CameraController, CameraControllerStatus,
+        CameraControllerToggle, ControlEventBatches, ControllerKind, DragTrigger, InputRoute,
+        InputRouting, ModifierKey, MouseDeltaFilter, TargetedControlEvent, DEFAULT_PIXELS_PER_LINE,
+    },
+    ease::Ease,
+    LookAngles, LookTransform, LookTransformBundle, Smoother,
+};
 
 use bevy::{
     app::prelude::*,
-    ecs::{bundle::Bundle, prelude::*},
+    ecs::{
+        bundle::Bundle,
+        prelude::*,
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+    },
     input::{
-        mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+        mouse::{MouseMotion, MouseWheel},
         prelude::*,
     },
     math::prelude::*,
     prelude::ReflectDefault,
     reflect::Reflect,
-    time::Time,
     transform::components::Transform,
 };
 
-#[derive(Default)]
+use std::f32::consts::PI;
+
 pub struct OrbitCameraPlugin {
     pub override_input_system: bool,
+    /// The schedule that the control systems run in. Defaults to `Update`.
+    pub control_schedule: InternedScheduleLabel,
+    /// The schedule that the default input map runs in. Defaults to `Update`. In the same schedule as the control
+    /// systems, the input map runs first, so input is applied in the frame that it arrives.
+    pub input_schedule: InternedScheduleLabel,
+}
+
+impl Default for OrbitCameraPlugin {
+    fn default() -> Self {
+        Self::new(false)
+    }
 }
 
 impl OrbitCameraPlugin {
     pub fn new(override_input_system: bool) -> Self {
         Self {
             override_input_system,
+            control_schedule: Update.intern(),
+            input_schedule: Update.intern(),
         }
     }
+
+    /// Runs the control systems in `schedule`, e.g. `FixedUpdate` to move the camera in step with gameplay. Add a
+    /// [`FixedInterpolation`](crate::FixedInterpolation) to the camera to keep it smooth between steps.
+    pub fn with_control_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.control_schedule = schedule.intern();
+        self
+    }
+
+    /// Runs the default input map in `schedule`, e.g. `PreUpdate` after input handling, for apps that pace their own
+    /// frames.
+    pub fn with_input_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.input_schedule = schedule.intern();
+        self
+    }
 }
 
 impl Plugin for OrbitCameraPlugin {
     fn build(&self, app: &mut App) {
         let app = app
-            .add_systems(PreUpdate, on_controller_enabled_changed)
-            .add_systems(Update, control_system)
-            .add_event::<ControlEvent>();
+            .add_plugins(controller_plugin::<OrbitCameraController>())
+            .add_systems(
+                self.control_schedule,
+                (
+                    orbit_mode_transition_system,
+                    view_snap_system,
+                    control_system,
+                )
+                    .chain(),
+            );
 
         if !self.override_input_system {
-            app.add_systems(Update, default_input_map);
+            app.add_systems(
+                self.input_schedule,
+                (default_input_map, keyboard_input_map).before(control_system),
+            );
         }
     }
 }
@@ -48,6 +99,15 @@ pub struct OrbitCameraBundle {
 }
 
 impl OrbitCameraBundle {
+    pub fn builder() -> CameraBundleBuilder<OrbitCameraController> {
+        CameraBundleBuilder::new(
+            OrbitCameraController::default(),
+            Vec3::Z,
+            Vec3::ZERO,
+            Vec3::Y,
+        )
+    }
+
     pub fn new(controller: OrbitCameraController, eye: Vec3, target: Vec3, up: Vec3) -> Self {
         // Make sure the transform is consistent with the controller to start.
         let transform = Transform::from_translation(eye).looking_at(target, up);
@@ -63,40 +123,434 @@ impl OrbitCameraBundle {
     }
 }
 
-/// A 3rd person camera that orbits around the target.
+/// A 3rd person camera that orbits around the target, or a panorama camera that looks around from a fixed point, depending
+/// on its [`OrbitMode`].
+///
+/// The controller keeps some state between frames in private fields, like an unfinished zoom, so start from
+/// `OrbitCameraController::default()` and set the fields to change, rather than using a struct literal.
 #[derive(Clone, Component, Copy, Debug, Reflect)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[reflect(Component, Default, Debug)]
 pub struct OrbitCameraController {
     pub enabled: bool,
+    /// Radians per pixel of mouse motion, for yaw (X) and pitch (Y).
     pub mouse_rotate_sensitivity: Vec2,
+    /// World units per pixel of mouse motion, scaled by the radius if there's a `pan_reference_radius`.
     pub mouse_translate_sensitivity: Vec2,
     pub mouse_wheel_zoom_sensitivity: f32,
+    /// Zooms by the same percentage of the radius for each wheel line in either direction, so zooming feels the same at
+    /// any scale, from millimeters to kilometers. Otherwise a line zooms out a little further than it zooms back in, and a
+    /// fast scroll can overshoot.
+    pub logarithmic_zoom: bool,
     pub pixels_per_line: f32,
     pub smoothing_weight: f32,
+    /// Like `smoothing_weight`, but only for the radius, which approaches its zoomed value separately from the `Smoother`
+    /// for continuous wheel zoom. `0.0` zooms instantly. It's the lag of one frame at 60 FPS, and is scaled to the actual
+    /// frame time, so zooms take as long at any frame rate.
+    pub zoom_smoothing_weight: f32,
+    pub mode: OrbitMode,
+    /// When set, panning is tuned for this radius and scaled in proportion to the current one, so the scene moves about as
+    /// fast on screen whether zoomed in or out. This applies to every `TranslateTarget`, including keyboard and edge pans.
+    pub pan_reference_radius: Option<f32>,
+    /// Which mouse drags orbit and pan.
+    pub triggers: OrbitTriggers,
+    /// Cleans up mouse motion before it's used.
+    pub mouse_delta_filter: MouseDeltaFilter,
+    /// Flips mouse and wheel directions.
+    pub invert: AxisInversion,
+    /// What happens to the view when `mode` changes.
+    pub mode_transition: OrbitModeTransition,
+    /// The angle in radians that each press of an arrow (or numpad 4/6/8/2) key orbits by. `0.0` disables it.
+    pub keyboard_orbit_step: f32,
+    /// The distance that each press of Shift + an arrow key pans by. `0.0` disables it.
+    pub keyboard_pan_step: f32,
+    /// The fraction of the radius that each press of +/- zooms by. `0.0` disables it.
+    pub keyboard_zoom_step: f32,
+    /// Whether numpad 1/3/7 (with Ctrl for the opposite side) and numpad 5 snap to a [`ViewPreset`].
+    pub keyboard_view_presets: bool,
+    /// How long [`OrbitCameraController::snap_to_view`] takes to rotate to the preset view, in seconds.
+    pub view_snap_duration: f32,
+    pub view_snap_easing: Ease,
+    /// While this is held, orbiting with the mouse turns in steps of `snap_rotation_step` instead, each animated over
+    /// `view_snap_duration`, like Blender's Ctrl + orbit. `None` disables it.
+    pub snap_rotation: Option<ModifierKey>,
+    /// The yaw and pitch angle in radians of each snap rotation step. Steps land on multiples of this angle.
+    pub snap_rotation_step: f32,
+    /// Spins the camera around the target while the user is idle, like a turntable for product showcases. Any input
+    /// pauses it right away, and it resumes after the `idle_delay`. `None` disables it.
+    pub auto_rotate: Option<AutoRotate>,
+    /// Lets pans and zooms coast to a stop after the input ends, like a maps app. `None` stops them right away.
+    pub inertia: Option<OrbitInertia>,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pending_view: Option<Vec3>,
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    goal_radius: Option<f32>,
+    /// The radius that the zoom last wrote, to tell when something else changed it.
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    written_radius: f32,
+    /// Snap rotation that hasn't added up to a whole step yet.
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    snap_remainder: Vec2,
+    /// The angles of the last snap rotation step, which the next step starts from if it's still animating.
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    snap_angles: Option<LookAngles>,
+    /// Seconds since the last input, for `auto_rotate`.
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    idle_time: f32,
+    /// The recent pan speed in `TranslateTarget` units per second, for `inertia`.
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pan_velocity: Vec2,
+    /// The recent zoom speed as the natural log of the radius factor per second, for `inertia`.
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    zoom_velocity: f32,
+    /// The `mode` that the `orbit_mode_transition_system` last saw, to tell when it switches.
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    previous_mode: Option<OrbitMode>,
+}
+
+impl CameraController for OrbitCameraController {
+    const KIND: ControllerKind = ControllerKind::Orbit;
+    type Event = ControlEvent;
+
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn smoothing_weight(&self) -> f32 {
+        self.smoothing_weight
+    }
+
+    fn reseed(&mut self, _transform: &LookTransform) {
+        self.pending_view = None;
+        self.mouse_delta_filter.reset();
+        self.goal_radius = None;
+        self.written_radius = 0.0;
+        self.snap_remainder = Vec2::ZERO;
+        self.idle_time = 0.0;
+        self.snap_angles = None;
+        self.pan_velocity = Vec2::ZERO;
+        self.zoom_velocity = 0.0;
+    }
 }
 
 impl Default for OrbitCameraController {
     fn default() -> Self {
         Self {
-            mouse_rotate_sensitivity: Vec2::splat(0.08),
-            mouse_translate_sensitivity: Vec2::splat(0.1),
+            mouse_rotate_sensitivity: Vec2::splat(0.0013),
+            mouse_translate_sensitivity: Vec2::splat(0.0017),
             mouse_wheel_zoom_sensitivity: 0.2,
+            logarithmic_zoom: false,
             smoothing_weight: 0.8,
+            zoom_smoothing_weight: 0.7,
             enabled: true,
-            pixels_per_line: 53.0,
+            pixels_per_line: DEFAULT_PIXELS_PER_LINE,
+            mode: OrbitMode::Exterior,
+            pan_reference_radius: None,
+            triggers: OrbitTriggers::default(),
+            mouse_delta_filter: MouseDeltaFilter::default(),
+            invert: AxisInversion::default(),
+            mode_transition: OrbitModeTransition::MoveEye,
+            keyboard_orbit_step: PI / 12.0,
+            keyboard_pan_step: 0.5,
+            keyboard_zoom_step: 0.2,
+            keyboard_view_presets: true,
+            view_snap_duration: 0.3,
+            view_snap_easing: Ease::default(),
+            snap_rotation: None,
+            snap_rotation_step: PI / 12.0,
+            auto_rotate: None,
+            inertia: None,
+            pending_view: None,
+            goal_radius: None,
+            written_radius: 0.0,
+            snap_remainder: Vec2::ZERO,
+            snap_angles: None,
+            idle_time: 0.0,
+            pan_velocity: Vec2::ZERO,
+            zoom_velocity: 0.0,
+            previous_mode: None,
         }
     }
 }
 
-#[derive(Event)]
+impl OrbitCameraController {
+    /// Rotates the camera to `preset` around the current pivot, preserving the radius. The rotation is animated over
+    /// `view_snap_duration` seconds.
+    pub fn snap_to_view(&mut self, preset: ViewPreset) {
+        self.snap_to_direction(preset.eye_direction());
+    }
+
+    /// Like [`OrbitCameraController::snap_to_view`], for any direction from the pivot toward the camera.
+    pub fn snap_to_direction(&mut self, eye_direction: Vec3) {
+        self.pending_view = eye_direction.try_normalize();
+    }
+}
+
+/// An axis-aligned (or isometric) view for [`OrbitCameraController::snap_to_view`], named after the side of the scene
+/// that the camera looks at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Debug, PartialEq)]
+pub enum ViewPreset {
+    /// Looking down -Z.
+    Front,
+    /// Looking down +Z.
+    Back,
+    /// Looking down +X.
+    Left,
+    /// Looking down -X.
+    Right,
+    /// Looking down -Y.
+    Top,
+    /// Looking down +Y.
+    Bottom,
+    /// Looking down at the front right corner, with equal foreshortening of all three axes.
+    Isometric,
+}
+
+impl ViewPreset {
+    /// The direction from the pivot toward the camera.
+    pub fn eye_direction(self) -> Vec3 {
+        match self {
+            Self::Front => Vec3::Z,
+            Self::Back => -Vec3::Z,
+            Self::Left => -Vec3::X,
+            Self::Right => Vec3::X,
+            Self::Top => Vec3::Y,
+            Self::Bottom => -Vec3::Y,
+            Self::Isometric => Vec3::ONE.normalize(),
+        }
+    }
+}
+
+/// The state of a [`ViewPreset`] snap in progress. This is removed once the rotation finishes.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[reflect(Component, Debug)]
+pub struct SnappingToView {
+    /// The look direction angles at the start of the snap.
+    pub from: LookAngles,
+    /// The look direction angles at the end of the snap.
+    pub to: LookAngles,
+    pub duration: f32,
+    pub elapsed: f32,
+    pub easing: Ease,
+}
+
+impl SnappingToView {
+    pub fn progress(&self) -> f32 {
+        if self.duration > 0.0 {
+            (self.elapsed / self.duration).min(1.0)
+        } else {
+            1.0
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// The look direction angles at the current progress, eased and taking the shorter way around.
+    pub fn current_angles(&self) -> LookAngles {
+        let t = self.easing.sample(self.progress());
+        let yaw_delta = (self.to.get_yaw() - self.from.get_yaw() + PI).rem_euclid(2.0 * PI) - PI;
+        let pitch_delta = self.to.get_pitch() - self.from.get_pitch();
+        let mut angles = self.from;
+        angles.add_yaw(t * yaw_delta);
+        angles.add_pitch(t * pitch_delta);
+        angles
+    }
+}
+
+/// What the [`OrbitCameraController`] rotates around.
+///
+/// By default, both modes share the same pivot point, so switching modes at runtime moves the eye to (or away from) the
+/// pivot, and the `Smoother` turns that into a smooth transition. See [`OrbitModeTransition`] for keeping the view instead.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum OrbitMode {
+    /// The eye orbits around the target, which is the pivot.
+    #[default]
+    Exterior,
+    /// The eye stays at the pivot and the target swings around it, like a panorama viewer.
+    Interior,
+}
+
+/// How an [`OrbitCameraController`] switches between [`OrbitMode`]s.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum OrbitModeTransition {
+    /// Keeps the pivot, moving the eye to it for `Interior` or back out from it for `Exterior`.
+    #[default]
+    MoveEye,
+    /// Keeps the eye and target where they are, so the pivot becomes the eye for `Interior` ("stand here and look around")
+    /// or the target for `Exterior` ("orbit this object"), like in architectural walkthroughs.
+    KeepView,
+}
+
+/// The mouse drags that orbit and pan an [`OrbitCameraController`]. The presets match the conventions of other tools.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub struct OrbitTriggers {
+    pub orbit: DragTrigger,
+    pub pan: DragTrigger,
+}
+
+impl Default for OrbitTriggers {
+    /// Ctrl + mouse motion orbits, and Right drag pans.
+    fn default() -> Self {
+        Self {
+            orbit: DragTrigger::modifier(ModifierKey::Ctrl),
+            pan: DragTrigger::button(MouseButton::Right),
+        }
+    }
+}
+
+impl OrbitTriggers {
+    /// Alt + Left drag orbits, and Alt + Middle drag pans, like Maya.
+    pub fn alt_drag() -> Self {
+        Self {
+            orbit: DragTrigger::button(MouseButton::Left).with_modifier(ModifierKey::Alt),
+            pan: DragTrigger::button(MouseButton::Middle).with_modifier(ModifierKey::Alt),
+        }
+    }
+
+    /// Left drag orbits, and Right drag pans, like most web 3D viewers. Nothing needs to be held on the keyboard, which
+    /// suits trackpads.
+    pub fn left_drag() -> Self {
+        Self {
+            orbit: DragTrigger::button(MouseButton::Left),
+            pan: DragTrigger::button(MouseButton::Right),
+        }
+    }
+
+    /// Middle drag orbits, and Shift + Middle drag pans, like Blender and SketchUp.
+    pub fn middle_drag() -> Self {
+        Self {
+            orbit: DragTrigger::button(MouseButton::Middle),
+            pan: DragTrigger::button(MouseButton::Middle).with_modifier(ModifierKey::Shift),
+        }
+    }
+
+    /// Shift + Middle drag orbits, and Middle drag pans, like Fusion 360.
+    pub fn fusion_360() -> Self {
+        Self {
+            orbit: DragTrigger::button(MouseButton::Middle).with_modifier(ModifierKey::Shift),
+            pan: DragTrigger::button(MouseButton::Middle),
+        }
+    }
+
+    /// Whether mouse motion should orbit and pan, respectively.
+    pub fn active(
+        &self,
+        mouse_buttons: &ButtonInput<MouseButton>,
+        keyboard: &ButtonInput<KeyCode>,
+    ) -> (bool, bool) {
+        let orbit = self.orbit.is_active(mouse_buttons, keyboard);
+        let pan = self.pan.is_active(mouse_buttons, keyboard);
+        if orbit && pan {
+            let (o, p) = (self.orbit.specificity(), self.pan.specificity());
+            return (o >= p, p >= o);
+        }
+        (orbit, pan)
+    }
+}
+
+/// How pans and zooms of an [`OrbitCameraController`] coast after the input ends.
+///
+/// The speed of each is tracked over about a tenth of a second while there is input, and then decays exponentially by its
+/// friction, so higher friction stops sooner. Pans coast from any source, including keyboard steps and edge panning.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub struct OrbitInertia {
+    /// The rate per second at which the pan speed decays.
+    pub pan_friction: f32,
+    /// The rate per second at which the zoom speed decays.
+    pub zoom_friction: f32,
+}
+
+impl Default for OrbitInertia {
+    fn default() -> Self {
+        Self {
+            pan_friction: 5.0,
+            zoom_friction: 20.0,
+        }
+    }
+}
+
+impl OrbitInertia {
+    pub fn new(pan_friction: f32, zoom_friction: f32) -> Self {
+        Self {
+            pan_friction,
+            zoom_friction,
+        }
+    }
+}
+
+/// How long it takes [`OrbitInertia`] to pick up a new speed, in seconds.
+const INERTIA_TRACKING_TIME: f32 = 0.1;
+
+/// One frame of input for an [`OrbitCameraController`], as described on [`CameraController::Event`].
+#[derive(Clone, Copy, Debug, Event, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum ControlEvent {
+    /// Rotates by yaw (X) and pitch (Y) angles in radians.
     Orbit(Vec2),
+    /// Moves the target along the view plane by world space distances, scaled by the radius if the controller has a
+    /// `pan_reference_radius`.
     TranslateTarget(Vec2),
+    /// Multiplies the radius.
     Zoom(f32),
+    /// Rotates to look at the pivot from a direction, like [`OrbitCameraController::snap_to_direction`].
+    SnapToView(Vec3),
+    /// Like `Orbit`, but turns in steps of [`OrbitCameraController::snap_rotation_step`] once enough rotation adds up.
+    SnapOrbit(Vec2),
 }
 
-define_on_controller_enabled_changed!(OrbitCameraController);
+impl ControlEvent {
+    /// Orbits by a mouse motion of `cursor_delta` pixels.
+    pub fn orbit_from_mouse(controller: &OrbitCameraController, cursor_delta: Vec2) -> Self {
+        Self::Orbit(
+            controller.mouse_rotate_sensitivity * controller.invert.rotate.apply(cursor_delta),
+        )
+    }
+
+    /// Snap orbits by a mouse motion of `cursor_delta` pixels.
+    pub fn snap_orbit_from_mouse(controller: &OrbitCameraController, cursor_delta: Vec2) -> Self {
+        Self::SnapOrbit(
+            controller.mouse_rotate_sensitivity * controller.invert.rotate.apply(cursor_delta),
+        )
+    }
+
+    /// Pans by a mouse motion of `cursor_delta` pixels.
+    pub fn pan_from_mouse(controller: &OrbitCameraController, cursor_delta: Vec2) -> Self {
+        Self::TranslateTarget(
+            controller.mouse_translate_sensitivity * controller.invert.pan.apply(cursor_delta),
+        )
+    }
+
+    /// Zooms by `lines` of mouse wheel scrolling, where positive lines zoom in.
+    pub fn zoom_from_wheel(controller: &OrbitCameraController, lines: f32) -> Self {
+        let amount = controller.invert.apply_zoom(lines) * controller.mouse_wheel_zoom_sensitivity;
+        if controller.logarithmic_zoom {
+            Self::Zoom((-amount).exp())
+        } else {
+            Self::Zoom(1.0 - amount)
+        }
+    }
+}
 
 pub fn default_input_map(
     mut events: EventWriter<ControlEvent>,
@@ -104,86 +558,625 @@ pub fn default_input_map(
     mut mouse_motion_events: EventReader<MouseMotion>,
     mouse_buttons: Res<ButtonInput<MouseButton>>,
     keyboard: Res<ButtonInput<KeyCode>>,
-    controllers: Query<&OrbitCameraController>,
+    mut controllers: Query<(&mut OrbitCameraController, Option<&EdgePan>, InputRoute)>,
+    routing: InputRouting,
 ) {
+    if routing.is_blocked() {
+        mouse_wheel_reader.clear();
+        mouse_motion_events.clear();
+        return;
+    }
+
     // Can only control one camera at a time.
-    let controller = if let Some(controller) = controllers.iter().find(|c| c.enabled) {
+    let (mut controller, edge_pan, route) = if let Some(controller) = controllers
+        .iter_mut()
+        .find(|(c, _, route)| c.enabled && routing.accepts(route))
+    {
         controller
     } else {
         return;
     };
-    let OrbitCameraController {
-        mouse_rotate_sensitivity,
-        mouse_translate_sensitivity,
-        mouse_wheel_zoom_sensitivity,
-        pixels_per_line,
-        ..
-    } = *controller;
+    let pixels_per_line = controller.pixels_per_line;
 
     let mut cursor_delta = Vec2::ZERO;
     for event in mouse_motion_events.read() {
         cursor_delta += event.delta;
     }
+    let dt = routing.delta_secs(&route);
+    // The filter's state is the controller's own bookkeeping, so don't let it look like a user change.
+    let cursor_delta = controller
+        .bypass_change_detection()
+        .mouse_delta_filter
+        .filter(cursor_delta, dt);
+    let controller = &*controller;
 
-    if keyboard.pressed(KeyCode::ControlLeft) {
-        events.send(ControlEvent::Orbit(mouse_rotate_sensitivity * cursor_delta));
+    let (orbit, pan) = controller.triggers.active(&mouse_buttons, &keyboard);
+    if orbit && cursor_delta != Vec2::ZERO {
+        let snap = controller
+            .snap_rotation
+            .is_some_and(|modifier| modifier.is_pressed(&keyboard));
+        if snap {
+            events.send(ControlEvent::snap_orbit_from_mouse(
+                controller,
+                cursor_delta,
+            ));
+        } else {
+            events.send(ControlEvent::orbit_from_mouse(controller, cursor_delta));
+        }
     }
 
-    if mouse_buttons.pressed(MouseButton::Right) {
-        events.send(ControlEvent::TranslateTarget(
-            mouse_translate_sensitivity * cursor_delta,
-        ));
+    if pan && cursor_delta != Vec2::ZERO {
+        events.send(ControlEvent::pan_from_mouse(controller, cursor_delta));
+    } else if pan && controller.inertia.is_some() {
+        // Holding the drag still slows the pan down, so letting go afterwards doesn't fling the view.
+        events.send(ControlEvent::TranslateTarget(Vec2::ZERO));
+    }
+
+    if let Some(velocity) = edge_pan.map(EdgePan::velocity) {
+        if velocity != Vec2::ZERO {
+            // Move the view toward the edge, which moves the target the opposite way of a mouse drag.
+            events.send(ControlEvent::TranslateTarget(
+                dt * Vec2::new(-velocity.x, velocity.y),
+            ));
+        }
     }
 
-    let mut scalar = 1.0;
     for event in mouse_wheel_reader.read() {
-        // scale the event magnitude per pixel or per line
-        let scroll_amount = match event.unit {
-            MouseScrollUnit::Line => event.y,
-            MouseScrollUnit::Pixel => event.y / pixels_per_line,
+        let scroll_amount = scroll_lines(event, pixels_per_line).y;
+        if scroll_amount != 0.0 {
+            events.send(ControlEvent::zoom_from_wheel(controller, scroll_amount));
+        }
+    }
+}
+
+/// Orbits and pans in fixed steps per key press, like Blender's numpad controls:
+///
+/// - Arrows or numpad 4/6/8/2: orbit
+/// - Shift + arrows: pan
+/// - +/-: zoom in/out
+///
+/// Only the view presets are kept while a [`KeyboardNavigation`](super::keyboard::KeyboardNavigation) profile is active, which orbits, pans, and zooms smoothly
+/// with the same keys instead.
+pub fn keyboard_input_map(
+    mut events: EventWriter<ControlEvent>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut controllers: Query<(&mut OrbitCameraController, InputRoute)>,
+    routing: InputRouting,
+) {
+    if routing.is_blocked() {
+        return;
+    }
+
+    // Can only control one camera at a time.
+    let (mut controller, _) = if let Some(controller) = controllers
+        .iter_mut()
+        .find(|(c, route)| c.enabled && routing.accepts(route))
+    {
+        controller
+    } else {
+        return;
+    };
+
+    if controller.keyboard_view_presets {
+        let opposite = keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+        let preset = if keyboard.just_pressed(KeyCode::Numpad1) {
+            Some(if opposite {
+                ViewPreset::Back
+            } else {
+                ViewPreset::Front
+            })
+        } else if keyboard.just_pressed(KeyCode::Numpad3) {
+            Some(if opposite {
+                ViewPreset::Left
+            } else {
+                ViewPreset::Right
+            })
+        } else if keyboard.just_pressed(KeyCode::Numpad7) {
+            Some(if opposite {
+                ViewPreset::Bottom
+            } else {
+                ViewPreset::Top
+            })
+        } else if keyboard.just_pressed(KeyCode::Numpad5) {
+            Some(ViewPreset::Isometric)
+        } else {
+            None
+        };
+        if let Some(preset) = preset {
+            controller.snap_to_view(preset);
+        }
+    }
+
+    if routing.arrows_reserved() {
+        return;
+    }
+
+    let any_just_pressed = |keys: &[KeyCode]| keys.iter().any(|k| keyboard.just_pressed(*k));
+    let mut direction = Vec2::ZERO;
+    if any_just_pressed(&[KeyCode::ArrowLeft, KeyCode::Numpad4]) {
+        direction.x -= 1.0;
+    }
+    if any_just_pressed(&[KeyCode::ArrowRight, KeyCode::Numpad6]) {
+        direction.x += 1.0;
+    }
+    if any_just_pressed(&[KeyCode::ArrowUp, KeyCode::Numpad8]) {
+        direction.y -= 1.0;
+    }
+    if any_just_pressed(&[KeyCode::ArrowDown, KeyCode::Numpad2]) {
+        direction.y += 1.0;
+    }
+
+    if direction != Vec2::ZERO {
+        if keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+            // Pan the view in the direction of the arrow, which moves the target the opposite way of a mouse drag.
+            let step = -controller.keyboard_pan_step * direction;
+            if step != Vec2::ZERO {
+                events.send(ControlEvent::TranslateTarget(step));
+            }
+        } else {
+            let step = controller.keyboard_orbit_step * direction;
+            if step != Vec2::ZERO {
+                events.send(ControlEvent::Orbit(step));
+            }
+        }
+    }
+
+    let mut scalar = 1.0;
+    if any_just_pressed(&[KeyCode::Equal, KeyCode::NumpadAdd]) {
+        scalar *= 1.0 - controller.keyboard_zoom_step;
+    }
+    if any_just_pressed(&[KeyCode::Minus, KeyCode::NumpadSubtract]) {
+        scalar *= 1.0 + controller.keyboard_zoom_step;
+    }
+    if scalar != 1.0 {
+        events.send(ControlEvent::Zoom(scalar));
+    }
+}
+
+/// Starts and animates the snaps requested with [`OrbitCameraController::snap_to_view`]. Disabled controllers keep their
+/// requested view, and finish the snap once they are enabled again.
+pub fn view_snap_system(
+    mut commands: Commands,
+    time: CameraTime,
+    mut cameras: Query<(
+        Entity,
+        &mut OrbitCameraController,
+        &mut LookTransform,
+        Option<&mut SnappingToView>,
+    )>,
+    toggle: Res<CameraControllerToggle>,
+) {
+    if !toggle.enabled {
+        return;
+    }
+    for (entity, mut controller, mut transform, snapping) in cameras.iter_mut() {
+        if !controller.enabled {
+            continue;
+        }
+        let Some(look_direction) = transform.look_direction() else {
+            continue;
+        };
+
+        // Don't let taking the request look like a user change to the controller.
+        if let Some(eye_direction) = controller.bypass_change_detection().pending_view.take() {
+            let snap = SnappingToView {
+                from: LookAngles::from_vector(look_direction),
+                to: LookAngles::from_vector(-eye_direction),
+                duration: controller.view_snap_duration,
+                easing: controller.view_snap_easing,
+                elapsed: 0.0,
+            };
+            commands.entity(entity).insert(snap);
+            continue;
+        }
+
+        let Some(mut snapping) = snapping else {
+            continue;
+        };
+        snapping.elapsed += time.delta_secs(entity);
+
+        let radius = transform.radius();
+        let look_direction = snapping.current_angles().unit_vector();
+        match controller.mode {
+            OrbitMode::Exterior => transform.eye = transform.target - radius * look_direction,
+            OrbitMode::Interior => transform.target = transform.eye + radius * look_direction,
+        }
+
+        if snapping.is_finished() {
+            commands.entity(entity).remove::<SnappingToView>();
+        }
+    }
+}
+
+/// Moves the eye to or away from the pivot when an [`OrbitCameraController`] switches [`OrbitMode`], for
+/// [`OrbitModeTransition::MoveEye`].
+pub fn orbit_mode_transition_system(
+    mut cameras: Query<
+        (&mut OrbitCameraController, &mut LookTransform),
+        Changed<OrbitCameraController>,
+    >,
+) {
+    for (mut controller, mut transform) in cameras.iter_mut() {
+        // Keep the bookkeeping from looking like a change to the controller.
+        let controller = controller.bypass_change_detection();
+        let Some(last_mode) = controller.previous_mode.replace(controller.mode) else {
+            continue;
         };
-        scalar *= 1.0 - scroll_amount * mouse_wheel_zoom_sensitivity;
+        if controller.mode_transition == OrbitModeTransition::KeepView {
+            continue;
+        }
+        let Some(look_direction) = transform.look_direction() else {
+            continue;
+        };
+        let radius = transform.radius();
+        match (last_mode, controller.mode) {
+            (OrbitMode::Exterior, OrbitMode::Interior) => {
+                transform.eye = transform.target;
+                transform.target = transform.eye + radius * look_direction;
+            }
+            (OrbitMode::Interior, OrbitMode::Exterior) => {
+                transform.target = transform.eye;
+                transform.eye = transform.target - radius * look_direction;
+            }
+            _ => (),
+        }
     }
-    events.send(ControlEvent::Zoom(scalar));
 }
 
 pub fn control_system(
-    time: Res<Time>,
+    time: CameraTime,
     mut events: EventReader<ControlEvent>,
-    mut cameras: Query<(&OrbitCameraController, &mut LookTransform, &Transform)>,
+    mut targeted_events: EventReader<TargetedControlEvent<ControlEvent>>,
+    mut cameras: Query<(
+        &mut OrbitCameraController,
+        &mut LookTransform,
+        &Transform,
+        Has<SnappingToView>,
+        InputRoute,
+    )>,
+    mut status: ResMut<CameraControllerStatus>,
+    routing: InputRouting,
 ) {
-    // Can only control one camera at a time.
-    let (mut transform, scene_transform) =
-        if let Some((_, transform, scene_transform)) = cameras.iter_mut().find(|c| c.0.enabled) {
-            (transform, scene_transform)
-        } else {
-            return;
+    // Only one camera has the input at a time.
+    let input_camera = cameras
+        .iter()
+        .find(|(c, .., route)| c.enabled && routing.accepts(route))
+        .map(|(.., route)| route.entity);
+    let mut batches = ControlEventBatches::read(&mut events, &mut targeted_events, input_camera);
+
+    for (mut controller, mut transform, scene_transform, snapping, route) in cameras.iter_mut() {
+        let entity = route.entity;
+        let dt = time.delta_secs(entity);
+        // Cameras without events still auto-rotate, and finish their zooms and coasting.
+        let events = match batches.take(entity) {
+            Some(events) => events,
+            None if controller.enabled => Vec::new(),
+            None => continue,
         };
 
-    let mut look_angles = LookAngles::from_vector(-transform.look_direction().unwrap());
-    let mut radius_scalar = 1.0;
+        let mut look_transform = *transform;
+        // The zoom and idle state is the controller's own, so don't let it look like a user change.
+        let control_mode = apply_events(
+            controller.bypass_change_detection(),
+            &mut look_transform,
+            scene_transform.rotation,
+            snapping,
+            &events,
+            dt,
+        );
+        transform.set_if_neq(look_transform);
+
+        if batches.is_input_camera(entity) {
+            status.set_if_neq(CameraControllerStatus {
+                entity: Some(entity),
+                mode: control_mode,
+                speed: 0.0,
+                zoom: transform.radius(),
+            });
+        }
+    }
+}
+
+/// Applies one frame of `events`, `dt` seconds long, to `transform`, and returns the kind of motion. Without events, this
+/// advances the [`auto_rotate`](OrbitCameraController::auto_rotate) and any zoom or view snap in progress.
+///
+/// The math of the [`control_system`], without the ECS; see [`CameraController`](super::CameraController#control-systems).
+/// Pans move along the axes of `view_rotation`, the rotation of the camera's smoothed `Transform`. `snapping` is whether
+/// the camera has a [`SnappingToView`] animation.
+pub fn apply_events(
+    controller: &mut OrbitCameraController,
+    transform: &mut LookTransform,
+    view_rotation: Quat,
+    snapping: bool,
+    events: &[ControlEvent],
+    dt: f32,
+) -> CameraControlMode {
     let radius = transform.radius();
+    // Something else changed the radius, like a constraint or `FrameTargets`, so the zoom can't get where it was headed.
+    if controller.goal_radius.is_some()
+        && (radius - controller.written_radius).abs() > 1e-4 * controller.written_radius
+    {
+        controller.goal_radius = None;
+    }
+    let coasting = controller.inertia.is_some()
+        && (controller.pan_velocity != Vec2::ZERO || controller.zoom_velocity != 0.0);
+    let idle = events.is_empty()
+        && controller.goal_radius.is_none()
+        && controller.pending_view.is_none()
+        && !snapping
+        && !coasting;
+    let auto_rotation = if idle {
+        controller.idle_time += dt;
+        controller.auto_rotate.map_or(0.0, |auto_rotate| {
+            auto_rotate.angle(controller.idle_time, dt)
+        })
+    } else {
+        controller.idle_time = 0.0;
+        0.0
+    };
+    if idle && auto_rotation == 0.0 {
+        return CameraControlMode::Idle;
+    }
 
-    let dt = time.delta_secs();
-    for event in events.read() {
-        match event {
+    // The angles of the direction from the pivot to the point that swings around it.
+    let interior = controller.mode == OrbitMode::Interior;
+    let Some(look_direction) = transform.look_direction() else {
+        return CameraControlMode::Idle;
+    };
+    let mut look_angles = if interior {
+        LookAngles::from_vector(look_direction)
+    } else {
+        LookAngles::from_vector(-look_direction)
+    };
+    let mut goal_radius = controller.goal_radius.unwrap_or(radius);
+
+    let mut control_mode = if controller.goal_radius.is_some() {
+        CameraControlMode::Zoom
+    } else {
+        CameraControlMode::Idle
+    };
+    let pan_scale = controller
+        .pan_reference_radius
+        .map_or(1.0, |reference| radius / reference);
+    let pan = |transform: &mut LookTransform, delta: Vec2| {
+        let right_dir = view_rotation * -Vec3::X;
+        let up_dir = view_rotation * Vec3::Y;
+        let translation = pan_scale * (delta.x * right_dir + delta.y * up_dir);
+        transform.target += translation;
+        if interior {
+            transform.eye += translation;
+        }
+    };
+    // The status stays idle, since this isn't input.
+    look_angles.add_yaw(auto_rotation);
+    let mut pan_delta = None;
+    let mut zoom_delta = None;
+    for event in events {
+        match *event {
             ControlEvent::Orbit(delta) => {
-                look_angles.add_yaw(dt * -delta.x);
-                look_angles.add_pitch(dt * delta.y);
+                control_mode = CameraControlMode::Orbit;
+                look_angles.add_yaw(-delta.x);
+                if interior {
+                    look_angles.add_pitch(-delta.y);
+                } else {
+                    look_angles.add_pitch(delta.y);
+                }
+                controller.snap_remainder = Vec2::ZERO;
+            }
+            ControlEvent::SnapOrbit(delta) => {
+                control_mode = CameraControlMode::Orbit;
+                let step = controller.snap_rotation_step;
+                if step <= 0.0 {
+                    continue;
+                }
+                let pitch_sign = if interior { -1.0 } else { 1.0 };
+                controller.snap_remainder += Vec2::new(-delta.x, pitch_sign * delta.y);
+                let steps = (controller.snap_remainder / step).trunc();
+                if steps == Vec2::ZERO {
+                    continue;
+                }
+                controller.snap_remainder -= steps * step;
+
+                // Keep stepping from where the last step is headed, so quick steps don't get lost mid-animation.
+                let animating = snapping || controller.pending_view.is_some();
+                let mut angles = match controller.snap_angles {
+                    Some(angles) if animating => angles,
+                    _ => look_angles,
+                };
+                angles.set_yaw(((angles.get_yaw() / step).round() + steps.x) * step);
+                angles.set_pitch(((angles.get_pitch() / step).round() + steps.y) * step);
+                controller.snap_angles = Some(angles);
+                let direction = angles.unit_vector();
+                controller.snap_to_direction(if interior { -direction } else { direction });
             }
             ControlEvent::TranslateTarget(delta) => {
-                let right_dir = scene_transform.rotation * -Vec3::X;
-                let up_dir = scene_transform.rotation * Vec3::Y;
-                transform.target += dt * delta.x * right_dir + dt * delta.y * up_dir;
+                control_mode = CameraControlMode::Pan;
+                pan(transform, delta);
+                *pan_delta.get_or_insert(Vec2::ZERO) += delta;
             }
             ControlEvent::Zoom(scalar) => {
-                radius_scalar *= scalar;
+                control_mode = CameraControlMode::Zoom;
+                goal_radius *= scalar;
+                *zoom_delta.get_or_insert(0.0) += scalar.max(1e-3).ln();
+            }
+            ControlEvent::SnapToView(eye_direction) => {
+                controller.snap_to_direction(eye_direction);
+            }
+        }
+    }
+
+    // Track how fast the input moves, and keep moving at that speed with friction once it stops. The status stays as is,
+    // since this isn't input.
+    match controller.inertia {
+        Some(inertia) if dt > 0.0 => {
+            let tracking = 1.0 - (-dt / INERTIA_TRACKING_TIME).exp();
+            if let Some(delta) = pan_delta {
+                controller.pan_velocity = controller.pan_velocity.lerp(delta / dt, tracking);
+            } else if controller.pan_velocity != Vec2::ZERO {
+                controller.pan_velocity *= (-inertia.pan_friction * dt).exp();
+                if pan_scale * controller.pan_velocity.length() < 1e-3 * radius {
+                    controller.pan_velocity = Vec2::ZERO;
+                }
+                pan(transform, dt * controller.pan_velocity);
+            }
+            if let Some(delta) = zoom_delta {
+                controller.zoom_velocity += (delta / dt - controller.zoom_velocity) * tracking;
+            } else if controller.zoom_velocity != 0.0 {
+                controller.zoom_velocity *= (-inertia.zoom_friction * dt).exp();
+                if controller.zoom_velocity.abs() < 1e-3 {
+                    controller.zoom_velocity = 0.0;
+                }
+                goal_radius *= (dt * controller.zoom_velocity).exp();
             }
         }
+        Some(_) => {}
+        None => {
+            controller.pan_velocity = Vec2::ZERO;
+            controller.zoom_velocity = 0.0;
+        }
     }
 
     look_angles.assert_not_looking_up();
 
-    let new_radius = (radius_scalar * radius).min(1000000.0).max(0.001);
-    transform.eye = transform.target + new_radius * look_angles.unit_vector();
+    goal_radius = goal_radius.clamp(0.001, 1000000.0);
+    let zoom_lag = controller
+        .zoom_smoothing_weight
+        .clamp(0.0, 1.0)
+        .powf(60.0 * dt);
+    let mut new_radius = radius * zoom_lag + goal_radius * (1.0 - zoom_lag);
+    if (new_radius - goal_radius).abs() <= 1e-4 * goal_radius {
+        new_radius = goal_radius;
+        controller.goal_radius = None;
+    } else {
+        controller.goal_radius = Some(goal_radius);
+    }
+    controller.written_radius = new_radius;
+    if interior {
+        transform.target = transform.eye + new_radius * look_angles.unit_vector();
+    } else {
+        transform.eye = transform.target + new_radius * look_angles.unit_vector();
+    }
+    control_mode
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controllers::{
+        CameraControllerToggle, CameraInputBlocked, ViewportFocus, WindowFocus,
+    };
+    use bevy::time::{Real, Time};
+    use std::time::Duration;
+
+    #[test]
+    fn test_cameras_without_input_keep_auto_rotating() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<Time<Real>>()
+            .init_resource::<CameraInputBlocked>()
+            .init_resource::<CameraControllerToggle>()
+            .init_resource::<ViewportFocus>()
+            .init_resource::<WindowFocus>()
+            .init_resource::<CameraControllerStatus>()
+            .add_event::<ControlEvent>()
+            .add_event::<TargetedControlEvent<ControlEvent>>()
+            .add_systems(Update, control_system);
+        let controller = OrbitCameraController {
+            auto_rotate: Some(AutoRotate::new(1.0, 0.0)),
+            ..Default::default()
+        };
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y);
+        let [first, second] = [(); 2].map(|_| {
+            app.world_mut()
+                .spawn((controller, start, Transform::default()))
+                .id()
+        });
+
+        for _ in 0..10 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(16));
+            app.update();
+        }
+        // Only one of them has the input.
+        for camera in [first, second] {
+            let transform = app.world().get::<LookTransform>(camera).unwrap();
+            assert!(transform.eye.x.abs() > 0.5, "{transform:?}");
+        }
+    }
+
+    #[test]
+    fn test_disabled_cameras_wait_to_snap() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<Time<Real>>()
+            .init_resource::<CameraControllerToggle>()
+            .add_systems(Update, view_snap_system);
+        let mut controller = OrbitCameraController {
+            enabled: false,
+            ..Default::default()
+        };
+        controller.snap_to_view(ViewPreset::Right);
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y);
+        let camera = app.world_mut().spawn((controller, start)).id();
+
+        let step = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            app.update();
+        };
+        step(&mut app);
+        step(&mut app);
+        assert_eq!(*app.world().get::<LookTransform>(camera).unwrap(), start);
+
+        app.world_mut()
+            .get_mut::<OrbitCameraController>(camera)
+            .unwrap()
+            .enabled = true;
+        step(&mut app);
+        step(&mut app);
+        let transform = app.world().get::<LookTransform>(camera).unwrap();
+        assert!(transform
+            .eye
+            .abs_diff_eq(10.0 * ViewPreset::Right.eye_direction(), 1e-3));
+    }
+
+    #[test]
+    fn test_switching_to_interior_moves_the_eye_to_the_pivot() {
+        let mut app = App::new();
+        app.add_systems(Update, orbit_mode_transition_system);
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y);
+        let camera = app
+            .world_mut()
+            .spawn((OrbitCameraController::default(), start))
+            .id();
+        app.update();
+        assert_eq!(*app.world().get::<LookTransform>(camera).unwrap(), start);
+
+        app.world_mut()
+            .get_mut::<OrbitCameraController>(camera)
+            .unwrap()
+            .mode = OrbitMode::Interior;
+        app.update();
+        let transform = *app.world().get::<LookTransform>(camera).unwrap();
+        assert_eq!(transform.eye, Vec3::ZERO);
+        assert!(transform
+            .target
+            .abs_diff_eq(Vec3::new(0.0, 0.0, -10.0), 1e-5));
+
+        // Nothing else changed, so the next frame leaves it alone.
+        app.update();
+        assert_eq!(
+            *app.world().get::<LookTransform>(camera).unwrap(),
+            transform
+        );
+    }
 }
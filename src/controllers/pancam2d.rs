@@ -0,0 +1,307 @@
+use crate::{
+    controllers::{
+        controller_plugin, scroll_lines, smooth_scale, CameraBundleBuilder, CameraControlMode,
+        CameraController, CameraControllerStatus, ControlEventBatches, ControllerKind, InputRoute,
+        InputRouting, TargetedControlEvent, DEFAULT_PIXELS_PER_LINE,
+    },
+    LookTransform, LookTransformBundle, LookTransformSet, Smoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::{bundle::Bundle, prelude::*},
+    input::{mouse::MouseWheel, prelude::*},
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    render::camera::{Camera, CameraUpdateSystem, OrthographicProjection},
+    transform::components::Transform,
+    window::{PrimaryWindow, Window},
+};
+
+#[derive(Default)]
+pub struct PanCam2dPlugin {
+    pub override_input_system: bool,
+}
+
+impl PanCam2dPlugin {
+    pub fn new(override_input_system: bool) -> Self {
+        Self {
+            override_input_system,
+        }
+    }
+}
+
+impl Plugin for PanCam2dPlugin {
+    fn build(&self, app: &mut App) {
+        let app = app
+            .add_plugins(controller_plugin::<PanCam2dController>())
+            .add_systems(Update, control_system)
+            .add_systems(
+                PostUpdate,
+                scale_system
+                    .after(LookTransformSet::Sync)
+                    .before(CameraUpdateSystem),
+            );
+
+        if !self.override_input_system {
+            app.add_systems(Update, default_input_map);
+        }
+    }
+}
+
+/// Add this to an entity with a `Camera2d`.
+#[derive(Bundle)]
+pub struct PanCam2dBundle {
+    controller: PanCam2dController,
+    look_transform: LookTransformBundle,
+    transform: Transform,
+}
+
+impl PanCam2dBundle {
+    pub fn builder() -> CameraBundleBuilder<PanCam2dController> {
+        CameraBundleBuilder::new(PanCam2dController::default(), Vec3::ZERO, -Vec3::Z, Vec3::Y)
+    }
+
+    pub fn new(controller: PanCam2dController, position: Vec2) -> Self {
+        // A 2D camera looks down -Z from the XY plane.
+        let eye = position.extend(0.0);
+        let target = eye - Vec3::Z;
+
+        Self {
+            controller,
+            look_transform: LookTransformBundle {
+                transform: LookTransform::new(eye, target, Vec3::Y),
+                smoother: Smoother::new(controller.smoothing_weight),
+            },
+            transform: Transform::from_translation(eye),
+        }
+    }
+}
+
+/// A 2D camera that pans by dragging and zooms about the cursor.
+///
+/// The position is smoothed by the `Smoother`, and the orthographic scale is smoothed with the same lag weight.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug)]
+pub struct PanCam2dController {
+    pub enabled: bool,
+    pub mouse_wheel_zoom_sensitivity: f32,
+    pub pixels_per_line: f32,
+    pub smoothing_weight: f32,
+    /// The `OrthographicProjection::scale` that the projection is smoothed toward.
+    pub scale: f32,
+    pub min_scale: f32,
+    pub max_scale: f32,
+    /// If set, the center of the view is kept inside of this rectangle.
+    pub bounds: Option<Rect>,
+}
+
+impl CameraController for PanCam2dController {
+    const KIND: ControllerKind = ControllerKind::PanCam2d;
+    type Event = ControlEvent;
+
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn smoothing_weight(&self) -> f32 {
+        self.smoothing_weight
+    }
+}
+
+impl Default for PanCam2dController {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            mouse_wheel_zoom_sensitivity: 0.1,
+            pixels_per_line: DEFAULT_PIXELS_PER_LINE,
+            smoothing_weight: 0.8,
+            scale: 1.0,
+            min_scale: 0.1,
+            max_scale: 10.0,
+            bounds: None,
+        }
+    }
+}
+
+/// One frame of input for a [`PanCam2dController`], as described on [`CameraController::Event`].
+#[derive(Clone, Copy, Debug, Event, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ControlEvent {
+    /// Moves the view by a world space distance.
+    Pan(Vec2),
+    /// Multiplies the scale by `scalar`, keeping the world point at `about` (relative to the center of the view) fixed.
+    Zoom { scalar: f32, about: Vec2 },
+}
+
+impl ControlEvent {
+    /// Zooms by `lines` of mouse wheel scrolling about a point like [`ControlEvent::Zoom`], where positive lines zoom in.
+    pub fn zoom_from_wheel(controller: &PanCam2dController, lines: f32, about: Vec2) -> Self {
+        Self::Zoom {
+            scalar: 1.0 - lines * controller.mouse_wheel_zoom_sensitivity,
+            about,
+        }
+    }
+}
+
+pub fn default_input_map(
+    mut events: EventWriter<ControlEvent>,
+    mut mouse_wheel_reader: EventReader<MouseWheel>,
+    mut last_cursor_position: Local<Option<Vec2>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    controllers: Query<(
+        &PanCam2dController,
+        &Camera,
+        &OrthographicProjection,
+        InputRoute,
+    )>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    routing: InputRouting,
+) {
+    let cursor_position = windows.get_single().ok().and_then(|w| w.cursor_position());
+    let last_cursor_position = std::mem::replace(&mut *last_cursor_position, cursor_position);
+
+    if routing.is_blocked() {
+        mouse_wheel_reader.clear();
+        return;
+    }
+
+    // Can only control one camera at a time.
+    let (controller, camera, projection, _) = if let Some(controller) = controllers
+        .iter()
+        .find(|(c, _, _, route)| c.enabled && routing.accepts(route))
+    {
+        controller
+    } else {
+        return;
+    };
+    let Some(viewport) = camera.logical_viewport_rect() else {
+        mouse_wheel_reader.clear();
+        return;
+    };
+
+    // Converts window coordinates (Y down) into world units (Y up).
+    let world_per_pixel = projection.area.size() / viewport.size();
+    let to_world = |v: Vec2| Vec2::new(v.x, -v.y) * world_per_pixel;
+
+    if mouse_buttons.any_pressed([MouseButton::Left, MouseButton::Middle]) {
+        if let (Some(cursor), Some(last_cursor)) = (cursor_position, last_cursor_position) {
+            let delta = cursor - last_cursor;
+            if delta != Vec2::ZERO {
+                // Drag the world along with the cursor.
+                events.send(ControlEvent::Pan(-to_world(delta)));
+            }
+        }
+    }
+
+    let about = cursor_position.map_or(Vec2::ZERO, |c| to_world(c - viewport.center()));
+    for event in mouse_wheel_reader.read() {
+        let scroll_amount = scroll_lines(event, controller.pixels_per_line).y;
+        if scroll_amount != 0.0 {
+            events.send(ControlEvent::zoom_from_wheel(
+                controller,
+                scroll_amount,
+                about,
+            ));
+        }
+    }
+}
+
+pub fn control_system(
+    mut events: EventReader<ControlEvent>,
+    mut targeted_events: EventReader<TargetedControlEvent<ControlEvent>>,
+    mut cameras: Query<(&mut PanCam2dController, &mut LookTransform, InputRoute)>,
+    mut status: ResMut<CameraControllerStatus>,
+    routing: InputRouting,
+) {
+    // Only one camera has the input at a time.
+    let input_camera = cameras
+        .iter()
+        .find(|(c, _, route)| c.enabled && routing.accepts(route))
+        .map(|(.., route)| route.entity);
+    let mut batches = ControlEventBatches::read(&mut events, &mut targeted_events, input_camera);
+
+    for (mut controller, mut transform, route) in cameras.iter_mut() {
+        let entity = route.entity;
+        let Some(events) = batches.take(entity) else {
+            continue;
+        };
+
+        let mut look_transform = *transform;
+        // Don't let the controller's own state look like a user change, which would reset the smoother.
+        let control_mode = apply_events(
+            controller.bypass_change_detection(),
+            &mut look_transform,
+            &events,
+        );
+        transform.set_if_neq(look_transform);
+
+        if batches.is_input_camera(entity) {
+            status.set_if_neq(CameraControllerStatus {
+                entity: Some(entity),
+                mode: control_mode,
+                speed: 0.0,
+                zoom: controller.scale,
+            });
+        }
+    }
+}
+
+/// Applies one frame of `events` to `transform` and the controller's `scale`, and returns the kind of motion.
+///
+/// The math of the [`control_system`], without the ECS; see [`CameraController`](super::CameraController#control-systems).
+pub fn apply_events(
+    controller: &mut PanCam2dController,
+    transform: &mut LookTransform,
+    events: &[ControlEvent],
+) -> CameraControlMode {
+    if events.is_empty() {
+        return CameraControlMode::Idle;
+    }
+    let mut position = transform.eye.truncate();
+    let mut scale = controller.scale;
+
+    let mut control_mode = CameraControlMode::Idle;
+    for event in events {
+        match *event {
+            ControlEvent::Pan(delta) => {
+                control_mode = CameraControlMode::Pan;
+                position += delta;
+            }
+            ControlEvent::Zoom { scalar, about } => {
+                control_mode = CameraControlMode::Zoom;
+                let new_scale = (scale * scalar).clamp(controller.min_scale, controller.max_scale);
+                position += about * (1.0 - new_scale / scale);
+                scale = new_scale;
+            }
+        }
+    }
+
+    if let Some(bounds) = controller.bounds {
+        position = position.clamp(bounds.min, bounds.max);
+    }
+
+    let offset = position.extend(transform.eye.z) - transform.eye;
+    transform.eye += offset;
+    transform.target += offset;
+    controller.scale = scale;
+    control_mode
+}
+
+/// Smooths the `OrthographicProjection::scale` toward [`PanCam2dController::scale`].
+pub fn scale_system(
+    mut cameras: Query<(
+        &PanCam2dController,
+        &mut OrthographicProjection,
+        Option<&Smoother>,
+    )>,
+) {
+    for (controller, mut projection, smoother) in cameras.iter_mut() {
+        let scale = smooth_scale(projection.scale, controller.scale, smoother);
+        if projection.scale != scale {
+            projection.scale = scale;
+        }
+    }
+}
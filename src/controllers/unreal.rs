@@ -1,8 +1,21 @@
-use crate::{LookAngles, LookTransform, LookTransformBundle, Smoother};
+use crate::{
+    controllers::{
+        controller_plugin, fov_sensitivity_scale, scroll_lines, smooth_scale, AxisInversion,
+        CameraBundleBuilder, CameraControlMode, CameraController, CameraControllerStatus,
+        ControlEventBatches, ControllerKind, InputRoute, InputRouting, ModifierKey,
+        MouseDeltaFilter, TargetedControlEvent, DEFAULT_PIXELS_PER_LINE,
+    },
+    projection::ProjectionSwitch,
+    LookAngles, LookTransform, LookTransformBundle, LookTransformSet, Smoother,
+};
 
 use bevy::{
     app::prelude::*,
-    ecs::{bundle::Bundle, prelude::*},
+    ecs::{
+        bundle::Bundle,
+        prelude::*,
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+    },
     input::{
         mouse::{MouseMotion, MouseWheel},
         prelude::*,
@@ -10,31 +23,67 @@ use bevy::{
     math::prelude::*,
     prelude::ReflectDefault,
     reflect::Reflect,
-    time::Time,
+    render::camera::{CameraUpdateSystem, Projection},
     transform::components::Transform,
 };
 
-#[derive(Default)]
+use std::f32::consts::{PI, TAU};
+
 pub struct UnrealCameraPlugin {
     pub override_input_system: bool,
+    /// The schedule that the control systems run in. Defaults to `Update`.
+    pub control_schedule: InternedScheduleLabel,
+    /// The schedule that the default input map runs in. Defaults to `Update`. In the same schedule as the control
+    /// systems, the input map runs first, so input is applied in the frame that it arrives.
+    pub input_schedule: InternedScheduleLabel,
+}
+
+impl Default for UnrealCameraPlugin {
+    fn default() -> Self {
+        Self::new(false)
+    }
 }
 
 impl UnrealCameraPlugin {
     pub fn new(override_input_system: bool) -> Self {
         Self {
             override_input_system,
+            control_schedule: Update.intern(),
+            input_schedule: Update.intern(),
         }
     }
+
+    /// Runs the control systems in `schedule`, e.g. `FixedUpdate` to move the camera in step with gameplay. Add a
+    /// [`FixedInterpolation`](crate::FixedInterpolation) to the camera to keep it smooth between steps.
+    pub fn with_control_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.control_schedule = schedule.intern();
+        self
+    }
+
+    /// Runs the default input map in `schedule`, e.g. `PreUpdate` after input handling, for apps that pace their own
+    /// frames.
+    pub fn with_input_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.input_schedule = schedule.intern();
+        self
+    }
 }
 
 impl Plugin for UnrealCameraPlugin {
     fn build(&self, app: &mut App) {
         let app = app
-            .add_systems(PreUpdate, on_controller_enabled_changed)
-            .add_systems(Update, control_system)
-            .add_event::<ControlEvent>();
+            .add_plugins(controller_plugin::<UnrealCameraController>())
+            .add_systems(self.control_schedule, control_system)
+            .add_systems(
+                PostUpdate,
+                ortho_scale_system
+                    .after(LookTransformSet::Sync)
+                    .before(CameraUpdateSystem),
+            );
         if !self.override_input_system {
-            app.add_systems(Update, default_input_map);
+            app.add_systems(
+                self.input_schedule,
+                default_input_map.before(control_system),
+            );
         }
     }
 }
@@ -47,6 +96,15 @@ pub struct UnrealCameraBundle {
 }
 
 impl UnrealCameraBundle {
+    pub fn builder() -> CameraBundleBuilder<UnrealCameraController> {
+        CameraBundleBuilder::new(
+            UnrealCameraController::default(),
+            Vec3::ZERO,
+            -Vec3::Z,
+            Vec3::Y,
+        )
+    }
+
     pub fn new(controller: UnrealCameraController, eye: Vec3, target: Vec3, up: Vec3) -> Self {
         // Make sure the transform is consistent with the controller to start.
         let transform = Transform::from_translation(eye).looking_at(target, up);
@@ -63,6 +121,9 @@ impl UnrealCameraBundle {
 }
 
 /// A camera controlled with the mouse in the same way as Unreal Engine's viewport controller.
+///
+/// Like Unreal's orthographic viewports, moving forward or backward with an orthographic `Projection` zooms by changing
+/// [`ortho_scale`](Self::ortho_scale) instead, and left mouse dragging pans instead of turning.
 #[derive(Clone, Component, Copy, Debug, Reflect)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[reflect(Component, Default, Debug)]
@@ -70,48 +131,192 @@ pub struct UnrealCameraController {
     /// Whether to process input or ignore it
     pub enabled: bool,
 
-    /// How many radians per frame for each rotation axis (yaw, pitch) when rotating with the mouse
+    /// How many radians per pixel for each rotation axis (yaw, pitch) when rotating with the mouse
     pub rotate_sensitivity: Vec2,
 
-    /// How many units per frame for each direction when translating using Middle or L+R panning
+    /// How many units per pixel for each direction when translating using Middle or L+R panning
     pub mouse_translate_sensitivity: Vec2,
 
-    /// How many units per frame when translating using scroll wheel
+    /// How many units per line when translating using scroll wheel
     pub wheel_translate_sensitivity: f32,
 
-    /// How many units per frame when translating using W/S/Q/E
+    /// How many units per second when translating using W/S/Q/E
     /// Updated with scroll wheel while dragging with any mouse button
     pub keyboard_mvmt_sensitivity: f32,
 
     /// Wheel sensitivity for modulating keyboard movement speed
     pub keyboard_mvmt_wheel_sensitivity: f32,
 
+    /// What scrolling does while dragging with the Right mouse button
+    pub rotate_wheel_mode: RotateWheelMode,
+
+    /// How many pixels of trackpad scrolling count as one wheel notch
+    pub pixels_per_line: f32,
+
     /// The greater, the slower to follow input
     pub smoothing_weight: f32,
+
+    /// When set, `rotate_sensitivity` is tuned for this horizontal FOV (radians) and scaled to match the camera's current
+    /// one. See [`fov_sensitivity_scale`].
+    pub fov_compensation: Option<f32>,
+
+    /// Cleans up mouse motion before it's used
+    pub mouse_delta_filter: MouseDeltaFilter,
+
+    /// Flips mouse and wheel directions
+    pub invert: AxisInversion,
+
+    /// How fast forward/backward locomotion shrinks the orthographic scale, per unit of locomotion
+    pub ortho_zoom_sensitivity: f32,
+
+    /// The orthographic scale that an orthographic projection is smoothed toward. It starts out as the projection's own
+    /// scale once the controller is added.
+    pub ortho_scale: f32,
+
+    /// When set, mouse panning is tuned for a point under the cursor at this depth, and scaled in proportion to the
+    /// actual depth from the camera's [`CursorDepth`], so the scene under the mouse keeps up with the cursor like in
+    /// Unreal. Without a known depth, pans aren't scaled
+    pub pan_reference_depth: Option<f32>,
+
+    /// When set, the camera can be rolled for dutch angles
+    pub roll_controls: Option<RollControls>,
+
+    /// The current roll in radians around the view direction, positive when rolled to the right. It's applied through the
+    /// `LookTransform::up` vector
+    pub roll: f32,
+}
+
+impl CameraController for UnrealCameraController {
+    const KIND: ControllerKind = ControllerKind::Unreal;
+    type Event = ControlEvent;
+
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn smoothing_weight(&self) -> f32 {
+        self.smoothing_weight
+    }
+
+    fn reseed(&mut self, _transform: &LookTransform) {
+        self.mouse_delta_filter.reset();
+    }
 }
 
 impl Default for UnrealCameraController {
     fn default() -> Self {
         Self {
             enabled: true,
-            rotate_sensitivity: Vec2::splat(0.2),
-            mouse_translate_sensitivity: Vec2::splat(2.0),
-            wheel_translate_sensitivity: 50.0,
+            rotate_sensitivity: Vec2::splat(0.0033),
+            mouse_translate_sensitivity: Vec2::splat(0.033),
+            wheel_translate_sensitivity: 0.83,
             keyboard_mvmt_sensitivity: 10.0,
             keyboard_mvmt_wheel_sensitivity: 5.0,
+            rotate_wheel_mode: RotateWheelMode::default(),
+            pixels_per_line: DEFAULT_PIXELS_PER_LINE,
             smoothing_weight: 0.7,
+            fov_compensation: None,
+            mouse_delta_filter: MouseDeltaFilter::default(),
+            invert: AxisInversion::default(),
+            ortho_zoom_sensitivity: 0.1,
+            ortho_scale: 1.0,
+            pan_reference_depth: None,
+            roll_controls: None,
+            roll: 0.0,
         }
     }
 }
 
-#[derive(Event)]
+impl UnrealCameraController {
+    /// How much to scale mouse pans for a point under the cursor at `depth`. See `pan_reference_depth`.
+    pub fn pan_depth_scale(&self, depth: f32) -> f32 {
+        self.pan_reference_depth
+            .filter(|reference| *reference > 0.0)
+            .map_or(1.0, |reference| depth.max(0.0) / reference)
+    }
+}
+
+/// Keys and a mouse drag that roll an [`UnrealCameraController`], for visualizations that need dutch angles.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub struct RollControls {
+    pub roll_left: KeyCode,
+    pub roll_right: KeyCode,
+    /// Radians per second while a roll key is held.
+    pub key_speed: f32,
+    /// While this is held, horizontal Right mouse drags roll instead of turning.
+    pub mouse_modifier: Option<ModifierKey>,
+    /// Radians for each pixel of mouse motion.
+    pub mouse_sensitivity: f32,
+}
+
+impl Default for RollControls {
+    fn default() -> Self {
+        Self {
+            roll_left: KeyCode::KeyZ,
+            roll_right: KeyCode::KeyC,
+            key_speed: 1.0,
+            mouse_modifier: None,
+            mouse_sensitivity: 0.0033,
+        }
+    }
+}
+
+/// What scrolling does while an [`UnrealCameraController`] is turned by dragging with the Right mouse button.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum RotateWheelMode {
+    /// Changes `keyboard_mvmt_sensitivity`, like while dragging with any other button.
+    #[default]
+    AdjustSpeed,
+    /// Dollies along the view direction with `wheel_translate_sensitivity`, like recent Unreal Engine versions.
+    Dolly,
+}
+
+/// The depth along the view direction of the scene point under the cursor, for
+/// [`UnrealCameraController::pan_reference_depth`]. Apps update this from their own raycast or depth buffer read, and set
+/// it to `None` when the cursor is over empty space.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct CursorDepth(pub Option<f32>);
+
+/// One frame of input for an [`UnrealCameraController`], as described on [`CameraController::Event`].
+#[derive(Clone, Copy, Debug, Event, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum ControlEvent {
+    /// Turns by a yaw angle in radians (X) and moves forward by a world space distance (Y).
     Locomotion(Vec2),
+    /// Turns by yaw (X) and pitch (Y) angles in radians.
     Rotate(Vec2),
+    /// Moves the eye right (X) and up (Y) by world space distances.
     TranslateEye(Vec2),
+    /// Rolls to the right by an angle in radians.
+    Roll(f32),
 }
 
-define_on_controller_enabled_changed!(UnrealCameraController);
+impl ControlEvent {
+    /// Turns by a mouse motion of `cursor_delta` pixels, compensating for the camera's `projection` if the controller has
+    /// `fov_compensation`.
+    pub fn rotate_from_mouse(
+        controller: &UnrealCameraController,
+        projection: Option<&Projection>,
+        cursor_delta: Vec2,
+    ) -> Self {
+        let scale = fov_sensitivity_scale(controller.fov_compensation, projection);
+        Self::Rotate(
+            scale * controller.rotate_sensitivity * controller.invert.rotate.apply(cursor_delta),
+        )
+    }
+
+    /// Pans by a mouse motion of `cursor_delta` pixels.
+    pub fn pan_from_mouse(controller: &UnrealCameraController, cursor_delta: Vec2) -> Self {
+        Self::TranslateEye(
+            controller.mouse_translate_sensitivity * controller.invert.pan.apply(cursor_delta),
+        )
+    }
+}
 
 pub fn default_input_map(
     mut events: EventWriter<ControlEvent>,
@@ -119,10 +324,25 @@ pub fn default_input_map(
     mut mouse_motion_events: EventReader<MouseMotion>,
     keyboard: Res<ButtonInput<KeyCode>>,
     mouse_buttons: Res<ButtonInput<MouseButton>>,
-    mut controllers: Query<&mut UnrealCameraController>,
+    mut controllers: Query<(
+        &mut UnrealCameraController,
+        Option<&Projection>,
+        Option<&CursorDepth>,
+        InputRoute,
+    )>,
+    routing: InputRouting,
 ) {
+    if routing.is_blocked() {
+        mouse_wheel_reader.clear();
+        mouse_motion_events.clear();
+        return;
+    }
+
     // Can only control one camera at a time.
-    let mut controller = if let Some(controller) = controllers.iter_mut().find(|c| c.enabled) {
+    let (mut controller, projection, cursor_depth, route) = if let Some(controller) = controllers
+        .iter_mut()
+        .find(|(c, .., route)| c.enabled && routing.accepts(route))
+    {
         controller
     } else {
         return;
@@ -133,8 +353,21 @@ pub fn default_input_map(
         wheel_translate_sensitivity,
         mut keyboard_mvmt_sensitivity,
         keyboard_mvmt_wheel_sensitivity,
+        fov_compensation,
+        pixels_per_line,
+        invert,
         ..
     } = *controller;
+    let mouse_rotate_sensitivity =
+        mouse_rotate_sensitivity * fov_sensitivity_scale(fov_compensation, projection);
+    // Depth doesn't change the scale of orthographic views.
+    let orthographic = matches!(projection, Some(Projection::Orthographic(_)));
+    let pan_sensitivity = match cursor_depth.and_then(|d| d.0) {
+        Some(depth) if !orthographic => {
+            controller.pan_depth_scale(depth) * mouse_translate_sensitivity
+        }
+        _ => mouse_translate_sensitivity,
+    };
 
     let left_pressed = mouse_buttons.pressed(MouseButton::Left);
     let right_pressed = mouse_buttons.pressed(MouseButton::Right);
@@ -144,11 +377,19 @@ pub fn default_input_map(
     for event in mouse_motion_events.read() {
         cursor_delta += event.delta;
     }
+    let dt = routing.delta_secs(&route);
+    // The filter's state is the controller's own bookkeeping, so don't let it look like a user change.
+    let cursor_delta = controller
+        .bypass_change_detection()
+        .mouse_delta_filter
+        .filter(cursor_delta, dt);
 
     let mut wheel_delta = 0.0;
     for event in mouse_wheel_reader.read() {
-        wheel_delta += event.x + event.y;
+        let lines = scroll_lines(event, pixels_per_line);
+        wheel_delta += lines.x + lines.y;
     }
+    let wheel_delta = invert.apply_zoom(wheel_delta);
 
     let mut panning_dir = Vec2::ZERO;
     let mut translation_dir = Vec2::ZERO; // y is forward/backward axis, x is rotation around Z
@@ -189,14 +430,18 @@ pub fn default_input_map(
     // If any of the mouse button are pressed; read additional signals from the keyboard for panning
     // and locomotion along camera view axis
     if left_pressed || middle_pressed || right_pressed {
-        panning += keyboard_mvmt_sensitivity * panning_dir;
+        panning += dt * keyboard_mvmt_sensitivity * panning_dir;
 
         if translation_dir.y != 0.0 {
-            locomotion.y += keyboard_mvmt_sensitivity * translation_dir.y;
+            locomotion.y += dt * keyboard_mvmt_sensitivity * translation_dir.y;
         }
 
-        keyboard_mvmt_sensitivity += keyboard_mvmt_wheel_sensitivity * wheel_delta;
-        controller.keyboard_mvmt_sensitivity = keyboard_mvmt_sensitivity.max(0.01);
+        if right_pressed && controller.rotate_wheel_mode == RotateWheelMode::Dolly {
+            locomotion.y += wheel_translate_sensitivity * wheel_delta;
+        } else {
+            keyboard_mvmt_sensitivity += keyboard_mvmt_wheel_sensitivity * wheel_delta;
+            controller.keyboard_mvmt_sensitivity = keyboard_mvmt_sensitivity.max(0.01);
+        }
     }
     // Otherwise, if any scrolling is happening, do locomotion along camera view axis
     else if wheel_delta != 0.0 {
@@ -205,21 +450,52 @@ pub fn default_input_map(
 
     // You can also pan using the mouse only; add those signals to existing panning
     if middle_pressed || (left_pressed && right_pressed) {
-        panning += mouse_translate_sensitivity * cursor_delta;
+        panning += pan_sensitivity * invert.pan.apply(cursor_delta);
     }
 
-    // When left only is pressed, mouse movements add up to the "unreal locomotion" scheme
-    if left_pressed && !middle_pressed && !right_pressed {
-        locomotion.x = mouse_rotate_sensitivity.x * cursor_delta.x;
+    // When left only is pressed, mouse movements add up to the "unreal locomotion" scheme, except that orthographic
+    // viewports can't turn, so they pan instead
+    if left_pressed && !middle_pressed && !right_pressed && orthographic {
+        panning += pan_sensitivity * invert.pan.apply(cursor_delta);
+    } else if left_pressed && !middle_pressed && !right_pressed {
+        locomotion.x = mouse_rotate_sensitivity.x * invert.rotate.apply(cursor_delta).x;
         locomotion.y -= mouse_translate_sensitivity.y * cursor_delta.y;
     }
 
-    if !left_pressed && !middle_pressed && right_pressed {
-        events.send(ControlEvent::Rotate(
-            mouse_rotate_sensitivity * cursor_delta,
+    let mut roll = 0.0;
+    let mut mouse_roll = false;
+    if let Some(roll_controls) = controller.roll_controls {
+        if keyboard.pressed(roll_controls.roll_left) {
+            roll -= dt * roll_controls.key_speed;
+        }
+        if keyboard.pressed(roll_controls.roll_right) {
+            roll += dt * roll_controls.key_speed;
+        }
+        mouse_roll = roll_controls
+            .mouse_modifier
+            .is_some_and(|modifier| modifier.is_pressed(&keyboard));
+        if mouse_roll && !left_pressed && !middle_pressed && right_pressed {
+            roll += roll_controls.mouse_sensitivity * invert.rotate.apply(cursor_delta).x;
+        }
+    }
+
+    if !left_pressed
+        && !middle_pressed
+        && right_pressed
+        && !mouse_roll
+        && cursor_delta != Vec2::ZERO
+    {
+        events.send(ControlEvent::rotate_from_mouse(
+            &controller,
+            projection,
+            cursor_delta,
         ));
     }
 
+    if roll != 0.0 {
+        events.send(ControlEvent::Roll(roll));
+    }
+
     if panning.length_squared() > 0.0 {
         events.send(ControlEvent::TranslateEye(panning));
     }
@@ -230,48 +506,224 @@ pub fn default_input_map(
 }
 
 pub fn control_system(
-    time: Res<Time>,
     mut events: EventReader<ControlEvent>,
-    mut cameras: Query<(&UnrealCameraController, &mut LookTransform)>,
+    mut targeted_events: EventReader<TargetedControlEvent<ControlEvent>>,
+    mut cameras: Query<(
+        &mut UnrealCameraController,
+        &mut LookTransform,
+        Option<&Projection>,
+        InputRoute,
+    )>,
+    switches: Query<(), With<ProjectionSwitch>>,
+    mut status: ResMut<CameraControllerStatus>,
+    routing: InputRouting,
 ) {
-    // Can only control one camera at a time.
-    let mut transform = if let Some((_, transform)) = cameras.iter_mut().find(|c| c.0.enabled) {
-        transform
-    } else {
-        return;
-    };
+    // Only one camera has the input at a time.
+    let input_camera = cameras
+        .iter()
+        .find(|(c, .., route)| c.enabled && routing.accepts(route))
+        .map(|(.., route)| route.entity);
+    let mut batches = ControlEventBatches::read(&mut events, &mut targeted_events, input_camera);
+
+    for (mut controller, mut transform, projection, route) in cameras.iter_mut() {
+        let entity = route.entity;
+        let Some(events) = batches.take(entity) else {
+            continue;
+        };
+        // A `ProjectionSwitch` frames its orthographic view from the distance to the target, so moving the eye zooms.
+        let orthographic =
+            !switches.contains(entity) && matches!(projection, Some(Projection::Orthographic(_)));
+
+        let mut look_transform = *transform;
+        // Only zooming and rolling change the controller, so only they should look like a change.
+        let (ortho_scale, roll) = (controller.ortho_scale, controller.roll);
+        let mode = apply_events(
+            controller.bypass_change_detection(),
+            &mut look_transform,
+            orthographic,
+            &events,
+        );
+        if (controller.ortho_scale, controller.roll) != (ortho_scale, roll) {
+            controller.set_changed();
+        }
+        transform.set_if_neq(look_transform);
+
+        if batches.is_input_camera(entity) {
+            status.set_if_neq(CameraControllerStatus {
+                entity: Some(entity),
+                mode,
+                speed: controller.keyboard_mvmt_sensitivity,
+                zoom: if orthographic {
+                    controller.ortho_scale
+                } else {
+                    transform.radius()
+                },
+            });
+        }
+    }
+}
 
-    let look_vector = match transform.look_direction() {
-        Some(safe_look_vector) => safe_look_vector,
-        None => return,
+/// Applies one frame of `events` to `transform`, and returns the kind of motion.
+///
+/// The math of the [`control_system`], without the ECS; see [`CameraController`](super::CameraController#control-systems).
+/// With an `orthographic` projection, locomotion zooms the [`ortho_scale`](UnrealCameraController::ortho_scale) instead.
+pub fn apply_events(
+    controller: &mut UnrealCameraController,
+    transform: &mut LookTransform,
+    orthographic: bool,
+    events: &[ControlEvent],
+) -> CameraControlMode {
+    let (false, Some(look_vector)) = (events.is_empty(), transform.look_direction()) else {
+        return CameraControlMode::Idle;
     };
     let mut look_angles = LookAngles::from_vector(look_vector);
-
-    let dt = time.delta_secs();
-    for event in events.read() {
-        match event {
+    // Taken before moving, so flying up to the target can't collapse the view.
+    let radius = transform.radius();
+    // An unrolled camera leaves `up` alone, in case it was set to something else.
+    let mut rolled = controller.roll != 0.0;
+
+    let mut mode = CameraControlMode::Idle;
+    for event in events {
+        match *event {
+            ControlEvent::Locomotion(delta) if orthographic => {
+                // Moving along the view axis doesn't change an orthographic view, so zoom instead.
+                mode = CameraControlMode::Zoom;
+                let scalar = (-delta.y * controller.ortho_zoom_sensitivity).exp();
+                controller.ortho_scale = (controller.ortho_scale * scalar).clamp(0.001, 1000.0);
+                look_angles.add_yaw(-delta.x);
+            }
             ControlEvent::Locomotion(delta) => {
+                mode = CameraControlMode::Fly;
                 // Translates forward/backward and rotates about the Y axis.
-                look_angles.add_yaw(dt * -delta.x);
-                transform.eye += dt * delta.y * look_vector;
+                look_angles.add_yaw(-delta.x);
+                transform.eye += delta.y * look_vector;
             }
             ControlEvent::Rotate(delta) => {
+                mode = CameraControlMode::Look;
                 // Rotates with pitch and yaw.
-                look_angles.add_yaw(dt * -delta.x);
-                look_angles.add_pitch(dt * -delta.y);
+                look_angles.add_yaw(-delta.x);
+                look_angles.add_pitch(-delta.y);
             }
             ControlEvent::TranslateEye(delta) => {
+                mode = CameraControlMode::Pan;
                 let yaw_rot = Quat::from_axis_angle(Vec3::Y, look_angles.get_yaw());
                 let rot_x = yaw_rot * Vec3::X;
 
-                // Translates up/down and left/right (X).
-                let up = transform.up;
-                transform.eye -= dt * delta.x * rot_x - dt * delta.y * up;
+                // Translates up/down and left/right (X). Orthographic views pan in the view plane, since they are often
+                // looking straight down.
+                let up = if orthographic {
+                    rot_x.cross(look_angles.unit_vector())
+                } else {
+                    transform.up
+                };
+                transform.eye -= delta.x * rot_x - delta.y * up;
+            }
+            ControlEvent::Roll(delta) => {
+                mode = CameraControlMode::Look;
+                rolled = true;
+                controller.roll = (controller.roll + delta + PI).rem_euclid(TAU) - PI;
             }
         }
     }
 
     look_angles.assert_not_looking_up();
 
-    transform.target = transform.eye + transform.radius() * look_angles.unit_vector();
+    let look_vector = look_angles.unit_vector();
+    transform.target = transform.eye + radius * look_vector;
+    if rolled {
+        transform.up = Quat::from_axis_angle(look_vector, controller.roll) * Vec3::Y;
+    }
+    mode
+}
+
+/// Smooths an orthographic `Projection` toward [`UnrealCameraController::ortho_scale`]. Cameras with a
+/// [`ProjectionSwitch`] are left to its framing.
+pub fn ortho_scale_system(
+    mut cameras: Query<
+        (
+            &mut UnrealCameraController,
+            &mut Projection,
+            Option<&Smoother>,
+        ),
+        Without<ProjectionSwitch>,
+    >,
+) {
+    for (mut controller, mut projection, smoother) in cameras.iter_mut() {
+        let Projection::Orthographic(ortho) = projection.as_ref() else {
+            continue;
+        };
+        if controller.is_added() || projection.is_added() {
+            // Keep the scale the camera was spawned with, rather than the default.
+            controller.bypass_change_detection().ortho_scale = ortho.scale;
+            continue;
+        }
+        let scale = smooth_scale(ortho.scale, controller.ortho_scale, smoother);
+        if ortho.scale != scale {
+            if let Projection::Orthographic(ortho) = projection.as_mut() {
+                ortho.scale = scale;
+            }
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::render::camera::OrthographicProjection;
+
+    #[test]
+    fn test_ortho_scale_starts_from_the_projection() {
+        let mut app = App::new();
+        app.add_systems(Update, ortho_scale_system);
+        let projection = Projection::Orthographic(OrthographicProjection {
+            scale: 5.0,
+            ..OrthographicProjection::default_3d()
+        });
+        let camera = app
+            .world_mut()
+            .spawn((UnrealCameraController::default(), projection))
+            .id();
+        let scale = |app: &App| match app.world().get::<Projection>(camera) {
+            Some(Projection::Orthographic(ortho)) => ortho.scale,
+            _ => unreachable!(),
+        };
+        app.update();
+        app.update();
+        assert_eq!(scale(&app), 5.0);
+
+        let mut controller = app
+            .world_mut()
+            .get_mut::<UnrealCameraController>(camera)
+            .unwrap();
+        let mut transform = LookTransform::new(Vec3::Z, Vec3::ZERO, Vec3::Y);
+        apply_events(
+            &mut controller,
+            &mut transform,
+            true,
+            &[ControlEvent::Locomotion(Vec2::new(0.0, 10.0))],
+        );
+        app.update();
+        assert!((scale(&app) - 5.0 * (-1.0f32).exp()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_flying_forward_keeps_the_radius() {
+        let mut controller = UnrealCameraController::default();
+        let mut transform = LookTransform::new(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+        apply_events(
+            &mut controller,
+            &mut transform,
+            false,
+            &[ControlEvent::Locomotion(Vec2::new(0.0, 2.0))],
+        );
+        assert!(transform.eye.abs_diff_eq(Vec3::new(0.0, 0.0, 3.0), 1e-5));
+        assert!((transform.radius() - 5.0).abs() < 1e-5);
+    }
 }
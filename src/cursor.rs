@@ -0,0 +1,181 @@
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    input::prelude::*,
+    math::prelude::*,
+    reflect::Reflect,
+    window::{CursorGrabMode, Window},
+};
+
+/// Hides the cursor and locks it to the window, for mouse-look.
+pub fn grab_cursor(window: &mut Window) {
+    window.cursor_options.grab_mode = CursorGrabMode::Locked;
+    window.cursor_options.visible = false;
+}
+
+/// Undoes [`grab_cursor`].
+pub fn release_cursor(window: &mut Window) {
+    window.cursor_options.grab_mode = CursorGrabMode::None;
+    window.cursor_options.visible = true;
+}
+
+/// Whether the cursor was captured with [`grab_cursor`]. A visible cursor that is only confined doesn't count.
+pub fn is_cursor_grabbed(window: &Window) -> bool {
+    window.cursor_options.grab_mode != CursorGrabMode::None && !window.cursor_options.visible
+}
+
+/// Keeps the cursor inside of a [`CursorConfinement`] region while a camera drag is active.
+pub struct CursorConfinementPlugin;
+
+impl Plugin for CursorConfinementPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreUpdate, cursor_confinement_system);
+    }
+}
+
+/// Add this to a `Window` entity to confine the cursor to `region` while any of the `drag_buttons` are held.
+///
+/// A drag is only confined if it starts inside of the region, so clicks on docked UI panels are left alone. While confined,
+/// the window's `CursorGrabMode` is set to `Confined`, and it is restored when the drag ends. Nothing is confined while the
+/// cursor is grabbed for mouse-look, see [`is_cursor_grabbed`].
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Debug)]
+pub struct CursorConfinement {
+    pub enabled: bool,
+    /// The confinement rectangle in logical pixels, relative to the top-left corner of the window. `None` means the whole
+    /// window.
+    pub region: Option<Rect>,
+    pub drag_buttons: Vec<MouseButton>,
+    previous_grab_mode: Option<CursorGrabMode>,
+}
+
+impl Default for CursorConfinement {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            region: None,
+            drag_buttons: vec![MouseButton::Left, MouseButton::Right, MouseButton::Middle],
+            previous_grab_mode: None,
+        }
+    }
+}
+
+impl CursorConfinement {
+    pub fn new(region: Rect) -> Self {
+        Self {
+            region: Some(region),
+            ..Default::default()
+        }
+    }
+
+    /// Whether a drag is currently being confined.
+    pub fn is_confining(&self) -> bool {
+        self.previous_grab_mode.is_some()
+    }
+
+    fn region_in(&self, window: &Window) -> Rect {
+        self.region
+            .unwrap_or_else(|| Rect::from_corners(Vec2::ZERO, window.size()))
+    }
+}
+
+pub fn cursor_confinement_system(
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut windows: Query<(&mut Window, &mut CursorConfinement)>,
+) {
+    for (mut window, mut confinement) in windows.iter_mut() {
+        let dragging = confinement
+            .drag_buttons
+            .iter()
+            .any(|b| mouse_buttons.pressed(*b));
+
+        if is_cursor_grabbed(&window) {
+            // Mouse-look owns the cursor now, and restoring the mode from before the drag would release it.
+            confinement.previous_grab_mode = None;
+            continue;
+        }
+
+        if !confinement.enabled || !dragging {
+            if let Some(grab_mode) = confinement.previous_grab_mode.take() {
+                window.cursor_options.grab_mode = grab_mode;
+            }
+            continue;
+        }
+
+        let region = confinement.region_in(&window);
+        let cursor = window.cursor_position();
+
+        if !confinement.is_confining() {
+            let drag_started = confinement
+                .drag_buttons
+                .iter()
+                .any(|b| mouse_buttons.just_pressed(*b));
+            if !drag_started || !cursor.map_or(false, |c| region.contains(c)) {
+                continue;
+            }
+            confinement.previous_grab_mode = Some(window.cursor_options.grab_mode);
+            window.cursor_options.grab_mode = CursorGrabMode::Confined;
+        }
+
+        if let Some(cursor) = cursor {
+            let clamped = cursor.clamp(region.min, region.max);
+            if clamped != cursor {
+                window.set_cursor_position(Some(clamped));
+            }
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grabbed_cursor_is_not_confined() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<MouseButton>>()
+            .add_systems(Update, cursor_confinement_system);
+        let mut window = Window::default();
+        window.set_cursor_position(Some(Vec2::splat(10.0)));
+        let window = app
+            .world_mut()
+            .spawn((window, CursorConfinement::default()))
+            .id();
+        let press = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<ButtonInput<MouseButton>>()
+                .press(MouseButton::Right);
+            app.update();
+        };
+
+        press(&mut app);
+        assert!(app
+            .world()
+            .get::<CursorConfinement>(window)
+            .unwrap()
+            .is_confining());
+
+        // Grabbing mid-drag takes over, and releasing the button mustn't undo the grab.
+        grab_cursor(&mut app.world_mut().get_mut::<Window>(window).unwrap());
+        press(&mut app);
+        assert!(!app
+            .world()
+            .get::<CursorConfinement>(window)
+            .unwrap()
+            .is_confining());
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .release(MouseButton::Right);
+        app.update();
+        let window = app.world().get::<Window>(window).unwrap();
+        assert!(is_cursor_grabbed(window));
+        assert_eq!(window.cursor_options.grab_mode, CursorGrabMode::Locked);
+    }
+}
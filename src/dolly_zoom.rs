@@ -0,0 +1,197 @@
+use crate::{clock::CameraTime, ease::Ease, LookTransform, LookTransformSet};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    reflect::Reflect,
+    render::camera::{CameraUpdateSystem, Projection},
+    transform::components::Transform,
+};
+
+/// Performs dolly zooms requested with the [`DollyZoom`] event.
+pub struct DollyZoomPlugin;
+
+impl Plugin for DollyZoomPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<DollyZoom>()
+            .add_systems(
+                Update,
+                (start_dolly_zoom_system, dolly_zoom_eye_system).chain(),
+            )
+            .add_systems(
+                PostUpdate,
+                dolly_zoom_fov_system
+                    .after(LookTransformSet::Sync)
+                    .before(CameraUpdateSystem),
+            );
+    }
+}
+
+/// Starts a dolly zoom ("vertigo" effect) on `camera`: the eye moves along the look axis while the field of view changes, so
+/// that the view width at the target stays constant.
+///
+/// The camera must have a `LookTransform` and a perspective `Projection`.
+#[derive(Clone, Copy, Debug, Event)]
+pub struct DollyZoom {
+    pub camera: Entity,
+    /// The vertical field of view at the end of the effect, in radians.
+    pub target_fov: f32,
+    /// The width of the view at the target distance that is held constant. `None` keeps the current width.
+    pub target_width: Option<f32>,
+    /// How long the effect takes, in seconds.
+    pub duration: f32,
+    /// How the field of view, and with it the eye, moves over the effect. The classic effect is `Linear`.
+    pub easing: Ease,
+}
+
+/// The state of a dolly zoom in progress. This is removed once the effect finishes and the smoothed eye has caught up.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[reflect(Component, Debug)]
+pub struct DollyZooming {
+    pub width: f32,
+    pub start_fov: f32,
+    pub end_fov: f32,
+    pub duration: f32,
+    pub elapsed: f32,
+    pub easing: Ease,
+}
+
+impl DollyZooming {
+    pub fn progress(&self) -> f32 {
+        if self.duration > 0.0 {
+            (self.elapsed / self.duration).min(1.0)
+        } else {
+            1.0
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// The field of view that the eye distance is currently driven toward.
+    pub fn current_fov(&self) -> f32 {
+        self.start_fov + (self.end_fov - self.start_fov) * self.easing.sample(self.progress())
+    }
+}
+
+/// The distance at which a view with `fov` (vertical) and `aspect_ratio` is exactly `width` wide.
+pub fn distance_for_width(width: f32, fov: f32, aspect_ratio: f32) -> f32 {
+    width / (2.0 * (0.5 * fov).tan() * aspect_ratio)
+}
+
+/// The vertical field of view at which a view at `distance` is exactly `width` wide.
+pub fn fov_for_width(width: f32, distance: f32, aspect_ratio: f32) -> f32 {
+    2.0 * (width / (2.0 * distance * aspect_ratio)).atan()
+}
+
+pub fn start_dolly_zoom_system(
+    mut commands: Commands,
+    mut events: EventReader<DollyZoom>,
+    cameras: Query<(&LookTransform, &Projection)>,
+) {
+    for event in events.read() {
+        let Ok((look_transform, Projection::Perspective(perspective))) = cameras.get(event.camera)
+        else {
+            continue;
+        };
+
+        let width = event.target_width.unwrap_or_else(|| {
+            2.0 * look_transform.radius() * (0.5 * perspective.fov).tan() * perspective.aspect_ratio
+        });
+
+        commands.entity(event.camera).insert(DollyZooming {
+            width,
+            start_fov: perspective.fov,
+            end_fov: event.target_fov,
+            duration: event.duration,
+            elapsed: 0.0,
+            easing: event.easing,
+        });
+    }
+}
+
+pub fn dolly_zoom_eye_system(
+    time: CameraTime,
+    mut cameras: Query<(Entity, &mut DollyZooming, &mut LookTransform, &Projection)>,
+) {
+    for (entity, mut dolly, mut look_transform, projection) in cameras.iter_mut() {
+        let Projection::Perspective(perspective) = projection else {
+            continue;
+        };
+        let Some(look_direction) = look_transform.look_direction() else {
+            continue;
+        };
+
+        dolly.elapsed += time.delta_secs(entity);
+
+        let distance =
+            distance_for_width(dolly.width, dolly.current_fov(), perspective.aspect_ratio);
+        look_transform.eye = look_transform.target - distance * look_direction;
+    }
+}
+
+/// Derives the field of view from the *smoothed* eye position, so the subject keeps its size on screen even while the
+/// `Smoother` is catching up.
+pub fn dolly_zoom_fov_system(
+    mut commands: Commands,
+    mut cameras: Query<(
+        Entity,
+        &DollyZooming,
+        &LookTransform,
+        &Transform,
+        &mut Projection,
+    )>,
+) {
+    for (entity, dolly, look_transform, transform, mut projection) in cameras.iter_mut() {
+        let Projection::Perspective(perspective) = projection.as_mut() else {
+            continue;
+        };
+
+        let smoothed_distance = transform.translation.distance(look_transform.target);
+        if smoothed_distance > 0.0 {
+            perspective.fov =
+                fov_for_width(dolly.width, smoothed_distance, perspective.aspect_ratio);
+        }
+
+        let goal_distance = look_transform.radius();
+        if dolly.is_finished() && (smoothed_distance - goal_distance).abs() <= 1e-3 * goal_distance
+        {
+            perspective.fov = dolly.end_fov;
+            commands.entity(entity).remove::<DollyZooming>();
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_width_is_held_constant() {
+        let aspect_ratio = 16.0 / 9.0;
+        let width = 4.0;
+        for fov in [0.2, 0.8, 1.5] {
+            let distance = distance_for_width(width, fov, aspect_ratio);
+            assert_relative_eq!(
+                fov_for_width(width, distance, aspect_ratio),
+                fov,
+                epsilon = 1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn test_narrower_fov_moves_eye_back() {
+        assert!(distance_for_width(1.0, 0.3, 1.0) > distance_for_width(1.0, 1.2, 1.0));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{clock::CameraTime, xr::XrDriven, LookTransform, Smoother};
+
+use bevy::{
+    ecs::prelude::*, math::prelude::*, prelude::ReflectDefault, reflect::Reflect,
+    transform::components::Transform,
+};
+
+/// Slow, subtle motion of the eye and target, like a handheld camera, for cutscenes and menus.
+///
+/// The drift follows smooth 1D gradient (Perlin) noise on each axis, and is added to the smoothed `Transform`, so it never
+/// feeds back into the `LookTransform` and works with any controller, or none. Unlike a camera shake, it never stops: the
+/// camera keeps drifting for as long as the component is enabled. Cameras with different `seed`s drift differently.
+#[derive(Clone, Component, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct CameraDrift {
+    pub enabled: bool,
+    /// The largest offset of the eye from its position, in world units.
+    pub eye_amplitude: f32,
+    /// The largest offset of the target from its position, in world units.
+    pub target_amplitude: f32,
+    /// How many times per second, roughly, the drift changes direction. Values well below 1 feel handheld.
+    pub frequency: f32,
+    pub seed: u32,
+    phase: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    applied: Option<(Transform, Transform)>,
+}
+
+impl Default for CameraDrift {
+    fn default() -> Self {
+        Self::new(0.02, 0.05, 0.2)
+    }
+}
+
+impl CameraDrift {
+    pub fn new(eye_amplitude: f32, target_amplitude: f32, frequency: f32) -> Self {
+        Self {
+            enabled: true,
+            eye_amplitude,
+            target_amplitude,
+            frequency,
+            seed: 0,
+            phase: 0.0,
+            applied: None,
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// The offsets of the eye and target at the current phase, each within its amplitude.
+    pub fn offsets(&self) -> (Vec3, Vec3) {
+        let noise =
+            |axis: u32| gradient_noise(self.phase, self.seed.wrapping_mul(6).wrapping_add(axis));
+        (
+            self.eye_amplitude * Vec3::new(noise(0), noise(1), noise(2)),
+            self.target_amplitude * Vec3::new(noise(3), noise(4), noise(5)),
+        )
+    }
+}
+
+/// 1D Perlin noise in `[-1, 1]`, with random gradients at the integers.
+pub(crate) fn gradient_noise(x: f32, seed: u32) -> f32 {
+    let gradient = |i: i32| {
+        let mut h = (i as u32) ^ seed.wrapping_mul(0x9e37_79b9);
+        h = (h ^ (h >> 16)).wrapping_mul(0x7feb_352d);
+        h = (h ^ (h >> 15)).wrapping_mul(0x846c_a68b);
+        h ^= h >> 16;
+        (h as f32 / u32::MAX as f32) * 2.0 - 1.0
+    };
+    let i = x.floor();
+    let t = x - i;
+    let fade = t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
+    let i = i as i32;
+    let a = gradient(i) * t;
+    let b = gradient(i.wrapping_add(1)) * (t - 1.0);
+    // The noise never quite reaches half a gradient away from zero.
+    (2.0 * (a + (b - a) * fade)).clamp(-1.0, 1.0)
+}
+
+/// Adds each [`CameraDrift`] to the smoothed `Transform` of its camera.
+pub fn camera_drift_system(
+    time: CameraTime,
+    mut cameras: Query<
+        (Entity, &LookTransform, &mut CameraDrift, &mut Transform),
+        Without<XrDriven>,
+    >,
+    smoothers: Query<&Smoother>,
+) {
+    for (entity, look_transform, mut drift, mut transform) in cameras.iter_mut() {
+        let dt = time.delta_secs(entity);
+        let smoother = smoothers.get(entity).ok();
+        // A sleeping smoother leaves last frame's drift in the `Transform`, so take it back out.
+        let base = match drift.applied {
+            Some((base, drifted)) if *transform == drifted => base,
+            _ => *transform,
+        };
+        drift.applied = None;
+
+        if !drift.enabled {
+            transform.set_if_neq(base);
+            continue;
+        }
+        drift.phase += dt * drift.frequency;
+
+        let radius = smoother
+            .filter(|s| s.is_enabled())
+            .and_then(Smoother::smoothed)
+            .unwrap_or(*look_transform)
+            .radius();
+        let (eye_offset, target_offset) = drift.offsets();
+        let eye = base.translation + eye_offset;
+        let target = base.translation + radius * base.forward() + target_offset;
+        let drifted = if (target - eye).length_squared() > 0.0 {
+            Transform::from_translation(eye).looking_at(target, base.up())
+        } else {
+            base
+        };
+
+        *transform = drifted;
+        drift.applied = Some((base, drifted));
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gradient_noise_is_continuous_and_bounded() {
+        let mut last = gradient_noise(0.0, 7);
+        assert_eq!(last, 0.0);
+        for i in 1..1000 {
+            let value = gradient_noise(i as f32 * 0.01, 7);
+            assert!((-1.0..=1.0).contains(&value), "{value}");
+            assert!((value - last).abs() < 0.05, "{last} -> {value}");
+            last = value;
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use bevy::{prelude::ReflectDefault, reflect::Reflect};
+
+use std::fmt;
+
+/// An easing curve for camera animations, like blends, view snaps, bookmark recalls, and scripted moves, so they can all be
+/// tuned the same way.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum Ease {
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    /// Eases in and out gently, so the camera doesn't lurch at either end.
+    #[default]
+    SmoothStep,
+    /// Any other curve, which should map `0.0` to `0.0` and `1.0` to `1.0`. Functions can't be serialized, so saving one is
+    /// an error.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(#[reflect(ignore)] EaseFn),
+}
+
+impl Ease {
+    pub fn custom(f: fn(f32) -> f32) -> Self {
+        Self::Custom(EaseFn(f))
+    }
+
+    /// The eased progress at `t` from `0.0` to `1.0` of the way through an animation.
+    pub fn sample(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::QuadIn => t * t,
+            Self::QuadOut => 1.0 - (1.0 - t).powi(2),
+            Self::QuadInOut if t < 0.5 => 2.0 * t * t,
+            Self::QuadInOut => 1.0 - 0.5 * (2.0 - 2.0 * t).powi(2),
+            Self::CubicIn => t * t * t,
+            Self::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Self::CubicInOut if t < 0.5 => 4.0 * t * t * t,
+            Self::CubicInOut => 1.0 - 0.5 * (2.0 - 2.0 * t).powi(3),
+            // The exponential curves don't quite reach their ends on their own.
+            Self::ExpoIn | Self::ExpoOut | Self::ExpoInOut if t == 0.0 || t == 1.0 => t,
+            Self::ExpoIn => 2f32.powf(10.0 * t - 10.0),
+            Self::ExpoOut => 1.0 - 2f32.powf(-10.0 * t),
+            Self::ExpoInOut if t < 0.5 => 0.5 * 2f32.powf(20.0 * t - 10.0),
+            Self::ExpoInOut => 1.0 - 0.5 * 2f32.powf(10.0 - 20.0 * t),
+            Self::SmoothStep => t * t * (3.0 - 2.0 * t),
+            Self::Custom(f) => (f.0)(t),
+        }
+    }
+}
+
+/// The function of an [`Ease::Custom`] curve.
+#[derive(Clone, Copy)]
+pub struct EaseFn(pub fn(f32) -> f32);
+
+impl Default for EaseFn {
+    fn default() -> Self {
+        Self(|t| t)
+    }
+}
+
+impl fmt::Debug for EaseFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("EaseFn")
+    }
+}
+
+impl PartialEq for EaseFn {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 as usize == other.0 as usize
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eases_are_monotonic_from_zero_to_one() {
+        let eases = [
+            Ease::Linear,
+            Ease::QuadIn,
+            Ease::QuadOut,
+            Ease::QuadInOut,
+            Ease::CubicIn,
+            Ease::CubicOut,
+            Ease::CubicInOut,
+            Ease::ExpoIn,
+            Ease::ExpoOut,
+            Ease::ExpoInOut,
+            Ease::SmoothStep,
+            Ease::custom(|t| t.sqrt()),
+        ];
+        for ease in eases {
+            assert_eq!(ease.sample(0.0), 0.0, "{ease:?}");
+            assert_eq!(ease.sample(1.0), 1.0, "{ease:?}");
+            let mut last = 0.0;
+            for i in 1..=100 {
+                let eased = ease.sample(i as f32 / 100.0);
+                assert!(eased >= last, "{ease:?}");
+                last = eased;
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{LookTransform, Smoother};
+
+use bevy::{ecs::prelude::*, prelude::ReflectDefault, reflect::Reflect};
+
+#[cfg(feature = "depth_of_field")]
+use bevy::core_pipeline::dof::DepthOfField;
+
+/// The distance from the eye to the target of the camera's smoothed view, updated every frame, e.g. to keep an orbit
+/// camera's depth of field focused on what it orbits.
+///
+/// With the `depth_of_field` feature, the distance is also written into the camera's `DepthOfField::focal_distance`.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct FocusDistance(pub f32);
+
+/// Updates each [`FocusDistance`] from the smoothed `LookTransform`.
+pub fn focus_distance_system(
+    mut cameras: Query<(&LookTransform, Option<&Smoother>, &mut FocusDistance)>,
+) {
+    for (look_transform, smoother, mut focus_distance) in cameras.iter_mut() {
+        let radius = smoother
+            .filter(|s| s.is_enabled())
+            .and_then(Smoother::smoothed)
+            .unwrap_or(*look_transform)
+            .radius();
+        focus_distance.set_if_neq(FocusDistance(radius));
+    }
+}
+
+/// Focuses the `DepthOfField` of each camera at its [`FocusDistance`].
+#[cfg(feature = "depth_of_field")]
+pub fn depth_of_field_focus_system(
+    mut cameras: Query<(&FocusDistance, &mut DepthOfField), Changed<FocusDistance>>,
+) {
+    for (focus_distance, mut depth_of_field) in cameras.iter_mut() {
+        depth_of_field.focal_distance = focus_distance.0;
+    }
+}
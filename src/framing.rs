@@ -0,0 +1,308 @@
+use crate::{controllers::horizontal_fov, LookTransform};
+
+use bevy::{
+    ecs::prelude::*,
+    math::prelude::*,
+    reflect::Reflect,
+    render::{camera::Projection, primitives::Aabb},
+    transform::components::GlobalTransform,
+};
+
+/// A sphere that contains all of the `spheres`, given as `(center, radius)`. It's centered on their average center, so it
+/// isn't always the smallest one. Returns `None` if there are no spheres.
+pub fn bounding_sphere(spheres: impl IntoIterator<Item = (Vec3, f32)>) -> Option<(Vec3, f32)> {
+    let spheres: Vec<_> = spheres.into_iter().collect();
+    if spheres.is_empty() {
+        return None;
+    }
+
+    let center = spheres.iter().map(|(c, _)| *c).sum::<Vec3>() / spheres.len() as f32;
+    let radius = spheres
+        .iter()
+        .map(|(c, r)| c.distance(center) + r)
+        .fold(0.0, f32::max);
+    Some((center, radius))
+}
+
+/// The world space bounding sphere of an entity, from its `Aabb` if it has one, or else just its position.
+pub fn entity_bounding_sphere(transform: &GlobalTransform, aabb: Option<&Aabb>) -> (Vec3, f32) {
+    match aabb {
+        Some(aabb) => {
+            let scale = transform.compute_transform().scale.abs().max_element();
+            (
+                transform.transform_point(aabb.center.into()),
+                scale * Vec3::from(aabb.half_extents).length(),
+            )
+        }
+        None => (transform.translation(), 0.0),
+    }
+}
+
+/// How far from the center of a sphere with `radius` the eye must be to fit the sphere in view.
+///
+/// For orthographic projections (or none), the distance doesn't change the size on screen, so this is just far enough to keep
+/// the sphere in front of the eye.
+pub fn framing_distance(radius: f32, projection: Option<&Projection>) -> f32 {
+    match projection {
+        Some(Projection::Perspective(perspective)) => {
+            let half_fov = 0.5 * perspective.fov.min(horizontal_fov(perspective));
+            radius / half_fov.sin()
+        }
+        _ => 2.0 * radius,
+    }
+}
+
+/// Where an eye at `eye` should look so that `subject` appears at `anchor` in the viewport, like the rule of thirds.
+///
+/// The `anchor` is in normalized device coordinates, from `-1.0` to `1.0` with `+y` up, so `(0.0, 0.0)` is the center.
+/// The view isn't rolled about `up`. The returned target is as far from the eye as the subject is. Without a projection,
+/// the subject itself is returned.
+pub fn anchored_target(
+    eye: Vec3,
+    subject: Vec3,
+    up: Vec3,
+    anchor: Vec2,
+    projection: Option<&Projection>,
+) -> Vec3 {
+    let to_subject = subject - eye;
+    let distance = to_subject.length();
+    if anchor == Vec2::ZERO || distance <= 0.0 {
+        return subject;
+    }
+
+    // The direction from the eye to the anchor in view space.
+    let anchor_direction = match projection {
+        Some(Projection::Perspective(perspective)) => {
+            let half_height = (0.5 * perspective.fov).tan();
+            Vec3::new(
+                anchor.x * half_height * perspective.aspect_ratio,
+                anchor.y * half_height,
+                -1.0,
+            )
+        }
+        Some(Projection::Orthographic(orthographic)) => {
+            let offset = 0.5 * anchor * orthographic.area.size();
+            let depth = (distance * distance - offset.length_squared())
+                .max(0.0)
+                .sqrt();
+            offset.extend(-depth)
+        }
+        None => return subject,
+    };
+    let Some(v) = anchor_direction.try_normalize() else {
+        return subject;
+    };
+
+    // Solve for the pitch, then the yaw, in a frame where `up` is +Y.
+    let to_y_up = Quat::from_rotation_arc(up.try_normalize().unwrap_or(Vec3::Y), Vec3::Y);
+    let w = to_y_up * (to_subject / distance);
+    let reach = v.y.hypot(v.z);
+    let pitch = (w.y / reach).clamp(-1.0, 1.0).asin() - v.y.atan2(-v.z);
+    let pitched = Quat::from_rotation_x(pitch) * v;
+    let yaw = w.x.atan2(w.z) - pitched.x.atan2(pitched.z);
+
+    let rotation = Quat::from_rotation_y(yaw) * Quat::from_rotation_x(pitch);
+    eye + distance * (to_y_up.inverse() * rotation * Vec3::NEG_Z)
+}
+
+/// Where `point` appears in the viewport of a camera at `eye` with `rotation`, in normalized device coordinates from `-1.0`
+/// to `1.0` with `+y` up. Returns `None` without a projection, or if the point isn't in front of a perspective camera.
+pub fn viewport_ndc(
+    eye: Vec3,
+    rotation: Quat,
+    point: Vec3,
+    projection: Option<&Projection>,
+) -> Option<Vec2> {
+    let view = rotation.inverse() * (point - eye);
+    match projection? {
+        Projection::Perspective(perspective) => {
+            if view.z >= 0.0 {
+                return None;
+            }
+            let half_height = (0.5 * perspective.fov).tan();
+            let half_size = Vec2::new(half_height * perspective.aspect_ratio, half_height);
+            Some(view.truncate() / (-view.z * half_size))
+        }
+        Projection::Orthographic(orthographic) => {
+            Some(view.truncate() / (0.5 * orthographic.area.size()))
+        }
+    }
+}
+
+/// Keeps a group of entities in view, like the camera of a fighting game or couch co-op game.
+///
+/// The target follows the center of a sphere that bounds the entities, and the eye stays far enough back along the view
+/// direction to fit the sphere in the camera's `Projection`. The view direction itself is left alone, so an orbit controller
+/// can still turn around the group. Entities that don't exist or have no `GlobalTransform` are skipped.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct FrameTargets {
+    pub entities: Vec<Entity>,
+    /// How much room to leave around the entities, as a factor of the bounding sphere's radius.
+    pub padding: f32,
+    /// The smallest bounding sphere radius to frame, so the camera doesn't get too close to a single point.
+    pub min_radius: f32,
+    /// How slowly the framed sphere follows the entities, between 0.0 and 1.0, like a [`Smoother`](crate::Smoother) lag
+    /// weight. This keeps the view steady when the group suddenly grows or shrinks.
+    pub smoothing_weight: f32,
+    sphere: Option<(Vec3, f32)>,
+}
+
+impl FrameTargets {
+    pub fn new(entities: impl IntoIterator<Item = Entity>) -> Self {
+        Self {
+            entities: entities.into_iter().collect(),
+            padding: 1.1,
+            min_radius: 0.5,
+            smoothing_weight: 0.8,
+            sphere: None,
+        }
+    }
+
+    pub fn with_padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn with_min_radius(mut self, min_radius: f32) -> Self {
+        self.min_radius = min_radius;
+        self
+    }
+
+    pub fn with_smoothing_weight(mut self, smoothing_weight: f32) -> Self {
+        self.smoothing_weight = smoothing_weight;
+        self
+    }
+
+    /// The center and radius of the sphere being framed, once the entities have been found.
+    pub fn sphere(&self) -> Option<(Vec3, f32)> {
+        self.sphere
+    }
+
+    /// Moves the framed sphere one frame toward the bounding sphere at `center` with `radius`.
+    fn follow(&mut self, center: Vec3, radius: f32) -> (Vec3, f32) {
+        let weight = self.smoothing_weight.clamp(0.0, 1.0);
+        let sphere = match self.sphere {
+            Some((last_center, last_radius)) => {
+                let mut next_center = last_center.lerp(center, 1.0 - weight);
+                let mut next_radius = weight * last_radius + (1.0 - weight) * radius;
+                // Settle exactly, so the camera can go to sleep.
+                if next_center.distance_squared(center) < 1e-8 {
+                    next_center = center;
+                }
+                if (next_radius - radius).abs() < 1e-4 {
+                    next_radius = radius;
+                }
+                (next_center, next_radius)
+            }
+            None => (center, radius),
+        };
+        self.sphere = Some(sphere);
+        sphere
+    }
+}
+
+/// Points each [`FrameTargets`] camera at its group of entities, from far enough away to see all of them.
+pub fn frame_targets_system(
+    mut cameras: Query<(&mut LookTransform, &mut FrameTargets, Option<&Projection>)>,
+    anchors: Query<(&GlobalTransform, Option<&Aabb>)>,
+) {
+    for (mut look_transform, mut frame_targets, projection) in cameras.iter_mut() {
+        let spheres = frame_targets
+            .entities
+            .iter()
+            .filter_map(|&entity| anchors.get(entity).ok())
+            .map(|(transform, aabb)| entity_bounding_sphere(transform, aabb));
+        let Some((center, radius)) = bounding_sphere(spheres) else {
+            continue;
+        };
+
+        // This is internal state, so only the resulting view change should wake the camera.
+        let frame_targets = frame_targets.bypass_change_detection();
+        let (center, radius) = frame_targets.follow(center, radius);
+        let distance = framing_distance(
+            frame_targets.padding * radius.max(frame_targets.min_radius),
+            projection,
+        );
+        let direction = look_transform.look_direction().unwrap_or(Vec3::NEG_Z);
+        // Only write on change, so idle cameras can stay asleep.
+        let eye = center - distance * direction;
+        if look_transform.target != center || look_transform.eye != eye {
+            look_transform.target = center;
+            look_transform.eye = eye;
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use bevy::{render::camera::PerspectiveProjection, transform::components::Transform};
+
+    #[test]
+    fn test_bounding_sphere_contains_all() {
+        let spheres = [
+            (Vec3::new(-2.0, 0.0, 0.0), 1.0),
+            (Vec3::new(2.0, 0.0, 0.0), 0.5),
+        ];
+        let (center, radius) = bounding_sphere(spheres).unwrap();
+        for (c, r) in spheres {
+            assert!(c.distance(center) + r <= radius + 1e-6);
+        }
+        assert_eq!(bounding_sphere([]), None);
+    }
+
+    #[test]
+    fn test_anchored_target_frames_subject_at_anchor() {
+        let projection = Projection::Perspective(PerspectiveProjection {
+            aspect_ratio: 16.0 / 9.0,
+            ..Default::default()
+        });
+        let eye = Vec3::new(1.0, 3.0, 8.0);
+        let subject = Vec3::new(-2.0, 0.5, -1.0);
+        let anchor = Vec2::new(-1.0 / 3.0, 0.2);
+        let target = anchored_target(eye, subject, Vec3::Y, anchor, Some(&projection));
+        assert_relative_eq!(target.distance(eye), subject.distance(eye), epsilon = 1e-4);
+
+        // Project the subject into the camera's view.
+        let transform = Transform::from_translation(eye).looking_at(target, Vec3::Y);
+        let ndc = viewport_ndc(eye, transform.rotation, subject, Some(&projection)).unwrap();
+        assert!(ndc.abs_diff_eq(anchor, 1e-4), "{ndc}");
+    }
+
+    #[test]
+    fn test_frame_targets_follow_settles_on_the_group() {
+        let mut frame_targets = FrameTargets::new([]);
+        assert_eq!(frame_targets.follow(Vec3::ZERO, 1.0), (Vec3::ZERO, 1.0));
+
+        // A sudden change is eased into, and then reached exactly.
+        let goal = (Vec3::new(4.0, 0.0, 0.0), 3.0);
+        let (center, radius) = frame_targets.follow(goal.0, goal.1);
+        assert!(center.x > 0.0 && center.x < goal.0.x);
+        assert!(radius > 1.0 && radius < goal.1);
+        for _ in 0..200 {
+            frame_targets.follow(goal.0, goal.1);
+        }
+        assert_eq!(frame_targets.sphere(), Some(goal));
+    }
+
+    #[test]
+    fn test_framing_distance_fits_narrowest_fov() {
+        let projection = Projection::Perspective(PerspectiveProjection {
+            fov: std::f32::consts::FRAC_PI_2,
+            aspect_ratio: 2.0,
+            ..Default::default()
+        });
+        let distance = framing_distance(1.0, Some(&projection));
+        assert_relative_eq!(distance, 2.0f32.sqrt(), epsilon = 1e-5);
+    }
+}
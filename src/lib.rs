@@ -8,14 +8,23 @@
 //!
 //! All controllers are based on a [`LookTransform`] component, which is just an
 //! `eye` point that looks at a `target` point. By modifying this component, the
-//! scene graph `Transform` will automatically be synchronized.
+//! scene graph `Transform` will automatically be synchronized. An `up` vector
+//! that is zero or parallel to the view can't roll the camera, so a sane one is
+//! derived instead; use `LookTransform::set_up_safe` to check an `up` before
+//! setting it.
 //!
 //! Any entities with all of `Transform`, LookTransform, and [`Smoother`]
 //! components will automatically have their `Transform` smoothed. Smoothing
 //! will have no effect on the `LookTransform`, only the final `Transform` in
-//! the scene graph.
+//! the scene graph. For a springier feel that can overshoot a little, use
+//! [`Smoother::with_kind`] with a [`SmoothingKind::Spring`].
+//! To jump to a new view without smoothing across the scene, e.g. on respawn, use
+//! [`LookTransform::teleport`]. Whether the `Smoother` snaps or glides there, also when
+//! its controller is re-enabled, is up to its `TeleportBehavior`.
+//! To wait for the camera to finish moving, e.g. before starting dialogue, check
+//! [`LookTransform::is_converged`] or read the [`SmootherSettled`] event.
 //!
-//! ```rust
+//! ```no_run
 //! use bevy::prelude::*;
 //! use smooth_bevy_cameras::{LookTransform, LookTransformBundle, LookTransformPlugin, Smoother};
 //!
@@ -37,7 +46,7 @@
 //!             transform: LookTransform::new(eye, target, Vec3::Y),
 //!             smoother: Smoother::new(0.9), // Value between 0.0 and 1.0, higher is smoother.
 //!         })
-//!         .insert(Camera3dBundle::default());
+//!         .insert(Camera3d::default());
 //!
 //! }
 //!
@@ -47,6 +56,91 @@
 //! }
 //! ```
 //!
+//! To make a camera follow other entities without writing a system, add [`LookAt`]
+//! and/or [`EyeAt`] components next to the `LookTransform`. They copy the
+//! referenced entity's `GlobalTransform` translation (plus an offset) into
+//! `target` or `eye` before smoothing. Add [`LookAhead`] too, and the target
+//! will lead a moving subject in the direction it's going.
+//! Use `LookAt::with_anchor` to frame the entity off center, e.g. on a rule of
+//! thirds line, for the camera's current projection.
+//! Add [`FramingZones`] to let the entity wander inside a dead zone before the
+//! camera turns to follow it.
+//!
+//! For a spectator or "killcam" view, add a
+//! [`Spectate`](crate::spectator::Spectate) instead. The eye moves along with the
+//! entity so an orbit controller can still orbit around it, a `CameraOcclusion`
+//! keeps scenery from blocking the view, and `auto_rotate` slowly circles the
+//! entity while nobody is controlling the camera.
+//!
+//! To keep a group of entities in view, like a fighting game or couch co-op
+//! camera, add a [`FrameTargets`](crate::framing::FrameTargets) listing them.
+//! The camera looks at the center of their bounding sphere, from far enough away
+//! to fit it in the camera's projection.
+//!
+//! For over-the-shoulder or boom cameras, put the `LookTransform` on a parent
+//! "rig" entity and the `Camera` on a child with a `RigOffset`. The rig is
+//! smoothed as usual, and the child is offset from its smoothed view. A
+//! `ShoulderOffset` keeps the child over one shoulder, frames the target at a
+//! viewport anchor, and smoothly changes sides with `swap_shoulder()`.
+//!
+//! To keep scenery from blocking a third-person view, add the
+//! [`CameraOcclusionPlugin`](crate::occlusion::CameraOcclusionPlugin) and a
+//! `CameraOcclusion` to the camera, and fill its `OcclusionHits` from your own
+//! raycast along `CameraOcclusion::probe`. Depending on the `OcclusionPolicy`,
+//! the camera is pulled in front of the blockers, or an `OccludedEntities` event
+//! lists them so the game can fade them out.
+//!
+//! For VR/XR, add [`XrDriven`](crate::xr::XrDriven) to the headset camera. Its
+//! `LookTransform` then follows the headset pose, so bookmarks, recording, and
+//! anything else that reads it keep working, while controllers, smoothing, and
+//! other camera motion that would cause motion sickness are turned off.
+//!
+//! For a top-down minimap or picture-in-picture, add a
+//! [`MirrorLookTransform`](crate::mirror::MirrorLookTransform) to a second camera. It
+//! follows the smoothed view of a source camera, e.g. looking straight down at
+//! its target from a fixed height.
+//!
+//! To keep a depth of field effect focused on the target, e.g. while orbiting, add
+//! a [`FocusDistance`](crate::focus::FocusDistance). It's updated with the smoothed
+//! distance from the eye to the target every frame, and with the `depth_of_field`
+//! feature, it's written into the camera's `DepthOfField` too.
+//!
+//! For the subtle, handheld feel of a cutscene or menu camera, add a
+//! [`CameraDrift`](crate::drift::CameraDrift). It slowly wanders the eye and
+//! target with low-frequency noise after smoothing, on top of any controller.
+//! For hits and explosions, add a [`CameraShake`](crate::shake::CameraShake) and
+//! call `add_trauma`. The shake grows with the square of the trauma, which wears
+//! off over time.
+//!
+//! A camera parented to a moving vehicle or platform has its `LookTransform` in the
+//! parent's space, so it moves rigidly with the parent. To follow and smooth it in
+//! world space instead, add a
+//! [`WorldSpaceLookTransform`](crate::world_space::WorldSpaceLookTransform).
+//!
+//! The synchronization runs in `PostUpdate`, before transform propagation, so any
+//! `LookTransform` changes made during `Update` show up in the same frame. Use
+//! [`LookTransformSet`] to order your own systems around it, or
+//! [`LookTransformPlugin::in_schedule`] to run it in another schedule.
+//!
+//! If an animation or your own code also writes the camera's `Transform`, add
+//! [`SyncFromTransform`](crate::transform_sync::SyncFromTransform) so the `LookTransform` picks up
+//! those edits instead of snapping back on the next move.
+//!
+//! For scenes with hundreds of smoothed cameras, like security-camera arrays or
+//! replays, enable the `parallel` feature to smooth them on multiple threads. See
+//! the `stress_look_transforms` example and the `look_transform` benchmark.
+//!
+//! To track down NaNs in camera math, enable the `validation` feature and add the
+//! [`CameraValidationPlugin`](crate::validation::CameraValidationPlugin). It logs
+//! the camera and its recent control events when a non-finite value shows up, and
+//! restores the last valid state instead of passing it on to rendering.
+//!
+//! If a camera only moves in `FixedUpdate`, e.g. following a physics body, add
+//! [`FixedInterpolation`] to smooth it between fixed steps. The FPS, orbit, and
+//! Unreal plugins can run their control systems there with
+//! `with_control_schedule(FixedUpdate)`, and their input maps anywhere else with
+//! `with_input_schedule`.
+//!
 //! # Look Angles
 //!
 //! When implementing a camera controller, it's often useful to work directly
@@ -81,12 +175,46 @@
 //!   [`FpsCameraBundle`](crate::controllers::fps::FpsCameraBundle)
 //!   - WASD: Translate on the XZ plane
 //!   - Shift/Space: Translate along the Y axis
+//!   - With `MovementMode::FreeFly`, these follow the pitched view instead
+//!   - Mouse wheel: Move forward/backward, or change speed with `wheel_mode`
+//!   - Right mouse: Aim down sights, with an `AimZoom`
+//!   - Add a `HeadBob` or a `StrafeTilt` to bob or lean with movement
+//!   - Add a `Dash` to double tap a movement key for a burst of speed
+//!   - Add a `FlickStick` to turn with flick stick aiming on a gamepad's right
+//!     stick
+//!   - With the `gyro` feature, add a `GyroAim` to aim with a gamepad's gyro.
+//!     Other motion sources can turn the camera through its `AimMotion`
 //!   - Mouse: Rotate camera
+//!   - Click/Escape: Capture/release the cursor, if `mouse_look_requires_capture`
+//!   - Gameplay code can turn the camera by writing `yaw` and `pitch`, or with
+//!     `look_to`
+//! - [`MayaCameraPlugin`](crate::controllers::maya::MayaCameraPlugin) +
+//!   [`MayaCameraBundle`](crate::controllers::maya::MayaCameraBundle)
+//!   - Alt + Left mouse drag: Tumble camera around the pivot
+//!   - Alt + Middle mouse drag: Pan camera
+//!   - Alt + Right mouse drag or mouse wheel: Dolly camera
+//!   - F: Frame the `Selected` entities
 //! - [`OrbitCameraPlugin`](crate::controllers::orbit::OrbitCameraPlugin) +
 //!   [`OrbitCameraBundle`](crate::controllers::orbit::OrbitCameraBundle)
 //!   - CTRL + mouse drag: Rotate camera
 //!   - Right mouse drag: Pan camera
 //!   - Mouse wheel: Zoom
+//!   - Arrows or numpad 4/6/8/2: Rotate camera in steps
+//!   - Shift + arrows: Pan camera in steps
+//!   - +/-: Zoom in steps
+//!   - Numpad 1/3/7 (Ctrl for the opposite side) and 5: Front/right/top and
+//!     isometric views
+//!   - The rotate and pan drags can be changed with `triggers`, e.g. to
+//!     `OrbitTriggers::middle_drag()` for Blender-style controls, or
+//!     `OrbitTriggers::left_drag()` to orbit without holding a key
+//!   - Set `snap_rotation` to a modifier key to rotate in 15° steps while it's
+//!     held, like Blender's Ctrl + orbit
+//!   - Set `logarithmic_zoom` so each wheel line zooms by the same percentage
+//!     in and out, which suits scenes of very different scales
+//!   - Set `auto_rotate` to spin around the target like a turntable while the
+//!     user is idle
+//!   - Set `inertia` to an `OrbitInertia` so pans and zooms coast to a stop
+//!     after letting go, like a maps app
 //! - [`UnrealCameraPlugin`](crate::controllers::unreal::UnrealCameraPlugin) +
 //!   [`UnrealCameraBundle`](crate::controllers::unreal::UnrealCameraBundle)
 //!
@@ -102,11 +230,202 @@
 //!     locomotion and panning speeds
 //!   - While holding no mouse button, use scroll wheel for locomotion
 //!     forward/backward
+//!   - Set `rotate_wheel_mode` to `RotateWheelMode::Dolly` to dolly with the
+//!     scroll wheel while holding Right mouse button, like recent Unreal versions
+//!   - With an orthographic projection, forward/backward locomotion zooms
+//!     instead, and Left mouse drag pans
+//!   - Set `pan_reference_depth` to pan by the depth of the point under the
+//!     cursor, from a `CursorDepth` component that the app updates
+//!   - Set `roll_controls` to roll the camera for dutch angles with Z/C, or with
+//!     a modifier and Right mouse drag
+//! - [`IsometricCameraPlugin`](crate::controllers::isometric::IsometricCameraPlugin) +
+//!   [`IsometricCameraBundle`](crate::controllers::isometric::IsometricCameraBundle)
+//!   - WASD or arrows, or Middle mouse drag: Pan camera along the ground
+//!   - Q/E: Rotate camera in steps
+//!   - Mouse wheel: Zoom
+//! - [`PanCam2dPlugin`](crate::controllers::pancam2d::PanCam2dPlugin) +
+//!   [`PanCam2dBundle`](crate::controllers::pancam2d::PanCam2dBundle), for `Camera2d`
+//!   - Left or Middle mouse drag: Pan camera
+//!   - Mouse wheel: Zoom about the cursor
+//!
+//! Sensitivities don't depend on the frame rate. Mouse sensitivities are in
+//! radians or world units per pixel, and keyboard and gamepad speeds are per
+//! second, scaled by the frame time in the input maps. Each controller's
+//! `ControlEvent`s carry the amounts to move by in one frame, so any number of
+//! events from any input source add up the same at 30 or 240 FPS.
+//!
+//! To switch which camera (or controller type) is active at runtime, send a
+//! [`CameraControlEvent`](crate::controllers::CameraControlEvent). To freeze
+//! every controller at once, e.g. during a pause menu, disable the
+//! [`CameraControllerToggle`](crate::controllers::CameraControllerToggle)
+//! resource. When a window loses focus, held keys and buttons are
+//! released so the camera doesn't keep moving, and the mouse jump when it gets
+//! focus back is ignored.
+//!
+//! To drive a specific camera from scripts or tools, no matter which camera
+//! has the input, send its controller's `ControlEvent` wrapped in a
+//! [`TargetedControlEvent`](crate::controllers::TargetedControlEvent).
+//!
+//! Each controller's math is also available as a plain `apply_events` function,
+//! e.g. `orbit::apply_events`, which applies a frame of `ControlEvent`s to a
+//! `LookTransform`. Use it to unit test custom input maps, or to drive the
+//! controller from scripts and replays without an `App`.
+//! For deterministic golden tests of your camera tuning, a
+//! [`CameraSimulation`](crate::simulate::CameraSimulation) steps a controller, its
+//! `LookTransform`, and its `Smoother` through a list of timed control events,
+//! without Bevy's scheduling, and returns every frame's transforms.
+//!
+//! To put more than one controller on the same camera, e.g. to toggle between
+//! orbiting and flying, add an
+//! [`ActiveController`](crate::controllers::ActiveController) to choose which
+//! one is in control.
+//!
+//! To make a camera snappy while the user drags and smooth once they let go,
+//! add [`InteractiveSmoothing`](crate::controllers::InteractiveSmoothing) with
+//! a lower lag weight.
+//!
+//! To tame a jittery high-DPI mouse, set the `smoothing` of the orbit, FPS, or
+//! Unreal controller's
+//! [`MouseDeltaFilter`](crate::controllers::MouseDeltaFilter) to a moving
+//! average window or a one-euro filter. Only the mouse motion is filtered, so
+//! keyboard movement and the rest of the camera don't lag.
+//!
+//! To write your own controller, implement
+//! [`CameraController`](crate::controllers::CameraController) for its component and
+//! add [`controller_plugin`](crate::controllers::controller_plugin), which registers
+//! its event and handles enabling and disabling, then add your own input map
+//! and control systems.
+//!
+//! For multi-viewport editors, add
+//! [`ViewportInput`](crate::controllers::ViewportInput) to each camera, and
+//! only the controller whose `Camera::viewport` contains the cursor will
+//! receive input. For multi-window editors, add
+//! [`WindowInput`](crate::controllers::WindowInput) to bind each camera to its
+//! window.
+//!
+//! To keep a drag from leaving a viewport, add the
+//! [`CursorConfinementPlugin`](crate::cursor::CursorConfinementPlugin) and a
+//! [`CursorConfinement`](crate::cursor::CursorConfinement) to the window. Drags
+//! are left alone while the cursor is grabbed for mouse-look.
+//!
+//! For split-screen games, add the
+//! [`SplitScreenPlugin`](crate::split_screen::SplitScreenPlugin) and spawn a
+//! camera per player with [`spawn_layout`](crate::split_screen::spawn_layout).
+//! Each camera gets its own viewport, which follows the window size, and a
+//! `PlayerInput` that gives it the keyboard and mouse or one gamepad. The
+//! built-in input maps only read the keyboard and mouse, so gamepad players need
+//! an input map of your own that sends them `TargetedControlEvent`s.
+//!
+//! For users who can't use a mouse, add the
+//! [`KeyboardNavigationPlugin`](crate::controllers::keyboard::KeyboardNavigationPlugin).
+//! Every built-in controller can then be driven with the arrows to rotate,
+//! Shift + arrows to pan, and +/- to zoom, with `[`/`]` changing the global
+//! `NavigationSpeedScale`.
+//!
+//! For RTS-style screen-edge panning with the orbit and isometric controllers,
+//! add [`EdgePan`](crate::controllers::edge_pan::EdgePan) to the camera.
+//!
+//! To capture a camera session and replay it later, e.g. for benchmarks or
+//! video capture, add a
+//! [`ControlEventRecordingPlugin`](crate::recording::ControlEventRecordingPlugin)
+//! for the controller's `ControlEvent`.
+//!
+//! To keep a camera within limits no matter which controller (or system) moves
+//! it, add a [`Constraints`](crate::constraints::Constraints) component with
+//! e.g. [`PitchLimits`](crate::constraints::PitchLimits) or
+//! [`AboveGround`](crate::constraints::AboveGround). They are applied before
+//! smoothing.
+//!
+//! To keep a free flying camera above uneven terrain, add a
+//! [`HeightClamp`](crate::constraints::HeightClamp) with a height field or a
+//! `|x, z| height` closure.
+//!
+//! For guided tours and broadcast-style cameras, add an
+//! [`EyeOnPath`](crate::constraints::EyeOnPath) to the `Constraints` to keep the
+//! eye on a `HorizontalCircle`, a `Polyline` along a spline, or any `EyePath`,
+//! while the target stays free.
+//!
+//! To toggle a camera between perspective and orthographic without the view
+//! popping, add a [`ProjectionSwitch`](crate::projection::ProjectionSwitch)
+//! and send
+//! [`ProjectionSwitchEvent`](crate::projection::ProjectionSwitchEvent)s. The
+//! orthographic scale follows the distance to the target, so orbit zoom keeps
+//! working.
+//!
+//! For spectator modes or shared editor views, the
+//! [`LookTransformSyncPlugin`](crate::network::LookTransformSyncPlugin) streams
+//! a camera's `LookTransform` as compact packets for your networking crate to
+//! send, and plays them back smoothly on a remote camera.
+//!
+//! Editors can save and recall viewpoints with the
+//! [`CameraBookmarksPlugin`](crate::bookmarks::CameraBookmarksPlugin). Ctrl +
+//! Shift + a number key saves the view, and Ctrl + the number eases back to it.
+//!
+//! With the `view_cube` feature, add a `ViewCube` to an orbit camera and the
+//! `ViewCubePlugin` to show a CAD-style orientation cube in the corner of the
+//! viewport. Clicking its faces, edges, and corners snaps the view.
+//!
+//! For a game's photo mode, add the
+//! [`PhotoModePlugin`](crate::photo_mode::PhotoModePlugin) and send
+//! [`PhotoModeEvent`](crate::photo_mode::PhotoModeEvent)s. The camera flies
+//! freely with roll and zoom controls, and everything is restored on exit.
+//!
+//! Cameras move on the `Time<Virtual>` clock, so pausing the game or slowing it
+//! down for bullet time freezes or slows them too. To keep a camera responsive
+//! in a pause menu or photo mode, add
+//! [`CameraClock::Real`](crate::clock::CameraClock) to it, and its controller,
+//! input maps, and smoothing use `Time<Real>` instead.
+//!
+//! For cutscenes, the [`CameraBlendPlugin`](crate::blend::CameraBlendPlugin)
+//! drives a render camera from virtual cameras, which are `LookTransform`s
+//! without a `Camera`. Use the [`CameraBlend`](crate::blend::CameraBlend)
+//! resource to cut or blend between them, or give them
+//! [`VirtualCamera`](crate::blend::VirtualCamera) priorities, and the highest
+//! one is blended to automatically.
+//!
+//! To script camera moves for cutscenes and gameplay triggers, queue them on the
+//! [`LookTransformCommands`] resource with `move_to`, `orbit_by`, and `wait`.
+//! They play one after another on a camera, which follows them exactly and then
+//! hands back to its controller.
+//!
+//! Animated features, like blends, orbit view snaps, bookmark recalls, shoulder
+//! swaps, and scripted moves, take an [`Ease`](crate::ease::Ease) curve, from
+//! `Linear` to `ExpoInOut` or a custom function.
 
+pub mod blend;
+pub mod bookmarks;
+pub mod clock;
+pub mod constraints;
 pub mod controllers;
+pub mod cursor;
+pub mod dolly_zoom;
+pub mod drift;
+pub mod ease;
+pub mod focus;
+pub mod framing;
+pub mod mirror;
+pub mod network;
+pub mod occlusion;
+pub mod photo_mode;
+pub mod projection;
+pub mod recording;
+pub mod rig;
+pub mod shake;
+pub mod simulate;
+pub mod spectator;
+pub mod split_screen;
+pub mod transform_sync;
+#[cfg(feature = "validation")]
+pub mod validation;
+#[cfg(feature = "view_cube")]
+pub mod view_cube;
+pub mod world_space;
+pub mod xr;
 
 mod look_angles;
 mod look_transform;
+mod look_transform_commands;
 
 pub use look_angles::*;
 pub use look_transform::*;
+pub use look_transform_commands::*;
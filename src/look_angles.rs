@@ -21,10 +21,21 @@ impl LookAngles {
         p
     }
 
+    /// The angles of the forward (-Z) direction of `rotation`, like a camera `Transform::rotation`. Any roll is dropped.
+    pub fn from_quat(rotation: Quat) -> Self {
+        Self::from_vector(rotation * Vec3::NEG_Z)
+    }
+
     pub fn unit_vector(self) -> Vec3 {
         unit_vector_from_yaw_and_pitch(self.yaw, self.pitch)
     }
 
+    /// The rotation of a camera `Transform` looking in this direction, rolled so that its local Y is as close as possible to
+    /// `up`.
+    pub fn to_quat(self, up: Vec3) -> Quat {
+        rotation_looking_to(self.unit_vector(), up)
+    }
+
     pub fn set_direction(&mut self, v: Vec3) {
         let (yaw, pitch) = yaw_and_pitch_from_vector(v);
         self.set_yaw(yaw);
@@ -99,6 +110,25 @@ fn yaw_and_pitch_from_vector(v: Vec3) -> (f32, f32) {
     (yaw, pitch)
 }
 
+/// Like `Transform::looking_to`, the rotation that points -Z at `direction` and Y toward `up`. See [`orthonormal_up`] for
+/// when `up` doesn't work.
+pub(crate) fn rotation_looking_to(direction: Vec3, up: Vec3) -> Quat {
+    let back = -direction.normalize();
+    let up = orthonormal_up(-back, up);
+    let right = up.cross(back);
+    Quat::from_mat3(&Mat3::from_cols(right, up, back))
+}
+
+/// The unit vector perpendicular to the unit `direction` that is closest to `up`. If `up` is zero, not finite, or parallel
+/// to `direction`, it's re-derived from +Y instead, or from -Z when looking straight up or down, so that a top-down view
+/// has north at the top of the screen.
+pub(crate) fn orthonormal_up(direction: Vec3, up: Vec3) -> Vec3 {
+    [up, Vec3::Y, Vec3::NEG_Z]
+        .into_iter()
+        .find_map(|up| up.reject_from_normalized(direction).try_normalize())
+        .unwrap_or_else(|| direction.any_orthonormal_vector())
+}
+
 fn unit_vector_from_yaw_and_pitch(yaw: f32, pitch: f32) -> Vec3 {
     let ray = Mat3::from_rotation_y(yaw) * Vec3::Z;
     let pitch_axis = ray.cross(Vec3::Y);
@@ -118,6 +148,7 @@ mod tests {
     use super::*;
 
     use approx::assert_relative_eq;
+    use bevy::transform::components::Transform;
 
     const PI: f32 = std::f32::consts::PI;
 
@@ -163,4 +194,21 @@ mod tests {
         assert_relative_eq!(yaw, -PI / 4.0, epsilon = 1e-6f32);
         assert_relative_eq!(pitch, -PI / 4.0);
     }
+
+    #[test]
+    fn test_quat_round_trip() {
+        let mut angles = LookAngles::default();
+        angles.set_yaw(0.3);
+        angles.set_pitch(-0.7);
+        let rotation = angles.to_quat(Vec3::Y);
+
+        let expected = Transform::IDENTITY
+            .looking_to(angles.unit_vector(), Vec3::Y)
+            .rotation;
+        assert!(rotation.abs_diff_eq(expected, 1e-5) || rotation.abs_diff_eq(-expected, 1e-5));
+
+        let round_trip = LookAngles::from_quat(rotation);
+        assert_relative_eq!(round_trip.get_yaw(), 0.3, epsilon = 1e-5);
+        assert_relative_eq!(round_trip.get_pitch(), -0.7, epsilon = 1e-5);
+    }
 }
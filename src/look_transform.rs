@@ -1,16 +1,145 @@
+use crate::{
+    clock::CameraTime,
+    constraints::{constraint_system, height_clamp_system},
+    drift::camera_drift_system,
+    focus::focus_distance_system,
+    framing::{anchored_target, frame_targets_system, viewport_ndc},
+    look_angles::{orthonormal_up, rotation_looking_to},
+    look_transform_commands::{look_transform_commands_system, LookTransformCommands},
+    mirror::mirror_look_transform_system,
+    rig::{rig_offset_system, shoulder_offset_system},
+    shake::{camera_shake_system, restore_camera_shake_system},
+    spectator::spectate_system,
+    transform_sync::{record_synced_transform_system, sync_from_transform_system},
+    world_space::{restore_world_space_system, world_space_to_parent_system},
+    xr::{xr_driven_system, XrDriven},
+};
+
 use bevy::{
-    app::prelude::*, ecs::prelude::*, math::prelude::*, prelude::ReflectDefault, reflect::Reflect,
-    transform::components::Transform,
+    app::prelude::*,
+    ecs::{
+        prelude::*,
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+    },
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    render::camera::{Camera, Projection},
+    time::{Fixed, Time},
+    transform::{
+        components::{GlobalTransform, Transform},
+        TransformSystem,
+    },
+    utils::Parallel,
 };
 
+/// Synchronizes each `LookTransform` into its entity's `Transform`, in `PostUpdate`. Use
+/// [`LookTransformPlugin::in_schedule`] to run the [`LookTransformSet`]s in another schedule.
 pub struct LookTransformPlugin;
 
+impl LookTransformPlugin {
+    pub fn in_schedule(schedule: impl ScheduleLabel) -> ScheduledLookTransformPlugin {
+        ScheduledLookTransformPlugin {
+            schedule: schedule.intern(),
+        }
+    }
+}
+
 impl Plugin for LookTransformPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, look_transform_system);
+        Self::in_schedule(PostUpdate).build(app);
+    }
+}
+
+/// The [`LookTransformPlugin`], in a schedule of your choice. See [`LookTransformPlugin::in_schedule`].
+pub struct ScheduledLookTransformPlugin {
+    /// The schedule that the [`LookTransformSet`]s run in.
+    pub schedule: InternedScheduleLabel,
+}
+
+impl Plugin for ScheduledLookTransformPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SmootherIdleSettings>()
+            .init_resource::<LookTransformCommands>()
+            .add_event::<SmootherSettled>()
+            .configure_sets(
+                self.schedule,
+                (
+                    LookTransformSet::Follow,
+                    LookTransformSet::Constrain,
+                    LookTransformSet::Sync,
+                    LookTransformSet::Finish,
+                )
+                    .chain()
+                    .before(TransformSystem::TransformPropagate),
+            )
+            .add_systems(
+                self.schedule,
+                (
+                    // Before following and constraints, which still apply to scripted moves.
+                    (sync_from_transform_system, look_transform_commands_system)
+                        .chain()
+                        .before(LookTransformSet::Follow),
+                    (
+                        spectate_system,
+                        look_at_entity_system,
+                        look_ahead_system,
+                        frame_targets_system,
+                    )
+                        .chain()
+                        .in_set(LookTransformSet::Follow),
+                    (constraint_system, height_clamp_system)
+                        .chain()
+                        .in_set(LookTransformSet::Constrain),
+                    (
+                        restore_world_space_system,
+                        restore_camera_shake_system,
+                        xr_driven_system,
+                        look_transform_system,
+                        focus_distance_system,
+                        mirror_look_transform_system,
+                        shoulder_offset_system,
+                        rig_offset_system,
+                        camera_drift_system,
+                        camera_shake_system,
+                    )
+                        .chain()
+                        .in_set(LookTransformSet::Sync),
+                    (world_space_to_parent_system, record_synced_transform_system)
+                        .chain()
+                        .in_set(LookTransformSet::Finish),
+                ),
+            )
+            .add_systems(FixedPostUpdate, record_fixed_interpolation_system);
+
+        #[cfg(feature = "depth_of_field")]
+        app.add_systems(
+            self.schedule,
+            crate::focus::depth_of_field_focus_system
+                .after(focus_distance_system)
+                .in_set(LookTransformSet::Sync),
+        );
     }
 }
 
+/// Labels for the systems added by the [`LookTransformPlugin`], so you can order your own camera logic around them.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LookTransformSet {
+    /// Copies followed entity positions into `LookTransform`s.
+    Follow,
+    /// Applies each camera's [`Constraints`](crate::constraints::Constraints) and
+    /// [`HeightClamp`](crate::constraints::HeightClamp) to its `LookTransform`.
+    Constrain,
+    /// Smooths each `LookTransform` and writes the result into the scene graph `Transform`, then places the
+    /// [`RigOffset`](crate::rig::RigOffset) cameras relative to their rigs, and adds any
+    /// [`CameraDrift`](crate::drift::CameraDrift) and [`CameraShake`](crate::shake::CameraShake).
+    Sync,
+    /// Converts [`WorldSpaceLookTransform`](crate::world_space::WorldSpaceLookTransform) cameras into their parent's space.
+    /// Effects on the synced `Transform`, like occlusion and head bob, run between [`Sync`](Self::Sync) and this set, in
+    /// world space.
+    Finish,
+}
+
 #[derive(Bundle, Clone)]
 pub struct LookTransformBundle {
     pub transform: LookTransform,
@@ -53,74 +182,616 @@ impl LookTransform {
         (self.target - self.eye).length()
     }
 
+    /// Whether the eye, target, and up vector are all free of NaNs and infinities.
+    pub fn is_finite(&self) -> bool {
+        self.eye.is_finite() && self.target.is_finite() && self.up.is_finite()
+    }
+
     pub fn look_direction(&self) -> Option<Vec3> {
         (self.target - self.eye).try_normalize()
     }
+
+    /// Sets `up` if it's usable, normalizing it, and returns whether it was. An `up` that is zero, not finite, or nearly
+    /// parallel to the look direction would roll the camera unpredictably, so it's rejected, and the current `up` is kept
+    /// (or re-derived, if it's degenerate too).
+    pub fn set_up_safe(&mut self, up: Vec3) -> bool {
+        let direction = self.look_direction().unwrap_or(Vec3::NEG_Z);
+        let usable = |up: Vec3| {
+            up.try_normalize()
+                .filter(|up| up.dot(direction).abs() < 1.0 - 1e-4)
+        };
+        if let Some(up) = usable(up) {
+            self.up = up;
+            return true;
+        }
+        if usable(self.up).is_none() {
+            self.up = orthonormal_up(direction, self.up);
+        }
+        false
+    }
+
+    /// The rotation of the scene graph `Transform` that this synchronizes to, including the roll from `up`. `None` if the eye
+    /// is at the target.
+    pub fn rotation(&self) -> Option<Quat> {
+        self.look_direction()
+            .map(|direction| rotation_looking_to(direction, self.up))
+    }
+
+    /// The world space ray through `viewport_position` (in logical pixels) of a `camera` at this transform. `None` if the
+    /// eye is at the target or the camera's projection isn't known yet.
+    ///
+    /// For picking what is on screen, call this on [`Smoother::smoothed`] rather than the `LookTransform` itself, which the
+    /// displayed camera may still be catching up to. Cameras with a parent entity aren't supported.
+    pub fn viewport_ray(&self, camera: &Camera, viewport_position: Vec2) -> Option<Ray3d> {
+        let global_transform = self.global_transform()?;
+        camera
+            .viewport_to_world(&global_transform, viewport_position)
+            .ok()
+    }
+
+    /// The inverse of [`LookTransform::viewport_ray`]: where `world_position` appears in the viewport of a `camera` at this
+    /// transform, in logical pixels. `None` if it's behind the camera, or as for `viewport_ray`.
+    pub fn world_to_viewport(&self, camera: &Camera, world_position: Vec3) -> Option<Vec2> {
+        let global_transform = self.global_transform()?;
+        camera
+            .world_to_viewport(&global_transform, world_position)
+            .ok()
+    }
+
+    /// Jumps to a new `eye` and `target`, and lets the camera's `smoother` snap or glide there according to its
+    /// [`TeleportBehavior`], e.g. for respawns and cuts, where smoothing from the old view would swoosh across the scene.
+    pub fn teleport(&mut self, eye: Vec3, target: Vec3, smoother: &mut Smoother) {
+        self.eye = eye;
+        self.target = target;
+        smoother.teleported();
+    }
+
+    /// Whether the camera's `smoother` has caught up with this transform, to within `epsilon` (e.g. the
+    /// [`SmootherIdleSettings`] one), so the camera has finished moving. It hasn't before the first smoothed frame, and a
+    /// disabled smoother never lags behind. See [`SmootherSettled`] to be told when this happens.
+    pub fn is_converged(&self, smoother: &Smoother, epsilon: f32) -> bool {
+        !smoother.is_enabled()
+            || smoother
+                .smoothed()
+                .is_some_and(|smoothed| smoothed.abs_diff_eq(self, epsilon))
+    }
+
+    /// Linearly interpolates the eye, target, and up vector toward `other`, where `t = 0.0` is `self`.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            eye: self.eye.lerp(other.eye, t),
+            target: self.target.lerp(other.target, t),
+            up: self.up.lerp(other.up, t).normalize_or(other.up),
+        }
+    }
+
+    fn global_transform(&self) -> Option<GlobalTransform> {
+        let rotation = self.rotation()?;
+        Some(GlobalTransform::from(Transform {
+            translation: self.eye,
+            rotation,
+            ..Default::default()
+        }))
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.eye.abs_diff_eq(other.eye, epsilon)
+            && self.target.abs_diff_eq(other.target, epsilon)
+            && self.up.abs_diff_eq(other.up, epsilon)
+    }
 }
 
-fn eye_look_at_target_transform(eye: Vec3, target: Vec3, up: Vec3) -> Transform {
-    // If eye and target are very close, we avoid imprecision issues by keeping the look vector a unit vector.
-    let look_vector = (target - eye).normalize();
-    let look_at = eye + look_vector;
+/// Binds `LookTransform::target` to the translation of another entity, plus an offset.
+///
+/// With an `anchor`, the target is turned away from the entity so that it appears at that point in the viewport instead of
+/// the center, e.g. on a rule of thirds line. This depends on the camera's `Projection`.
+#[derive(Component, Debug, PartialEq, Clone, Copy, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct LookAt {
+    pub entity: Entity,
+    pub offset: Vec3,
+    /// Where the entity appears in the viewport, in normalized device coordinates from `-1.0` to `1.0` with `+y` up.
+    pub anchor: Vec2,
+}
+
+impl LookAt {
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            offset: Vec3::ZERO,
+            anchor: Vec2::ZERO,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Frames the entity at `anchor` in the viewport, e.g. `Vec2::new(-1.0 / 3.0, 1.0 / 3.0)` for the upper left
+    /// thirds intersection.
+    pub fn with_anchor(mut self, anchor: Vec2) -> Self {
+        self.anchor = anchor;
+        self
+    }
 
-    Transform::from_translation(eye).looking_at(look_at, up)
+    /// The target that frames `subject` at the `anchor` from `look_transform`'s eye, or with [`FramingZones`], the target
+    /// that the camera turns to this frame.
+    fn framed_target(
+        &self,
+        look_transform: &LookTransform,
+        subject: Vec3,
+        projection: Option<&Projection>,
+        zones: Option<&FramingZones>,
+        dt: f32,
+    ) -> Vec3 {
+        let anchor = match zones.zip(look_transform.rotation()) {
+            Some((zones, rotation)) => {
+                let current = viewport_ndc(look_transform.eye, rotation, subject, projection);
+                match current.map(|current| zones.reframe(current, self.anchor, dt)) {
+                    // Inside the dead zone.
+                    Some(None) => return look_transform.target,
+                    Some(Some(anchor)) => anchor,
+                    // Behind the camera.
+                    None => self.anchor,
+                }
+            }
+            None => self.anchor,
+        };
+        anchored_target(
+            look_transform.eye,
+            subject,
+            look_transform.up,
+            anchor,
+            projection,
+        )
+    }
+}
+
+/// Binds `LookTransform::eye` to the translation of another entity, plus an offset.
+#[derive(Component, Debug, PartialEq, Clone, Copy, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct EyeAt {
+    pub entity: Entity,
+    pub offset: Vec3,
+}
+
+impl EyeAt {
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            offset: Vec3::ZERO,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+/// Add next to a [`LookAt`] to lead the target ahead of the followed entity's motion, so fast subjects stay framed with room
+/// in front of them.
+///
+/// The velocity comes from the followed entity's [`LookAheadVelocity`] if it has one, or else from the change in its position
+/// since the last frame.
+#[derive(Component, Debug, PartialEq, Clone, Copy, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct LookAhead {
+    /// How many seconds of motion to lead by.
+    pub time: f32,
+    /// The longest the lead can be, in world units.
+    pub max_distance: f32,
+    /// How slowly the lead follows changes in velocity, between 0.0 and 1.0, like a [`Smoother`] lag weight.
+    pub smoothing_weight: f32,
+    last_position: Option<Vec3>,
+    offset: Vec3,
+}
+
+impl LookAhead {
+    pub fn new(time: f32) -> Self {
+        Self {
+            time,
+            max_distance: f32::INFINITY,
+            smoothing_weight: 0.9,
+            last_position: None,
+            offset: Vec3::ZERO,
+        }
+    }
+
+    pub fn with_max_distance(mut self, max_distance: f32) -> Self {
+        self.max_distance = max_distance;
+        self
+    }
+
+    pub fn with_smoothing_weight(mut self, smoothing_weight: f32) -> Self {
+        self.smoothing_weight = smoothing_weight;
+        self
+    }
+
+    /// The current lead, which is added to the `LookTransform::target`.
+    pub fn offset(&self) -> Vec3 {
+        self.offset
+    }
+}
+
+/// The velocity of an entity followed with [`LookAhead`], e.g. copied from a physics engine. This is more stable than
+/// differencing positions when the entity moves in a fixed timestep.
+#[derive(Component, Debug, Default, PartialEq, Clone, Copy, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct LookAheadVelocity(pub Vec3);
+
+/// Add next to a [`LookAt`] to let the entity wander around the viewport before the camera turns to follow it, like the
+/// framing zones of cinematic camera tools.
+///
+/// While the entity is inside the dead zone around the `LookAt::anchor`, the target doesn't move. Outside of it, the camera
+/// turns at `reframe_speed` to bring the entity back to the dead zone's edge, but never lets it leave the soft zone. Both zones
+/// are given as half extents in normalized device coordinates, so `Vec2::splat(1.0)` is the whole viewport.
+#[derive(Component, Debug, PartialEq, Clone, Copy, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct FramingZones {
+    pub dead_zone: Vec2,
+    pub soft_zone: Vec2,
+    /// How quickly the camera turns to re-frame the entity, per second.
+    pub reframe_speed: f32,
+}
+
+impl Default for FramingZones {
+    fn default() -> Self {
+        Self::new(Vec2::splat(0.1), Vec2::splat(0.5))
+    }
+}
+
+impl FramingZones {
+    pub fn new(dead_zone: Vec2, soft_zone: Vec2) -> Self {
+        Self {
+            dead_zone,
+            soft_zone,
+            reframe_speed: 4.0,
+        }
+    }
+
+    pub fn with_reframe_speed(mut self, reframe_speed: f32) -> Self {
+        self.reframe_speed = reframe_speed;
+        self
+    }
+
+    /// Where an entity that's `current` in the viewport should be after `dt` seconds, or `None` if it's inside the dead zone
+    /// around `anchor`.
+    pub fn reframe(&self, current: Vec2, anchor: Vec2, dt: f32) -> Option<Vec2> {
+        let dead_zone = self.dead_zone.abs();
+        let soft_zone = self.soft_zone.abs().max(dead_zone);
+        let goal = current.clamp(anchor - dead_zone, anchor + dead_zone);
+        if goal == current {
+            return None;
+        }
+        let follow = 1.0 - (-self.reframe_speed * dt).exp();
+        let next = current + (goal - current) * follow;
+        Some(next.clamp(anchor - soft_zone, anchor + soft_zone))
+    }
+}
+
+/// Copies the `GlobalTransform` translations of the entities referenced by [`LookAt`] and [`EyeAt`] into the `LookTransform`.
+pub fn look_at_entity_system(
+    time: Res<Time>,
+    mut cameras: Query<(Entity, &mut LookTransform, Option<&LookAt>, Option<&EyeAt>)>,
+    anchors: Query<&GlobalTransform>,
+    followers: Query<(Option<&Projection>, Option<&FramingZones>, Has<LookAhead>)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut look_transform, look_at, eye_at) in cameras.iter_mut() {
+        let Ok((projection, zones, has_look_ahead)) = followers.get(entity) else {
+            continue;
+        };
+        // Only write on change, so idle cameras can stay asleep. The eye goes first, since framing the target depends on it.
+        if let Some(eye_at) = eye_at {
+            if let Ok(anchor) = anchors.get(eye_at.entity) {
+                let eye = anchor.translation() + eye_at.offset;
+                if look_transform.eye != eye {
+                    look_transform.eye = eye;
+                }
+            }
+        }
+        // The `look_ahead_system` sets the target with the lead instead, so zones aren't re-framed twice a frame.
+        if let Some(look_at) = look_at.filter(|_| !has_look_ahead) {
+            if let Ok(anchor) = anchors.get(look_at.entity) {
+                let subject = anchor.translation() + look_at.offset;
+                let target = look_at.framed_target(&look_transform, subject, projection, zones, dt);
+                if look_transform.target != target {
+                    look_transform.target = target;
+                }
+            }
+        }
+    }
+}
+
+/// Sets the targets of [`LookAt`] cameras with a [`LookAhead`], leading the followed entity.
+pub fn look_ahead_system(
+    time: Res<Time>,
+    mut cameras: Query<(Entity, &mut LookTransform, &LookAt, &mut LookAhead)>,
+    anchors: Query<(&GlobalTransform, Option<&LookAheadVelocity>)>,
+    followers: Query<(Option<&Projection>, Option<&FramingZones>)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut look_transform, look_at, mut look_ahead) in cameras.iter_mut() {
+        let (Ok((anchor, velocity)), Ok((projection, zones))) =
+            (anchors.get(look_at.entity), followers.get(entity))
+        else {
+            continue;
+        };
+        let position = anchor.translation();
+        let velocity = match (velocity, look_ahead.last_position) {
+            (Some(velocity), _) => velocity.0,
+            (None, Some(last_position)) if dt > 0.0 => (position - last_position) / dt,
+            _ => Vec3::ZERO,
+        };
+
+        // This is internal state, so only the resulting target change should wake the camera.
+        let look_ahead = look_ahead.bypass_change_detection();
+        look_ahead.last_position = Some(position);
+        let goal = (look_ahead.time * velocity).clamp_length_max(look_ahead.max_distance);
+        let weight = look_ahead.smoothing_weight;
+        look_ahead.offset = weight * look_ahead.offset + (1.0 - weight) * goal;
+        if look_ahead.offset.length_squared() < 1e-8 {
+            look_ahead.offset = Vec3::ZERO;
+        }
+
+        let subject = position + look_at.offset + look_ahead.offset;
+        let target = look_at.framed_target(&look_transform, subject, projection, zones, dt);
+        if look_transform.target != target {
+            look_transform.target = target;
+        }
+    }
+}
+
+fn eye_look_at_target_transform(eye: Vec3, target: Vec3, up: Vec3) -> Transform {
+    // With the eye at the target, there is no direction to look in, so keep the default rather than producing NaNs.
+    let Some(look_vector) = (target - eye).try_normalize() else {
+        return Transform::from_translation(eye);
+    };
+    Transform::from_translation(eye).with_rotation(rotation_looking_to(look_vector, up))
 }
 
 /// Preforms exponential smoothing on a `LookTransform`. Set the `lag_weight` between `0.0` and `1.0`, where higher is smoother.
+///
+/// The lag weight can also be set per world axis, e.g. to follow a jumping character's Y instantly while smoothing X and Z.
+///
+/// Alternatively, the eye and target can follow on springs, with [`SmoothingKind::Spring`].
+///
+/// First-person cameras can smooth only the eye with [`Smoother::with_instant_look`], so the look direction follows the
+/// mouse without latency.
 #[derive(Clone, Component, Copy, Debug, Reflect)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[reflect(Component, Default, Debug)]
 pub struct Smoother {
-    lag_weight: f32,
+    lag_weight: Vec3,
+    kind: SmoothingKind,
     lerp_tfm: Option<LookTransform>,
+    /// The velocities of the smoothed eye and target, for springs.
+    velocity: [Vec3; 2],
+    instant_look: bool,
+    teleport_behavior: TeleportBehavior,
     enabled: bool,
+    idle_frames: u32,
 }
 
 impl Default for Smoother {
     fn default() -> Self {
         Self {
-            lag_weight: 0.9,
+            lag_weight: Vec3::splat(0.9),
+            kind: SmoothingKind::Exponential,
             lerp_tfm: Some(LookTransform::default()),
+            velocity: [Vec3::ZERO; 2],
+            instant_look: false,
+            teleport_behavior: TeleportBehavior::Snap,
             enabled: true,
+            idle_frames: 0,
+        }
+    }
+}
+
+/// What a [`Smoother`] does when its camera jumps, either because it's re-enabled or with [`LookTransform::teleport`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum TeleportBehavior {
+    /// Cuts straight to the new view.
+    #[default]
+    Snap,
+    /// Smooths over from the last displayed view, like any other move.
+    Glide,
+}
+
+/// How a [`Smoother`] approaches its goal.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum SmoothingKind {
+    /// Moves a fixed fraction of the remaining distance each frame, set by the lag weight. This never overshoots.
+    #[default]
+    Exponential,
+    /// Pulls the eye and target toward their goals with a damped spring, integrated in steps of at most
+    /// [`Smoother::MAX_SPRING_STEP`] for stability. The lag weight is ignored.
+    ///
+    /// `damping` below `2.0 * stiffness.sqrt()` overshoots a little before settling, and above it approaches more slowly.
+    Spring { stiffness: f32, damping: f32 },
+}
+
+impl SmoothingKind {
+    /// The spring that settles fastest without overshooting.
+    pub fn critically_damped_spring(stiffness: f32) -> Self {
+        Self::Spring {
+            stiffness,
+            damping: 2.0 * stiffness.sqrt(),
         }
     }
 }
 
 impl Smoother {
     pub fn new(lag_weight: f32) -> Self {
+        Self::new_per_axis(Vec3::splat(lag_weight))
+    }
+
+    /// Like [`Smoother::new`], but with a separate lag weight for each world axis.
+    pub fn new_per_axis(lag_weight: Vec3) -> Self {
         Self {
             lag_weight,
+            kind: SmoothingKind::Exponential,
             lerp_tfm: None,
+            velocity: [Vec3::ZERO; 2],
+            instant_look: false,
+            teleport_behavior: TeleportBehavior::Snap,
             enabled: true,
+            idle_frames: 0,
+        }
+    }
+
+    /// A smoother with any [`SmoothingKind`], with the default lag weight for exponential smoothing.
+    pub fn with_kind(kind: SmoothingKind) -> Self {
+        Self {
+            kind,
+            ..Self::new(0.9)
         }
     }
 
+    /// Smooths only the eye when `instant_look` is set, and keeps the target at the same offset from the smoothed eye as
+    /// in the goal, so the look direction is never behind.
+    pub fn with_instant_look(mut self, instant_look: bool) -> Self {
+        self.instant_look = instant_look;
+        self
+    }
+
+    pub fn with_teleport_behavior(mut self, teleport_behavior: TeleportBehavior) -> Self {
+        self.teleport_behavior = teleport_behavior;
+        self
+    }
+
+    /// The longest time step that springs are integrated with, in seconds.
+    pub const MAX_SPRING_STEP: f32 = 1.0 / 240.0;
+
     pub(crate) fn set_enabled(&mut self, enabled: bool) {
+        if self.enabled == enabled {
+            return;
+        }
         self.enabled = enabled;
         if self.enabled {
-            // To prevent camera jumping from last lerp before disabling to the current position,
-            // reset smoother state
-            self.reset();
+            self.teleported();
         }
     }
 
+    /// Applies the [`TeleportBehavior`] after the goal jumped.
+    fn teleported(&mut self) {
+        match self.teleport_behavior {
+            // Forget the last view, so the next frame starts at the goal rather than swooshing over from a stale view.
+            TeleportBehavior::Snap => self.reset(),
+            // Keep smoothing from the last displayed view, as for any other move.
+            TeleportBehavior::Glide => {}
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
     pub fn set_lag_weight(&mut self, lag_weight: f32) {
+        self.set_lag_weight_per_axis(Vec3::splat(lag_weight));
+    }
+
+    pub fn set_lag_weight_per_axis(&mut self, lag_weight: Vec3) {
         self.lag_weight = lag_weight;
+        self.idle_frames = 0;
+    }
+
+    pub fn lag_weight(&self) -> Vec3 {
+        self.lag_weight
     }
 
+    pub fn set_kind(&mut self, kind: SmoothingKind) {
+        self.kind = kind;
+        self.idle_frames = 0;
+    }
+
+    pub fn kind(&self) -> SmoothingKind {
+        self.kind
+    }
+
+    pub fn set_instant_look(&mut self, instant_look: bool) {
+        self.instant_look = instant_look;
+        self.idle_frames = 0;
+    }
+
+    pub fn instant_look(&self) -> bool {
+        self.instant_look
+    }
+
+    pub fn set_teleport_behavior(&mut self, teleport_behavior: TeleportBehavior) {
+        self.teleport_behavior = teleport_behavior;
+    }
+
+    pub fn teleport_behavior(&self) -> TeleportBehavior {
+        self.teleport_behavior
+    }
+
+    /// The transform that was displayed in the last frame, or `None` before the first frame or after a reset.
+    pub fn smoothed(&self) -> Option<LookTransform> {
+        self.lerp_tfm
+    }
+
+    /// How many consecutive frames the smoothed transform has been within epsilon of its goal.
+    pub fn idle_frames(&self) -> u32 {
+        self.idle_frames
+    }
+
+    /// Smooths one frame toward `new_tfm`, assuming 60 frames per second for springs. See
+    /// [`Smoother::smooth_transform_dt`].
     pub fn smooth_transform(&mut self, new_tfm: &LookTransform) -> LookTransform {
-        debug_assert!(0.0 <= self.lag_weight);
-        debug_assert!(self.lag_weight < 1.0);
+        self.smooth_transform_dt(new_tfm, 1.0 / 60.0)
+    }
 
+    /// Smooths one frame of `dt` seconds toward `new_tfm`. Exponential smoothing ignores `dt`, stepping once per call.
+    pub fn smooth_transform_dt(&mut self, new_tfm: &LookTransform, dt: f32) -> LookTransform {
         let old_lerp_tfm = self.lerp_tfm.unwrap_or(*new_tfm);
 
-        let lead_weight = 1.0 - self.lag_weight;
-        let lerp_tfm = LookTransform {
-            eye: old_lerp_tfm.eye * self.lag_weight + new_tfm.eye * lead_weight,
-            target: old_lerp_tfm.target * self.lag_weight + new_tfm.target * lead_weight,
-            up: new_tfm.up,
+        let mut lerp_tfm = match self.kind {
+            SmoothingKind::Exponential => {
+                debug_assert!(self.lag_weight.cmpge(Vec3::ZERO).all());
+                debug_assert!(self.lag_weight.cmplt(Vec3::ONE).all());
+
+                let lead_weight = Vec3::ONE - self.lag_weight;
+                LookTransform {
+                    eye: old_lerp_tfm.eye * self.lag_weight + new_tfm.eye * lead_weight,
+                    target: old_lerp_tfm.target * self.lag_weight + new_tfm.target * lead_weight,
+                    up: new_tfm.up,
+                }
+            }
+            SmoothingKind::Spring { stiffness, damping } => {
+                let [mut eye_velocity, mut target_velocity] = self.velocity;
+                let (mut eye, mut target) = (old_lerp_tfm.eye, old_lerp_tfm.target);
+                let steps = (dt / Self::MAX_SPRING_STEP).ceil().max(1.0);
+                let h = dt / steps;
+                for _ in 0..steps as u32 {
+                    // Semi-implicit Euler, which stays stable for stiff springs at small steps.
+                    eye_velocity += h * (stiffness * (new_tfm.eye - eye) - damping * eye_velocity);
+                    eye += h * eye_velocity;
+                    target_velocity +=
+                        h * (stiffness * (new_tfm.target - target) - damping * target_velocity);
+                    target += h * target_velocity;
+                }
+                self.velocity = [eye_velocity, target_velocity];
+                LookTransform {
+                    eye,
+                    target,
+                    up: new_tfm.up,
+                }
+            }
         };
 
+        if self.instant_look {
+            lerp_tfm.target = lerp_tfm.eye + (new_tfm.target - new_tfm.eye);
+            // The target moves with the eye.
+            self.velocity[1] = self.velocity[0];
+        }
+
         self.lerp_tfm = Some(lerp_tfm);
 
         lerp_tfm
@@ -128,18 +799,294 @@ impl Smoother {
 
     pub fn reset(&mut self) {
         self.lerp_tfm = None;
+        self.velocity = [Vec3::ZERO; 2];
+        self.idle_frames = 0;
+    }
+}
+
+/// Add this to a camera whose `LookTransform` only changes in `FixedUpdate`, e.g. because it follows a physics body or its
+/// controller runs there (see `with_control_schedule` on the controller plugins), to smooth it between fixed steps.
+///
+/// The `LookTransform` is recorded after every fixed step, and the [`look_transform_system`] steers toward a blend of the
+/// last two records by how far the render frame is into the next step, instead of the `LookTransform` itself. Changes made
+/// outside of the fixed schedule only take effect at the next step.
+///
+/// Like smoothing, this needs an enabled [`Smoother`]. A lag weight of `0.0` interpolates without smoothing.
+#[derive(Component, Debug, Default, PartialEq, Clone, Copy, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct FixedInterpolation {
+    pub mode: FixedInterpolationMode,
+    previous: Option<LookTransform>,
+    current: Option<LookTransform>,
+}
+
+/// How a [`FixedInterpolation`] fills in the time between fixed steps.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub enum FixedInterpolationMode {
+    /// Blends between the last two steps. This is always smooth, but lags one fixed step behind.
+    #[default]
+    Interpolate,
+    /// Predicts ahead from the last two steps. This doesn't lag, but overshoots a little when the motion changes.
+    Extrapolate,
+}
+
+impl FixedInterpolation {
+    pub fn new(mode: FixedInterpolationMode) -> Self {
+        Self {
+            mode,
+            ..Default::default()
+        }
+    }
+
+    /// The `LookTransform` at `overstep`, the fraction from `0.0` to `1.0` of a fixed step since the last one ran. `None`
+    /// before the first step.
+    pub fn sample(&self, overstep: f32) -> Option<LookTransform> {
+        let current = self.current?;
+        let previous = self.previous.unwrap_or(current);
+        Some(match self.mode {
+            FixedInterpolationMode::Interpolate => previous.lerp(&current, overstep),
+            FixedInterpolationMode::Extrapolate => previous.lerp(&current, 1.0 + overstep),
+        })
+    }
+}
+
+/// Records the `LookTransform` of each [`FixedInterpolation`] camera after a fixed step.
+pub fn record_fixed_interpolation_system(
+    mut cameras: Query<(&LookTransform, &mut FixedInterpolation)>,
+) {
+    for (look_transform, mut interpolation) in cameras.iter_mut() {
+        if interpolation.current == Some(*look_transform)
+            && interpolation.previous == interpolation.current
+        {
+            // Don't wake a camera that's standing still.
+            continue;
+        }
+        interpolation.previous = interpolation.current.replace(*look_transform);
+    }
+}
+
+/// Lets the [`look_transform_system`] put cameras to sleep once their smoothing has converged.
+///
+/// A camera whose smoothed transform has been within `epsilon` of its `LookTransform` for `frames` consecutive frames is
+/// snapped to its goal and then skipped, until its `LookTransform` changes again. Sleeping cameras cost next to nothing, and
+/// their `Transform` isn't touched, so transform propagation skips them too.
+#[derive(Resource, Clone, Copy, Debug, Reflect)]
+#[reflect(Resource, Default, Debug)]
+pub struct SmootherIdleSettings {
+    pub enabled: bool,
+    pub frames: u32,
+    pub epsilon: f32,
+}
+
+impl Default for SmootherIdleSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            frames: 3,
+            epsilon: 1e-4,
+        }
     }
 }
 
+/// Sent once a camera's smoothing has converged, after it has been within the [`SmootherIdleSettings`] epsilon of its
+/// `LookTransform` for the configured number of frames, e.g. to start dialogue once the camera has finished moving. It's
+/// sent again every time the camera moves and settles, whether or not idle cameras are put to sleep.
+#[derive(Clone, Copy, Debug, Event, PartialEq, Eq)]
+pub struct SmootherSettled(pub Entity);
+
+/// Smooths each `LookTransform` into the `Transform` of its entity. With the `parallel` feature, the cameras are processed
+/// on multiple threads, which pays off for scenes with hundreds of smoothed cameras.
 pub fn look_transform_system(
-    mut cameras: Query<(&LookTransform, &mut Transform, Option<&mut Smoother>)>,
+    time: CameraTime,
+    fixed_time: Res<Time<Fixed>>,
+    idle_settings: Res<SmootherIdleSettings>,
+    mut cameras: Query<
+        (Entity, Ref<LookTransform>, &mut Transform, &mut Smoother),
+        Without<XrDriven>,
+    >,
+    interpolations: Query<Ref<FixedInterpolation>>,
+    mut settled: Local<Parallel<Vec<Entity>>>,
+    mut settled_events: EventWriter<SmootherSettled>,
 ) {
-    for (look_transform, mut scene_transform, smoother) in cameras.iter_mut() {
-        match smoother {
-            Some(mut s) if s.enabled => {
-                *scene_transform = s.smooth_transform(look_transform).into()
+    let overstep = fixed_time.overstep_fraction();
+    let sync = |(entity, look_transform, mut scene_transform, mut s): (
+        Entity,
+        Ref<LookTransform>,
+        Mut<Transform>,
+        Mut<Smoother>,
+    )| {
+        if !s.enabled {
+            return;
+        }
+        let dt = time.delta_secs(entity);
+        let interpolation = interpolations.get(entity).ok();
+        let is_changed =
+            look_transform.is_changed() || interpolation.as_ref().is_some_and(|i| i.is_changed());
+        let goal = interpolation
+            .and_then(|i| i.sample(overstep))
+            .unwrap_or(*look_transform);
+
+        let asleep = idle_settings.enabled && s.idle_frames >= idle_settings.frames;
+        // Writes that leave the goal where it was don't wake the camera either.
+        if asleep && (!is_changed || s.lerp_tfm == Some(goal)) {
+            return;
+        }
+
+        let mut smoothed = s.smooth_transform_dt(&goal, dt);
+        if smoothed.abs_diff_eq(&goal, idle_settings.epsilon) {
+            s.idle_frames = s.idle_frames.saturating_add(1);
+            if s.idle_frames >= idle_settings.frames {
+                smoothed = goal;
+                s.lerp_tfm = Some(smoothed);
+                s.velocity = [Vec3::ZERO; 2];
             }
-            _ => (),
-        };
+            if s.idle_frames == idle_settings.frames.max(1) {
+                settled.borrow_local_mut().push(entity);
+            }
+        } else {
+            s.idle_frames = 0;
+        }
+
+        // Leave an unchanged `Transform` alone, so that it doesn't need to be propagated.
+        scene_transform.set_if_neq(smoothed.into());
+    };
+
+    #[cfg(feature = "parallel")]
+    cameras.par_iter_mut().for_each(sync);
+    #[cfg(not(feature = "parallel"))]
+    cameras.iter_mut().for_each(sync);
+
+    settled_events.send_batch(settled.drain().map(SmootherSettled));
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_critically_damped_spring_settles_without_overshoot() {
+        let mut smoother = Smoother::with_kind(SmoothingKind::critically_damped_spring(100.0));
+        smoother.smooth_transform(&LookTransform::new(Vec3::ZERO, Vec3::Z, Vec3::Y));
+
+        let goal = LookTransform::new(Vec3::X, Vec3::X + Vec3::Z, Vec3::Y);
+        let mut smoothed = goal;
+        for _ in 0..60 {
+            smoothed = smoother.smooth_transform_dt(&goal, 1.0 / 60.0);
+            assert!(smoothed.eye.x <= 1.0);
+        }
+        assert!(smoothed.abs_diff_eq(&goal, 1e-3));
+    }
+
+    #[test]
+    fn test_fixed_interpolation_samples_between_steps() {
+        let mut interpolation = FixedInterpolation::default();
+        assert_eq!(interpolation.sample(0.5), None);
+
+        let step =
+            |x: f32| LookTransform::new(Vec3::new(x, 0.0, 0.0), Vec3::new(x, 0.0, -1.0), Vec3::Y);
+        interpolation.previous = Some(step(0.0));
+        interpolation.current = Some(step(1.0));
+        assert_eq!(interpolation.sample(0.25), Some(step(0.25)));
+
+        interpolation.mode = FixedInterpolationMode::Extrapolate;
+        assert_eq!(interpolation.sample(0.25), Some(step(1.25)));
+    }
+
+    #[test]
+    fn test_degenerate_up_still_makes_a_valid_transform() {
+        for up in [Vec3::ZERO, Vec3::NAN, Vec3::NEG_Y, 2.0 * Vec3::NEG_Y] {
+            let top_down = LookTransform::new(Vec3::Y, Vec3::ZERO, up);
+            let transform = Transform::from(top_down);
+            assert!(transform.rotation.is_finite(), "{up}");
+            assert!(transform.forward().abs_diff_eq(Vec3::NEG_Y, 1e-5), "{up}");
+            assert!(transform.up().abs_diff_eq(Vec3::NEG_Z, 1e-5), "{up}");
+        }
+        let at_target = LookTransform::new(Vec3::ONE, Vec3::ONE, Vec3::Y);
+        assert_eq!(
+            Transform::from(at_target),
+            Transform::from_translation(Vec3::ONE)
+        );
+    }
+
+    #[test]
+    fn test_set_up_safe_rejects_degenerate_ups() {
+        let mut transform = LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y);
+        assert!(!transform.set_up_safe(Vec3::Z));
+        assert!(!transform.set_up_safe(Vec3::ZERO));
+        assert_eq!(transform.up, Vec3::Y);
+        assert!(transform.set_up_safe(2.0 * Vec3::X));
+        assert_eq!(transform.up, Vec3::X);
+
+        transform.up = Vec3::NAN;
+        assert!(!transform.set_up_safe(Vec3::NAN));
+        assert_eq!(transform.up, Vec3::Y);
+    }
+
+    #[test]
+    fn test_instant_look_smooths_only_the_eye() {
+        let mut smoother = Smoother::new(0.5).with_instant_look(true);
+        smoother.smooth_transform(&LookTransform::new(Vec3::ZERO, Vec3::Z, Vec3::Y));
+
+        let goal = LookTransform::new(Vec3::X, Vec3::X - Vec3::Z, Vec3::Y);
+        let smoothed = smoother.smooth_transform(&goal);
+        assert_eq!(smoothed.eye, 0.5 * Vec3::X);
+        assert_eq!(smoothed.look_direction(), goal.look_direction());
+    }
+
+    #[test]
+    fn test_is_converged_once_the_smoother_catches_up() {
+        let epsilon = SmootherIdleSettings::default().epsilon;
+        let mut smoother = Smoother::new(0.5);
+        let start = LookTransform::new(Vec3::ZERO, -Vec3::Z, Vec3::Y);
+        assert!(!start.is_converged(&smoother, epsilon));
+        smoother.smooth_transform(&start);
+        assert!(start.is_converged(&smoother, epsilon));
+
+        let goal = LookTransform::new(Vec3::X, Vec3::X - Vec3::Z, Vec3::Y);
+        smoother.smooth_transform(&goal);
+        assert!(!goal.is_converged(&smoother, epsilon));
+        for _ in 0..30 {
+            smoother.smooth_transform(&goal);
+        }
+        assert!(goal.is_converged(&smoother, epsilon));
+    }
+
+    #[test]
+    fn test_framing_zones_reframe_toward_dead_zone_within_soft_zone() {
+        let zones = FramingZones::new(Vec2::splat(0.1), Vec2::splat(0.5));
+        let anchor = Vec2::new(0.3, 0.0);
+        assert_eq!(zones.reframe(Vec2::new(0.35, -0.05), anchor, 0.1), None);
+
+        let next = zones.reframe(Vec2::new(0.6, 0.0), anchor, 0.1).unwrap();
+        assert!(next.x > 0.4 && next.x < 0.6, "{next}");
+
+        let next = zones.reframe(Vec2::new(1.5, 0.0), anchor, 0.0).unwrap();
+        assert_eq!(next, Vec2::new(0.8, 0.0));
+    }
+
+    #[test]
+    fn test_teleport_snaps_or_glides() {
+        let start = LookTransform::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y);
+        let (eye, target) = (Vec3::new(100.0, 0.0, 0.0), Vec3::new(100.0, 0.0, -1.0));
+
+        let mut look_transform = start;
+        let mut smoother = Smoother::new(0.5);
+        smoother.smooth_transform(&look_transform);
+        look_transform.teleport(eye, target, &mut smoother);
+        assert_eq!(smoother.smooth_transform(&look_transform), look_transform);
+
+        let mut look_transform = start;
+        let mut smoother = Smoother::new(0.5).with_teleport_behavior(TeleportBehavior::Glide);
+        smoother.smooth_transform(&look_transform);
+        look_transform.teleport(eye, target, &mut smoother);
+        assert_eq!(smoother.smooth_transform(&look_transform).eye, 0.5 * eye);
     }
 }
@@ -0,0 +1,242 @@
+use crate::{
+    clock::CameraTime,
+    controllers::{AnyController, CameraControllerStatus, ControllerKind},
+    ease::Ease,
+    LookTransform, Smoother,
+};
+
+use bevy::{ecs::prelude::*, math::prelude::*};
+
+use std::collections::VecDeque;
+
+/// One step of a scripted camera motion, for [`LookTransformCommands::queue`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CameraMove {
+    /// Moves the eye and target in a straight line.
+    To {
+        eye: Vec3,
+        target: Vec3,
+        duration: f32,
+        easing: Ease,
+    },
+    /// Swings the eye around the target by a yaw angle around the up vector, and a pitch angle that raises the eye, both
+    /// in radians.
+    OrbitBy {
+        yaw: f32,
+        pitch: f32,
+        duration: f32,
+        easing: Ease,
+    },
+    /// Holds the camera still.
+    Wait(f32),
+}
+
+impl CameraMove {
+    pub fn duration(&self) -> f32 {
+        match *self {
+            Self::To { duration, .. } | Self::OrbitBy { duration, .. } | Self::Wait(duration) => {
+                duration
+            }
+        }
+    }
+
+    /// The camera at `t` from `0.0` to `1.0` of the way through this move, starting at `from`.
+    pub fn sample(&self, from: &LookTransform, t: f32) -> LookTransform {
+        match *self {
+            Self::To {
+                eye,
+                target,
+                easing,
+                ..
+            } => from.lerp(&LookTransform::new(eye, target, from.up), easing.sample(t)),
+            Self::OrbitBy {
+                yaw, pitch, easing, ..
+            } => {
+                let t = easing.sample(t);
+                let offset = from.eye - from.target;
+                let pitch_axis = offset.cross(from.up).try_normalize().unwrap_or(Vec3::X);
+                let rotation = Quat::from_axis_angle(from.up, t * yaw)
+                    * Quat::from_axis_angle(pitch_axis, t * pitch);
+                LookTransform::new(from.target + rotation * offset, from.target, from.up)
+            }
+            Self::Wait(_) => *from,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct ActiveMove {
+    from: LookTransform,
+    step: CameraMove,
+    elapsed: f32,
+}
+
+/// A queue of scripted [`CameraMove`]s, for cutscenes and gameplay triggers. Moves play one after another, each starting
+/// where the last one ended, and the calls chain:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use smooth_bevy_cameras::{ease::Ease, LookTransformCommands};
+/// fn intro(mut moves: ResMut<LookTransformCommands>) {
+///     moves
+///         .move_to(Vec3::new(0.0, 2.0, 8.0), Vec3::ZERO, 2.0, Ease::SmoothStep)
+///         .wait(0.5)
+///         .orbit_by(std::f32::consts::PI, 0.0, 4.0);
+/// }
+/// ```
+///
+/// The moves are applied in `PostUpdate` to the `camera` if set, or else to the camera of the [`CameraControllerStatus`],
+/// or else to the only camera with a `LookTransform`. The camera follows the script exactly, with its `Smoother` reset
+/// along the way, and its controllers are [reseeded](crate::controllers::CameraController::reseed) after each move so
+/// they pick up from where it ended. Following and constraints still apply on top.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct LookTransformCommands {
+    pub camera: Option<Entity>,
+    queue: VecDeque<CameraMove>,
+    active: Option<ActiveMove>,
+}
+
+impl LookTransformCommands {
+    /// Adds `step` to the end of the queue.
+    pub fn queue(&mut self, step: CameraMove) -> &mut Self {
+        self.queue.push_back(step);
+        self
+    }
+
+    pub fn move_to(&mut self, eye: Vec3, target: Vec3, duration: f32, easing: Ease) -> &mut Self {
+        self.queue(CameraMove::To {
+            eye,
+            target,
+            duration,
+            easing,
+        })
+    }
+
+    /// Orbits around the target by `yaw` and `pitch` radians, as for [`CameraMove::OrbitBy`], eased in and out.
+    pub fn orbit_by(&mut self, yaw: f32, pitch: f32, duration: f32) -> &mut Self {
+        self.queue(CameraMove::OrbitBy {
+            yaw,
+            pitch,
+            duration,
+            easing: Ease::default(),
+        })
+    }
+
+    pub fn wait(&mut self, duration: f32) -> &mut Self {
+        self.queue(CameraMove::Wait(duration))
+    }
+
+    /// Stops the current move where it is, and drops the rest of the queue.
+    pub fn clear(&mut self) {
+        self.queue.clear();
+        self.active = None;
+    }
+
+    /// How many moves are left, including the one playing.
+    pub fn len(&self) -> usize {
+        self.queue.len() + usize::from(self.active.is_some())
+    }
+
+    /// Whether no move is playing or queued.
+    pub fn is_empty(&self) -> bool {
+        self.active.is_none() && self.queue.is_empty()
+    }
+}
+
+/// Plays the [`LookTransformCommands`] queue.
+pub fn look_transform_commands_system(
+    time: CameraTime,
+    mut moves: ResMut<LookTransformCommands>,
+    status: Option<Res<CameraControllerStatus>>,
+    mut cameras: Query<(Entity, &mut LookTransform, AnyController)>,
+    mut smoothers: Query<&mut Smoother>,
+) {
+    if moves.is_empty() {
+        return;
+    }
+    let camera = moves
+        .camera
+        .or_else(|| status.and_then(|s| s.entity))
+        .or_else(|| cameras.get_single().ok().map(|(entity, ..)| entity));
+    let Some(Ok((entity, mut transform, mut controllers))) = camera.map(|e| cameras.get_mut(e))
+    else {
+        return;
+    };
+
+    let mut dt = time.delta_secs(entity);
+    loop {
+        let mut active = match moves.active {
+            Some(active) => active,
+            None => match moves.queue.pop_front() {
+                Some(step) => ActiveMove {
+                    from: *transform,
+                    step,
+                    elapsed: 0.0,
+                },
+                None => break,
+            },
+        };
+        active.elapsed += dt;
+        let duration = active.step.duration();
+        let done = active.elapsed >= duration;
+        let t = if done { 1.0 } else { active.elapsed / duration };
+        transform.set_if_neq(active.step.sample(&active.from, t));
+
+        if !done {
+            moves.active = Some(active);
+            break;
+        }
+        // The next move starts right away, from where this one ended.
+        moves.active = None;
+        dt = 0.0;
+        for kind in ControllerKind::BUILT_IN {
+            controllers.reseed(kind, &transform);
+        }
+    }
+
+    // Follow the script exactly instead of lagging behind it.
+    if let Ok(mut smoother) = smoothers.get_mut(entity) {
+        smoother.reset();
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+    use std::f32::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_orbit_by_swings_the_eye_around_the_target() {
+        let from = LookTransform::new(Vec3::new(0.0, 0.0, 4.0), Vec3::ZERO, Vec3::Y);
+        let orbit = CameraMove::OrbitBy {
+            yaw: FRAC_PI_2,
+            pitch: 0.0,
+            duration: 1.0,
+            easing: Ease::Linear,
+        };
+
+        let halfway = orbit.sample(&from, 0.5);
+        assert_relative_eq!(halfway.radius(), 4.0, epsilon = 1e-5);
+        assert!(orbit
+            .sample(&from, 1.0)
+            .eye
+            .abs_diff_eq(Vec3::new(4.0, 0.0, 0.0), 1e-5));
+
+        let raise = CameraMove::OrbitBy {
+            yaw: 0.0,
+            pitch: FRAC_PI_2 / 2.0,
+            duration: 1.0,
+            easing: Ease::Linear,
+        };
+        assert!(raise.sample(&from, 1.0).eye.y > 0.0);
+    }
+}
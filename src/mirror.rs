@@ -0,0 +1,114 @@
+use crate::{xr::XrDriven, LookTransform, Smoother};
+
+use bevy::{ecs::prelude::*, math::prelude::*, reflect::Reflect, transform::components::Transform};
+
+/// Derives a secondary camera's view, like a top-down minimap or a picture-in-picture, from the smoothed view of a
+/// `source` camera.
+///
+/// The mirror's `LookTransform` and `Transform` are both written every frame, right after the source is smoothed, so the
+/// mirror follows without lag and doesn't need a `Smoother` or controller of its own.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct MirrorLookTransform {
+    pub source: Entity,
+    pub mode: MirrorMode,
+}
+
+impl MirrorLookTransform {
+    pub fn new(source: Entity, mode: MirrorMode) -> Self {
+        Self { source, mode }
+    }
+}
+
+/// How a [`MirrorLookTransform`] camera's view is derived from its source's.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Debug, PartialEq)]
+pub enum MirrorMode {
+    /// The same view, e.g. for a picture-in-picture with a different projection or render layers.
+    Copy,
+    /// Looks straight down at the source's target from `height` above it, like a minimap. With `follow_heading`, the top
+    /// of the view turns with the source's heading, otherwise it points toward -Z.
+    TopDown { height: f32, follow_heading: bool },
+    /// Looks at the source's target from a fixed world space offset.
+    Offset(Vec3),
+}
+
+impl MirrorMode {
+    /// The mirror's view for the `source` view.
+    pub fn look_transform(&self, source: &LookTransform) -> LookTransform {
+        match *self {
+            Self::Copy => *source,
+            Self::TopDown {
+                height,
+                follow_heading,
+            } => {
+                let heading = source
+                    .look_direction()
+                    .filter(|_| follow_heading)
+                    .and_then(|d| d.with_y(0.0).try_normalize())
+                    .unwrap_or(Vec3::NEG_Z);
+                LookTransform::new(source.target + height * Vec3::Y, source.target, heading)
+            }
+            Self::Offset(offset) => {
+                LookTransform::new(source.target + offset, source.target, source.up)
+            }
+        }
+    }
+}
+
+/// Updates each [`MirrorLookTransform`] camera from the smoothed view of its source.
+pub fn mirror_look_transform_system(
+    sources: Query<(&LookTransform, Option<&Smoother>), Without<MirrorLookTransform>>,
+    mut mirrors: Query<
+        (&MirrorLookTransform, &mut LookTransform, &mut Transform),
+        Without<XrDriven>,
+    >,
+) {
+    for (mirror, mut look_transform, mut transform) in mirrors.iter_mut() {
+        let Ok((source, smoother)) = sources.get(mirror.source) else {
+            continue;
+        };
+        let source = smoother
+            .filter(|s| s.is_enabled())
+            .and_then(Smoother::smoothed)
+            .unwrap_or(*source);
+        let mirrored = mirror.mode.look_transform(&source);
+        look_transform.set_if_neq(mirrored);
+        // Leave an unchanged `Transform` alone, so that it doesn't need to be propagated.
+        transform.set_if_neq(mirrored.into());
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_top_down_looks_down_at_source_target() {
+        let source =
+            LookTransform::new(Vec3::new(0.0, 2.0, 5.0), Vec3::new(5.0, 2.0, 5.0), Vec3::Y);
+        let top_down = |follow_heading| MirrorMode::TopDown {
+            height: 50.0,
+            follow_heading,
+        };
+
+        let north_up = top_down(false).look_transform(&source);
+        assert_eq!(north_up.eye, Vec3::new(5.0, 52.0, 5.0));
+        assert_eq!(north_up.target, source.target);
+        assert_eq!(north_up.up, Vec3::NEG_Z);
+
+        // The source looks along +X, which is the top of the minimap.
+        let heading_up = top_down(true).look_transform(&source);
+        assert_eq!(heading_up.up, Vec3::X);
+        let transform = Transform::from(heading_up);
+        assert!(transform.up().abs_diff_eq(Vec3::X, 1e-5));
+    }
+}
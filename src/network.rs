@@ -0,0 +1,271 @@
+use crate::{LookTransform, LookTransformSet};
+
+use bevy::{app::prelude::*, ecs::prelude::*, math::prelude::*, time::Time};
+
+use std::collections::VecDeque;
+
+/// Streams `LookTransform`s between apps, e.g. to spectate another player or follow a collaborator's view in an editor.
+///
+/// This crate doesn't do any networking itself. Drain the packets of a [`LookTransformSender`] and send them with your
+/// networking crate of choice, then [push](LookTransformReceiver::push) them into a [`LookTransformReceiver`] on the remote
+/// camera. The receiver plays them back a little late to hide network jitter, and its `Smoother` (if any) smooths the rest.
+#[derive(Default)]
+pub struct LookTransformSyncPlugin;
+
+impl Plugin for LookTransformSyncPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (
+                send_system.after(LookTransformSet::Constrain),
+                receive_system.before(LookTransformSet::Follow),
+            ),
+        );
+    }
+}
+
+/// A `LookTransform`, quantized to a grid for a compact encoding. The `up` vector isn't sent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct LookTransformPacket {
+    /// Counts up with each packet from the same sender, to drop packets that arrive out of order.
+    pub sequence: u32,
+    /// When the packet was sent, in milliseconds since the sender started.
+    pub time_millis: u32,
+    pub eye: IVec3,
+    pub target: IVec3,
+}
+
+impl LookTransformPacket {
+    /// The size of [`LookTransformPacket::to_bytes`].
+    pub const SIZE: usize = 32;
+
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+        let words = [self.sequence, self.time_millis]
+            .into_iter()
+            .chain(self.eye.to_array().map(|x| x as u32))
+            .chain(self.target.to_array().map(|x| x as u32));
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip(words) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::SIZE {
+            return None;
+        }
+        let mut words = bytes
+            .chunks_exact(4)
+            .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()));
+        let mut next = || words.next().unwrap();
+        Some(Self {
+            sequence: next(),
+            time_millis: next(),
+            eye: IVec3::new(next() as i32, next() as i32, next() as i32),
+            target: IVec3::new(next() as i32, next() as i32, next() as i32),
+        })
+    }
+}
+
+fn quantize(v: Vec3, quantum: f32) -> IVec3 {
+    (v / quantum).round().as_ivec3()
+}
+
+fn dequantize(v: IVec3, quantum: f32) -> Vec3 {
+    v.as_vec3() * quantum
+}
+
+/// Produces a [`LookTransformPacket`] whenever the quantized `LookTransform` of this entity changes.
+#[derive(Component, Debug)]
+pub struct LookTransformSender {
+    /// The size of the quantization grid, in world units. It must match the receiver's.
+    pub quantum: f32,
+    sequence: u32,
+    last_sent: Option<(IVec3, IVec3)>,
+    outbox: Vec<LookTransformPacket>,
+}
+
+impl LookTransformSender {
+    pub fn new(quantum: f32) -> Self {
+        Self {
+            quantum,
+            sequence: 0,
+            last_sent: None,
+            outbox: Vec::new(),
+        }
+    }
+
+    /// Takes the packets produced since the last call, oldest first.
+    pub fn drain_packets(&mut self) -> impl Iterator<Item = LookTransformPacket> + '_ {
+        self.outbox.drain(..)
+    }
+}
+
+impl Default for LookTransformSender {
+    /// A millimeter grid, for world units of meters.
+    fn default() -> Self {
+        Self::new(0.001)
+    }
+}
+
+/// Drives this entity's `LookTransform` from the packets of a remote [`LookTransformSender`].
+#[derive(Component, Debug)]
+pub struct LookTransformReceiver {
+    /// The size of the quantization grid, in world units. It must match the sender's.
+    pub quantum: f32,
+    /// How far behind the sender to play back, in seconds. Packets that arrive up to this late still play on time.
+    pub delay: f32,
+    buffer: VecDeque<(f32, LookTransformPacket)>,
+    /// The local time minus the sender time, from the first packet.
+    clock_offset: Option<f32>,
+    last_sequence: Option<u32>,
+}
+
+impl LookTransformReceiver {
+    pub fn new(quantum: f32, delay: f32) -> Self {
+        Self {
+            quantum,
+            delay,
+            buffer: VecDeque::new(),
+            clock_offset: None,
+            last_sequence: None,
+        }
+    }
+
+    /// Queues a packet that arrived at the local time `now`, in seconds. Out of order packets are dropped.
+    pub fn push(&mut self, packet: LookTransformPacket, now: f32) {
+        if self
+            .last_sequence
+            .is_some_and(|last| packet.sequence.wrapping_sub(last) as i32 <= 0)
+        {
+            return;
+        }
+        self.last_sequence = Some(packet.sequence);
+
+        let sent = packet.time_millis as f32 / 1000.0;
+        self.clock_offset.get_or_insert(now - sent);
+        self.buffer.push_back((sent, packet));
+    }
+
+    /// The transform to show at the local time `now`, interpolated between the buffered packets, or `None` before the
+    /// first packet. Packets that are no longer needed are dropped.
+    pub fn sample(&mut self, now: f32) -> Option<(Vec3, Vec3)> {
+        let playback_time = now - self.clock_offset? - self.delay;
+        while self.buffer.len() > 1 && self.buffer[1].0 <= playback_time {
+            self.buffer.pop_front();
+        }
+
+        let (from_time, from) = *self.buffer.front()?;
+        let lerp = |a: IVec3, b: IVec3, t: f32| {
+            dequantize(a, self.quantum).lerp(dequantize(b, self.quantum), t)
+        };
+        match self.buffer.get(1) {
+            Some((to_time, to)) if playback_time > from_time => {
+                let t = (playback_time - from_time) / (to_time - from_time);
+                Some((lerp(from.eye, to.eye, t), lerp(from.target, to.target, t)))
+            }
+            _ => Some((
+                dequantize(from.eye, self.quantum),
+                dequantize(from.target, self.quantum),
+            )),
+        }
+    }
+}
+
+impl Default for LookTransformReceiver {
+    /// A millimeter grid, with a tenth of a second of delay.
+    fn default() -> Self {
+        Self::new(0.001, 0.1)
+    }
+}
+
+pub fn send_system(
+    time: Res<Time>,
+    mut senders: Query<(&mut LookTransformSender, &LookTransform), Changed<LookTransform>>,
+) {
+    let time_millis = (time.elapsed_secs_f64() * 1000.0) as u32;
+    for (mut sender, transform) in senders.iter_mut() {
+        let quantized = (
+            quantize(transform.eye, sender.quantum),
+            quantize(transform.target, sender.quantum),
+        );
+        if sender.last_sent == Some(quantized) {
+            continue;
+        }
+        sender.last_sent = Some(quantized);
+        let sequence = sender.sequence;
+        sender.sequence = sequence.wrapping_add(1);
+        sender.outbox.push(LookTransformPacket {
+            sequence,
+            time_millis,
+            eye: quantized.0,
+            target: quantized.1,
+        });
+    }
+}
+
+pub fn receive_system(
+    time: Res<Time>,
+    mut receivers: Query<(&mut LookTransformReceiver, &mut LookTransform)>,
+) {
+    let now = time.elapsed_secs();
+    for (mut receiver, mut transform) in receivers.iter_mut() {
+        let Some((eye, target)) = receiver.bypass_change_detection().sample(now) else {
+            continue;
+        };
+        // Only write on change, so the camera can sleep while the sender is idle.
+        if transform.eye != eye || transform.target != target {
+            transform.eye = eye;
+            transform.target = target;
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    fn packet(sequence: u32, time_millis: u32, x: i32) -> LookTransformPacket {
+        LookTransformPacket {
+            sequence,
+            time_millis,
+            eye: IVec3::new(x, -1, 2),
+            target: IVec3::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_packet_round_trips_through_bytes() {
+        let packet = packet(7, 1234, -500);
+        assert_eq!(
+            LookTransformPacket::from_bytes(&packet.to_bytes()),
+            Some(packet)
+        );
+        assert_eq!(LookTransformPacket::from_bytes(&[0; 3]), None);
+    }
+
+    #[test]
+    fn test_receiver_interpolates_after_delay() {
+        let mut receiver = LookTransformReceiver::new(1.0, 0.1);
+        receiver.push(packet(0, 0, 0), 5.0);
+        receiver.push(packet(1, 100, 10), 5.1);
+        // A late duplicate is ignored.
+        receiver.push(packet(0, 0, 0), 5.1);
+
+        let (eye, _) = receiver.sample(5.15).unwrap();
+        assert_relative_eq!(eye.x, 5.0, epsilon = 1e-4);
+        let (eye, _) = receiver.sample(5.3).unwrap();
+        assert_relative_eq!(eye.x, 10.0);
+    }
+}
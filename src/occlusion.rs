@@ -0,0 +1,286 @@
+use crate::{
+    shake::restore_camera_shake_system, world_space::restore_world_space_system, xr::XrDriven,
+    LookTransform, LookTransformSet, Smoother,
+};
+
+use bevy::{
+    app::prelude::*, ecs::prelude::*, math::prelude::*, prelude::ReflectDefault, reflect::Reflect,
+    transform::components::Transform,
+};
+
+/// Keeps scenery between a camera's target and eye from blocking the view, for cameras with a [`CameraOcclusion`].
+///
+/// This crate doesn't do raycasts itself. Each frame, the app casts [`CameraOcclusion::probe`] with its physics or picking
+/// backend, and writes what it hits into the camera's [`OcclusionHits`].
+pub struct CameraOcclusionPlugin;
+
+impl Plugin for CameraOcclusionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OccludedEntities>().add_systems(
+            PostUpdate,
+            (
+                restore_pulled_in_system
+                    .after(restore_world_space_system)
+                    .before(restore_camera_shake_system)
+                    .in_set(LookTransformSet::Sync),
+                occlusion_system
+                    .after(LookTransformSet::Sync)
+                    .before(LookTransformSet::Finish),
+            ),
+        );
+    }
+}
+
+/// What a [`CameraOcclusion`] does about scenery that blocks the view.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Default, Debug, PartialEq)]
+pub enum OcclusionPolicy {
+    /// Moves the camera in front of the nearest blocker, toward the target.
+    #[default]
+    PullIn,
+    /// Leaves the camera where it is, and sends [`OccludedEntities`] so the game can fade or dither the blockers.
+    Report,
+}
+
+/// Handles scenery that blocks the view from the eye to the target, according to its `policy`.
+#[derive(Component, Clone, Debug, Reflect)]
+#[require(OcclusionHits)]
+#[reflect(Component, Default, Debug)]
+pub struct CameraOcclusion {
+    pub policy: OcclusionPolicy,
+    /// How far in front of a blocker a pulled-in camera stays, in world units.
+    pub margin: f32,
+    probe: Option<Ray3d>,
+    probe_length: f32,
+    occluded: Vec<Entity>,
+    /// The translations from before and after the last pull-in.
+    pulled_in: Option<(Vec3, Vec3)>,
+}
+
+impl Default for CameraOcclusion {
+    fn default() -> Self {
+        Self::new(OcclusionPolicy::default())
+    }
+}
+
+impl CameraOcclusion {
+    pub fn new(policy: OcclusionPolicy) -> Self {
+        Self {
+            policy,
+            margin: 0.2,
+            probe: None,
+            probe_length: 0.0,
+            occluded: Vec::new(),
+            pulled_in: None,
+        }
+    }
+
+    /// The ray from the displayed target toward the displayed eye and its length, which the app should cast to fill in
+    /// the [`OcclusionHits`]. `None` until the camera has been displayed.
+    pub fn probe(&self) -> Option<(Ray3d, f32)> {
+        self.probe.map(|ray| (ray, self.probe_length))
+    }
+
+    /// The blockers in the last [`OccludedEntities`] event.
+    pub fn occluded(&self) -> &[Entity] {
+        &self.occluded
+    }
+}
+
+/// An entity that blocks a [`CameraOcclusion::probe`], `distance` units from the target.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct OcclusionHit {
+    pub entity: Entity,
+    pub distance: f32,
+}
+
+/// What the app's raycast along the [`CameraOcclusion::probe`] hit, in any order. Clear it when nothing is in the way.
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct OcclusionHits(pub Vec<OcclusionHit>);
+
+/// Sent by cameras with [`OcclusionPolicy::Report`] whenever the set of entities blocking the view changes, including to
+/// none.
+#[derive(Clone, Debug, Event, PartialEq)]
+pub struct OccludedEntities {
+    pub camera: Entity,
+    /// The blockers, nearest to the target first.
+    pub entities: Vec<Entity>,
+}
+
+/// Moves each pulled-in camera back out to where it was, so a sleeping smoother and the offsets after it start from the
+/// unobstructed view.
+pub fn restore_pulled_in_system(
+    mut cameras: Query<(&mut CameraOcclusion, &mut Transform), Without<XrDriven>>,
+) {
+    for (mut occlusion, mut transform) in cameras.iter_mut() {
+        let occlusion = occlusion.bypass_change_detection();
+        match occlusion.pulled_in {
+            Some((unpulled, pulled)) if transform.translation == pulled => {
+                // Only a real change should trigger propagation, which the occlusion system decides.
+                transform.bypass_change_detection().translation = unpulled;
+            }
+            _ => occlusion.pulled_in = None,
+        }
+    }
+}
+
+pub fn occlusion_system(
+    mut events: EventWriter<OccludedEntities>,
+    mut cameras: Query<
+        (Entity, &mut CameraOcclusion, &OcclusionHits, &mut Transform),
+        Without<XrDriven>,
+    >,
+    views: Query<(&LookTransform, Option<&Smoother>)>,
+) {
+    for (entity, mut occlusion, hits, mut transform) in cameras.iter_mut() {
+        let Ok((look_transform, smoother)) = views.get(entity) else {
+            continue;
+        };
+        // What's on screen, rather than where the camera is headed.
+        let displayed = smoother
+            .filter(|s| s.is_enabled())
+            .and_then(Smoother::smoothed)
+            .unwrap_or(*look_transform);
+        let Ok(direction) = Dir3::new(displayed.eye - displayed.target) else {
+            continue;
+        };
+        let length = displayed.radius();
+        // Don't let updating the probe look like a change.
+        let bypassed = occlusion.bypass_change_detection();
+        bypassed.probe = Some(Ray3d::new(displayed.target, direction));
+        bypassed.probe_length = length;
+
+        let mut blockers: Vec<_> = hits.0.iter().filter(|hit| hit.distance < length).collect();
+        blockers.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+
+        match occlusion.policy {
+            OcclusionPolicy::PullIn => {
+                let previous = occlusion.bypass_change_detection().pulled_in.take();
+                // Only pull in, starting from the current `Transform`, so offsets like a `CameraDrift` are kept.
+                let eye = transform.translation;
+                let current = eye.distance(displayed.target);
+                let distance = blockers
+                    .first()
+                    .map(|hit| (hit.distance - occlusion.margin).max(0.0))
+                    .filter(|&distance| distance < current);
+                if let Some(distance) = distance {
+                    let pulled =
+                        displayed.target + distance * (eye - displayed.target).normalize_or_zero();
+                    if previous == Some((eye, pulled)) {
+                        // Same pull as last frame.
+                        transform.bypass_change_detection().translation = pulled;
+                    } else {
+                        transform.translation = pulled;
+                    }
+                    occlusion.bypass_change_detection().pulled_in = Some((eye, pulled));
+                } else if previous.is_some() {
+                    // The camera was quietly moved back out, so propagate it.
+                    transform.set_changed();
+                }
+            }
+            OcclusionPolicy::Report => {
+                let entities: Vec<_> = blockers.iter().map(|hit| hit.entity).collect();
+                if entities != occlusion.occluded {
+                    occlusion.occluded.clone_from(&entities);
+                    events.send(OccludedEntities {
+                        camera: entity,
+                        entities,
+                    });
+                }
+            }
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{world_space::WorldSpaceLookTransform, LookTransformPlugin};
+    use bevy::{
+        hierarchy::BuildChildren,
+        time::{Fixed, Real, Time},
+    };
+
+    #[test]
+    fn test_pull_in_keeps_offsets_and_restores() {
+        let mut app = App::new();
+        app.add_event::<OccludedEntities>()
+            .add_systems(Update, (restore_pulled_in_system, occlusion_system).chain());
+        let look_transform = LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y);
+        // Offset from the `LookTransform`, like a drifting camera.
+        let offset = Transform::from_xyz(0.5, 0.0, 10.0);
+        let camera = app
+            .world_mut()
+            .spawn((look_transform, CameraOcclusion::default(), offset))
+            .id();
+        let blocker = app.world_mut().spawn_empty().id();
+        let set_hits = |app: &mut App, hits: Vec<OcclusionHit>| {
+            app.world_mut().get_mut::<OcclusionHits>(camera).unwrap().0 = hits;
+            app.update();
+            *app.world().get::<Transform>(camera).unwrap()
+        };
+
+        assert_eq!(set_hits(&mut app, Vec::new()), offset);
+        let hit = OcclusionHit {
+            entity: blocker,
+            distance: 5.2,
+        };
+        let pulled = set_hits(&mut app, vec![hit]);
+        assert!(pulled.translation.distance(Vec3::ZERO) < 5.0 + 1e-4);
+        assert!(pulled.translation.x > 0.0);
+        // Stays pulled in without stacking, even though nothing rewrites the `Transform`.
+        assert_eq!(set_hits(&mut app, vec![hit]), pulled);
+        assert_eq!(set_hits(&mut app, Vec::new()), offset);
+    }
+
+    #[test]
+    fn test_parented_world_space_camera_is_pulled_in_before_conversion() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<Time<Real>>()
+            .init_resource::<Time<Fixed>>()
+            .add_plugins((LookTransformPlugin, CameraOcclusionPlugin));
+        let parent = app
+            .world_mut()
+            .spawn(Transform::from_xyz(100.0, 0.0, 0.0))
+            .id();
+        let camera = app
+            .world_mut()
+            .spawn((
+                LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y),
+                Smoother::new(0.0),
+                WorldSpaceLookTransform::default(),
+                CameraOcclusion::default(),
+                Transform::default(),
+            ))
+            .set_parent(parent)
+            .id();
+        let blocker = app.world_mut().spawn_empty().id();
+        let set_hits = |app: &mut App, hits: Vec<OcclusionHit>| {
+            app.world_mut().get_mut::<OcclusionHits>(camera).unwrap().0 = hits;
+            app.update();
+            app.world().get::<Transform>(camera).unwrap().translation
+        };
+
+        assert!(set_hits(&mut app, Vec::new()).abs_diff_eq(Vec3::new(-100.0, 0.0, 10.0), 1e-4));
+        let hit = OcclusionHit {
+            entity: blocker,
+            distance: 5.2,
+        };
+        for _ in 0..2 {
+            let pulled = set_hits(&mut app, vec![hit]);
+            assert!(pulled.abs_diff_eq(Vec3::new(-100.0, 0.0, 5.0), 1e-4));
+        }
+        assert!(set_hits(&mut app, Vec::new()).abs_diff_eq(Vec3::new(-100.0, 0.0, 10.0), 1e-4));
+    }
+}
@@ -0,0 +1,270 @@
+use crate::{
+    clock::CameraTime,
+    controllers::{
+        active_controller_system,
+        fps::{self, FpsCameraController, FpsCameraPlugin, FpsWheelMode, MovementMode},
+        scroll_lines, ActiveController, AnyController, ControllerKind, InputRouting,
+        DEFAULT_PIXELS_PER_LINE,
+    },
+    LookTransform, Smoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    input::{mouse::MouseWheel, prelude::*},
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    render::camera::Projection,
+};
+
+use std::f32::consts::PI;
+
+/// A free camera for taking screenshots, entered and exited with [`PhotoModeEvent`]s.
+///
+/// Entering photo mode saves the camera's view, projection, and controllers, and flies it with an [`FpsCameraController`]
+/// in [`MovementMode::FreeFly`] instead. Q/E rolls, R levels the horizon, and the mouse wheel changes the field of view. The
+/// eye can't leave a sphere of [`PhotoModeSettings::radius`] around where it started. Exiting restores everything.
+///
+/// This adds the [`FpsCameraPlugin`] if it hasn't been added yet.
+#[derive(Default)]
+pub struct PhotoModePlugin;
+
+impl Plugin for PhotoModePlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FpsCameraPlugin>() {
+            app.add_plugins(FpsCameraPlugin::default());
+        }
+
+        app.init_resource::<PhotoModeSettings>()
+            .add_event::<PhotoModeEvent>()
+            .add_systems(
+                PreUpdate,
+                // Before the controllers react to the handover.
+                photo_mode_event_system.before(active_controller_system),
+            )
+            .add_systems(
+                Update,
+                (photo_mode_input_map, photo_mode_range_system).after(fps::control_system),
+            );
+    }
+}
+
+/// How the camera behaves in photo mode. Changes take effect the next time photo mode is entered.
+#[derive(Resource, Clone, Copy, Debug, Reflect)]
+#[reflect(Resource, Default, Debug)]
+pub struct PhotoModeSettings {
+    /// How far the eye may move from where photo mode was entered.
+    pub radius: f32,
+    /// Radians per pixel of mouse motion, for yaw (X) and pitch (Y).
+    pub mouse_rotate_sensitivity: Vec2,
+    /// The flying speed, in world units per second. This is usually slower than gameplay cameras, for framing shots.
+    pub translate_sensitivity: f32,
+    pub smoothing_weight: f32,
+    /// Radians per second of roll while Q or E is held.
+    pub roll_speed: f32,
+    /// Radians of field of view per line of mouse wheel scrolling.
+    pub fov_step: f32,
+    pub min_fov: f32,
+    pub max_fov: f32,
+}
+
+impl Default for PhotoModeSettings {
+    fn default() -> Self {
+        Self {
+            radius: 10.0,
+            mouse_rotate_sensitivity: Vec2::splat(0.0017),
+            translate_sensitivity: 1.0,
+            smoothing_weight: 0.9,
+            roll_speed: 0.5,
+            fov_step: PI / 90.0,
+            min_fov: PI / 36.0,
+            max_fov: PI * 2.0 / 3.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Event, PartialEq, Eq)]
+pub enum PhotoModeEvent {
+    Enter(Entity),
+    Exit(Entity),
+    /// Enters photo mode if the camera isn't in it, or else exits.
+    Toggle(Entity),
+}
+
+/// Marks a camera in photo mode, holding what to restore when it exits.
+#[derive(Component, Clone, Debug)]
+pub struct PhotoMode {
+    origin: Vec3,
+    transform: LookTransform,
+    projection: Option<Projection>,
+    lag_weight: Option<Vec3>,
+    fps: Option<FpsCameraController>,
+    active: Option<ActiveController>,
+    enabled: Vec<(ControllerKind, bool)>,
+}
+
+impl PhotoMode {
+    /// Where the eye was when photo mode was entered.
+    pub fn origin(&self) -> Vec3 {
+        self.origin
+    }
+}
+
+pub fn photo_mode_event_system(
+    mut commands: Commands,
+    mut events: EventReader<PhotoModeEvent>,
+    settings: Res<PhotoModeSettings>,
+    mut cameras: Query<(
+        &mut LookTransform,
+        Option<&mut Projection>,
+        Option<&PhotoMode>,
+        AnyController,
+    )>,
+    mut smoothers: Query<&mut Smoother>,
+) {
+    for event in events.read() {
+        let (entity, enter) = match *event {
+            PhotoModeEvent::Enter(entity) => (entity, true),
+            PhotoModeEvent::Exit(entity) => (entity, false),
+            PhotoModeEvent::Toggle(entity) => match cameras.get(entity) {
+                Ok((.., photo_mode, _)) => (entity, photo_mode.is_none()),
+                Err(_) => continue,
+            },
+        };
+        let Ok((mut transform, mut projection, photo_mode, mut controllers)) =
+            cameras.get_mut(entity)
+        else {
+            continue;
+        };
+        let smoother = smoothers.get_mut(entity).ok();
+
+        match (enter, photo_mode) {
+            (true, None) => {
+                let saved = PhotoMode {
+                    origin: transform.eye,
+                    transform: *transform,
+                    projection: projection.as_deref().cloned(),
+                    lag_weight: smoother.as_ref().map(|s| s.lag_weight()),
+                    fps: controllers.fps.as_deref().copied(),
+                    active: controllers.active.copied(),
+                    enabled: ControllerKind::BUILT_IN
+                        .into_iter()
+                        .filter_map(|kind| Some((kind, controllers.enabled_of(kind)?)))
+                        .collect(),
+                };
+                for kind in ControllerKind::BUILT_IN {
+                    controllers.set_enabled_of(kind, false);
+                }
+                if let Some(mut smoother) = smoother {
+                    smoother.set_lag_weight(settings.smoothing_weight);
+                }
+                let mut controller = FpsCameraController::default();
+                controller.mouse_rotate_sensitivity = settings.mouse_rotate_sensitivity;
+                controller.translate_sensitivity = settings.translate_sensitivity;
+                controller.movement_mode = MovementMode::FreeFly;
+                controller.wheel_mode = FpsWheelMode::Nothing;
+                controller.smoothing_weight = settings.smoothing_weight;
+                commands.entity(entity).insert((
+                    saved,
+                    controller,
+                    // Keeps the saved controllers from re-enabling or smoothing the camera.
+                    ActiveController(ControllerKind::Fps),
+                ));
+            }
+            (false, Some(saved)) => {
+                *transform = saved.transform;
+                if let (Some(projection), Some(saved_projection)) =
+                    (projection.as_deref_mut(), &saved.projection)
+                {
+                    *projection = saved_projection.clone();
+                }
+                for &(kind, enabled) in &saved.enabled {
+                    controllers.set_enabled_of(kind, enabled);
+                }
+                if let Some(mut smoother) = smoother {
+                    if let Some(lag_weight) = saved.lag_weight {
+                        smoother.set_lag_weight_per_axis(lag_weight);
+                    }
+                    // Cut straight back to the saved view.
+                    smoother.reset();
+                }
+
+                let mut entity_commands = commands.entity(entity);
+                entity_commands.remove::<PhotoMode>();
+                match saved.fps {
+                    Some(fps) => entity_commands.insert(fps),
+                    None => entity_commands.remove::<FpsCameraController>(),
+                };
+                match saved.active {
+                    Some(active) => entity_commands.insert(active),
+                    None => entity_commands.remove::<ActiveController>(),
+                };
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Q/E: roll, R: level the horizon, mouse wheel: field of view.
+pub fn photo_mode_input_map(
+    time: CameraTime,
+    settings: Res<PhotoModeSettings>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut mouse_wheel_reader: EventReader<MouseWheel>,
+    mut cameras: Query<(Entity, &mut LookTransform, Option<&mut Projection>), With<PhotoMode>>,
+    routing: InputRouting,
+) {
+    if routing.is_blocked() {
+        mouse_wheel_reader.clear();
+        return;
+    }
+    let lines: f32 = mouse_wheel_reader
+        .read()
+        .map(|event| scroll_lines(event, DEFAULT_PIXELS_PER_LINE).y)
+        .sum();
+
+    let mut roll_direction = 0.0;
+    if keyboard.pressed(KeyCode::KeyQ) {
+        roll_direction -= 1.0;
+    }
+    if keyboard.pressed(KeyCode::KeyE) {
+        roll_direction += 1.0;
+    }
+
+    for (entity, mut transform, projection) in cameras.iter_mut() {
+        let roll = roll_direction * settings.roll_speed * time.delta_secs(entity);
+        if keyboard.just_pressed(KeyCode::KeyR) && transform.up != Vec3::Y {
+            transform.up = Vec3::Y;
+        } else if roll != 0.0 {
+            if let Some(direction) = transform.look_direction() {
+                transform.up = Quat::from_axis_angle(direction, roll) * transform.up;
+            }
+        }
+
+        if lines != 0.0 {
+            if let Some(mut projection) = projection {
+                if let Projection::Perspective(perspective) = projection.as_mut() {
+                    perspective.fov = (perspective.fov - settings.fov_step * lines)
+                        .clamp(settings.min_fov, settings.max_fov);
+                }
+            }
+        }
+    }
+}
+
+/// Keeps cameras in photo mode within [`PhotoModeSettings::radius`] of where they entered it.
+pub fn photo_mode_range_system(
+    settings: Res<PhotoModeSettings>,
+    mut cameras: Query<(&PhotoMode, &mut LookTransform)>,
+) {
+    for (photo_mode, mut transform) in cameras.iter_mut() {
+        let offset = transform.eye - photo_mode.origin;
+        if offset.length() > settings.radius {
+            let correction = offset.clamp_length_max(settings.radius) - offset;
+            transform.eye += correction;
+            transform.target += correction;
+        }
+    }
+}
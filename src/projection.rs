@@ -0,0 +1,145 @@
+use crate::{LookTransform, LookTransformSet};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    reflect::Reflect,
+    render::camera::{
+        CameraUpdateSystem, OrthographicProjection, PerspectiveProjection, Projection, ScalingMode,
+    },
+    transform::components::Transform,
+};
+
+/// Handles [`ProjectionSwitchEvent`]s for cameras with a [`ProjectionSwitch`].
+#[derive(Default)]
+pub struct ProjectionSwitchPlugin;
+
+impl Plugin for ProjectionSwitchPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ProjectionSwitchEvent>().add_systems(
+            PostUpdate,
+            // After smoothing, so the framing matches what is actually on screen.
+            (projection_switch_event_system, orthographic_framing_system)
+                .chain()
+                .after(LookTransformSet::Sync)
+                .before(CameraUpdateSystem),
+        );
+    }
+}
+
+/// Lets a camera switch between perspective and orthographic projections without the framing jumping.
+///
+/// While orthographic, the projection's scale is derived from the distance to the `LookTransform` target, so the target
+/// appears the same size it would with the `perspective` projection. Controllers that zoom by changing that distance, like
+/// the orbit controller, keep zooming as usual, and switching back to perspective doesn't need to move the eye.
+#[derive(Clone, Component, Debug, Reflect)]
+#[reflect(Component, Debug)]
+pub struct ProjectionSwitch {
+    /// The projection to switch back to, whose `fov` also sets the orthographic framing. It's updated from the current
+    /// projection when switching to orthographic.
+    pub perspective: PerspectiveProjection,
+}
+
+impl ProjectionSwitch {
+    pub fn new(perspective: PerspectiveProjection) -> Self {
+        Self { perspective }
+    }
+}
+
+/// Switches the projection of a camera with a [`ProjectionSwitch`].
+#[derive(Clone, Copy, Debug, Event, PartialEq, Eq)]
+pub enum ProjectionSwitchEvent {
+    Perspective(Entity),
+    Orthographic(Entity),
+    /// Switches to whichever projection the camera isn't using.
+    Toggle(Entity),
+}
+
+/// The orthographic scale that makes a plane `distance` in front of the eye look the same as it does through `perspective`,
+/// for a [`ScalingMode::FixedVertical`] viewport height of 2.
+pub fn orthographic_scale(perspective: &PerspectiveProjection, distance: f32) -> f32 {
+    distance.max(f32::EPSILON) * (0.5 * perspective.fov).tan()
+}
+
+/// An orthographic projection that frames a plane `distance` in front of the eye the same as `perspective` does.
+pub fn matching_orthographic(
+    perspective: &PerspectiveProjection,
+    distance: f32,
+) -> OrthographicProjection {
+    OrthographicProjection {
+        scaling_mode: ScalingMode::FixedVertical {
+            viewport_height: 2.0,
+        },
+        scale: orthographic_scale(perspective, distance),
+        far: perspective.far,
+        ..OrthographicProjection::default_3d()
+    }
+}
+
+/// The distance from the eye to the plane through the target, along the view direction.
+fn target_depth(look_transform: &LookTransform, transform: &Transform) -> f32 {
+    (look_transform.target - transform.translation).dot(*transform.forward())
+}
+
+pub fn projection_switch_event_system(
+    mut events: EventReader<ProjectionSwitchEvent>,
+    mut cameras: Query<(
+        &mut ProjectionSwitch,
+        &LookTransform,
+        &Transform,
+        &mut Projection,
+    )>,
+) {
+    for event in events.read() {
+        let (entity, orthographic) = match *event {
+            ProjectionSwitchEvent::Perspective(entity) => (entity, false),
+            ProjectionSwitchEvent::Orthographic(entity) => (entity, true),
+            ProjectionSwitchEvent::Toggle(entity) => match cameras.get(entity) {
+                Ok((.., projection)) => {
+                    (entity, !matches!(projection, Projection::Orthographic(_)))
+                }
+                Err(_) => continue,
+            },
+        };
+        let Ok((mut switch, look_transform, transform, mut projection)) = cameras.get_mut(entity)
+        else {
+            continue;
+        };
+
+        match (orthographic, projection.as_ref()) {
+            (true, Projection::Orthographic(_)) | (false, Projection::Perspective(_)) => (),
+            (true, projection_ref) => {
+                // Remember any changes made to the perspective projection, like a dolly zoom, to switch back to.
+                if let Projection::Perspective(perspective) = projection_ref {
+                    switch.perspective = perspective.clone();
+                }
+                let distance = target_depth(look_transform, transform);
+                *projection = matching_orthographic(&switch.perspective, distance).into();
+            }
+            (false, _) => *projection = switch.perspective.clone().into(),
+        }
+    }
+}
+
+/// Keeps the scale of orthographic [`ProjectionSwitch`] cameras in sync with their distance to the target.
+pub fn orthographic_framing_system(
+    mut cameras: Query<(
+        &ProjectionSwitch,
+        &LookTransform,
+        &Transform,
+        &mut Projection,
+    )>,
+) {
+    for (switch, look_transform, transform, mut projection) in cameras.iter_mut() {
+        let Projection::Orthographic(ortho) = projection.as_ref() else {
+            continue;
+        };
+        let scale =
+            orthographic_scale(&switch.perspective, target_depth(look_transform, transform));
+        if ortho.scale != scale {
+            if let Projection::Orthographic(ortho) = projection.as_mut() {
+                ortho.scale = scale;
+            }
+        }
+    }
+}
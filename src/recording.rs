@@ -0,0 +1,376 @@
+use crate::{
+    clock::CameraTime,
+    controllers::{CameraControllerStatus, TargetedControlEvent},
+};
+
+use bevy::{app::prelude::*, ecs::prelude::*, reflect::Reflect};
+
+use std::marker::PhantomData;
+
+/// Records and replays a controller's `ControlEvent`s, and the [`TargetedControlEvent`]s of them, with a
+/// [`ControlEventRecorder`], e.g. `ControlEventRecordingPlugin::<orbit::ControlEvent>::default()`.
+///
+/// While a replay is running, the built-in `default_input_map` systems ignore live input.
+pub struct ControlEventRecordingPlugin<E> {
+    marker: PhantomData<fn() -> E>,
+}
+
+impl<E> Default for ControlEventRecordingPlugin<E> {
+    fn default() -> Self {
+        Self {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<E: Event + Clone> Plugin for ControlEventRecordingPlugin<E> {
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<ControlEventReplayActive>() {
+            app.init_resource::<ControlEventReplayActive>().add_systems(
+                PreUpdate,
+                reset_replay_active_system.before(ControlEventReplaySet),
+            );
+        }
+
+        app.add_event::<E>()
+            .add_event::<TargetedControlEvent<E>>()
+            .init_resource::<ControlEventRecorder<E>>()
+            .add_systems(PreUpdate, replay_system::<E>.in_set(ControlEventReplaySet))
+            // Recording after `Update` sees every event from the input maps in the frame they were sent.
+            .add_systems(PostUpdate, record_system::<E>);
+    }
+}
+
+/// Contains the systems that send replayed events, in `PreUpdate`.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ControlEventReplaySet;
+
+/// Whether any [`ControlEventRecorder`] is replaying this frame.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub struct ControlEventReplayActive(pub bool);
+
+/// A recorded sequence of control events, which can be serialized with the `serde` feature.
+///
+/// Replaying by [`ReplayTiming::Frames`] is only deterministic if each frame also takes the same time as it did while
+/// recording. The recorded `frame_times` can be fed to `TimeUpdateStrategy::ManualDuration` for that.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ControlEventLog<E> {
+    pub events: Vec<RecordedControlEvent<E>>,
+    /// The delta time of each recorded frame, in seconds.
+    pub frame_times: Vec<f32>,
+}
+
+impl<E> Default for ControlEventLog<E> {
+    fn default() -> Self {
+        Self {
+            events: Vec::new(),
+            frame_times: Vec::new(),
+        }
+    }
+}
+
+impl<E> ControlEventLog<E> {
+    /// The total recorded time, in seconds.
+    pub fn duration(&self) -> f32 {
+        self.frame_times.iter().sum()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct RecordedControlEvent<E> {
+    /// The frame it was sent in, counting from the start of the recording.
+    pub frame: u32,
+    /// The time since the start of the recording, in seconds, including this frame.
+    pub time: f32,
+    /// The camera of a [`TargetedControlEvent`], which it's replayed to. `None` for plain events.
+    ///
+    /// Entities are only valid in the world they were recorded in, so a log that is replayed after a restart needs its
+    /// cameras mapped to the new ones.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub camera: Option<Entity>,
+    pub event: E,
+}
+
+/// When a replay sends each [`RecordedControlEvent`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum ReplayTiming {
+    /// In the same frame (counting from the start) that it was recorded in.
+    #[default]
+    Frames,
+    /// In the first frame at or after the time it was recorded at, for replays with a different frame rate.
+    Time,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum RecorderState {
+    #[default]
+    Idle,
+    Recording,
+    Replaying(ReplayTiming),
+}
+
+/// Records the `E` events, and the [`TargetedControlEvent`]s of them, sent by any source, or replays a
+/// [`ControlEventLog`] of them.
+#[derive(Resource)]
+pub struct ControlEventRecorder<E> {
+    state: RecorderState,
+    log: ControlEventLog<E>,
+    frame: u32,
+    elapsed: f32,
+    next_event: usize,
+}
+
+impl<E> Default for ControlEventRecorder<E> {
+    fn default() -> Self {
+        Self {
+            state: RecorderState::Idle,
+            log: ControlEventLog::default(),
+            frame: 0,
+            elapsed: 0.0,
+            next_event: 0,
+        }
+    }
+}
+
+impl<E> ControlEventRecorder<E> {
+    /// Starts a new recording, discarding the current log.
+    pub fn start_recording(&mut self) {
+        self.reset(RecorderState::Recording, ControlEventLog::default());
+    }
+
+    /// Replays `log` from the start, replacing the current log.
+    pub fn replay(&mut self, log: ControlEventLog<E>, timing: ReplayTiming) {
+        self.reset(RecorderState::Replaying(timing), log);
+    }
+
+    /// Stops recording or replaying. The log is kept.
+    pub fn stop(&mut self) {
+        self.state = RecorderState::Idle;
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.state == RecorderState::Recording
+    }
+
+    pub fn is_replaying(&self) -> bool {
+        matches!(self.state, RecorderState::Replaying(_))
+    }
+
+    pub fn log(&self) -> &ControlEventLog<E> {
+        &self.log
+    }
+
+    /// Stops and returns the log.
+    pub fn take_log(&mut self) -> ControlEventLog<E> {
+        self.stop();
+        std::mem::take(&mut self.log)
+    }
+
+    fn reset(&mut self, state: RecorderState, log: ControlEventLog<E>) {
+        self.state = state;
+        self.log = log;
+        self.frame = 0;
+        self.elapsed = 0.0;
+        self.next_event = 0;
+    }
+}
+
+fn reset_replay_active_system(mut active: ResMut<ControlEventReplayActive>) {
+    active.set_if_neq(ControlEventReplayActive(false));
+}
+
+/// The frame time on the [`CameraClock`](crate::clock::CameraClock) of the camera that takes input, so a recording keeps
+/// the time of the camera it drives, even while the game is paused.
+fn input_camera_delta_secs(time: &CameraTime, status: Option<&CameraControllerStatus>) -> f32 {
+    time.delta_secs_or_default(status.and_then(|s| s.entity))
+}
+
+pub fn record_system<E: Event + Clone>(
+    time: CameraTime,
+    status: Option<Res<CameraControllerStatus>>,
+    mut recorder: ResMut<ControlEventRecorder<E>>,
+    mut events: EventReader<E>,
+    mut targeted_events: EventReader<TargetedControlEvent<E>>,
+) {
+    // Always drain the readers, so a new recording doesn't pick up stale events.
+    if !recorder.is_recording() {
+        events.clear();
+        targeted_events.clear();
+        return;
+    }
+
+    let recorder = recorder.as_mut();
+    let dt = input_camera_delta_secs(&time, status.as_deref());
+    recorder.elapsed += dt;
+    recorder.log.frame_times.push(dt);
+    let plain = events.read().map(|event| (None, event));
+    let targeted = targeted_events
+        .read()
+        .map(|targeted| (Some(targeted.camera), &targeted.event));
+    for (camera, event) in plain.chain(targeted) {
+        recorder.log.events.push(RecordedControlEvent {
+            frame: recorder.frame,
+            time: recorder.elapsed,
+            camera,
+            event: event.clone(),
+        });
+    }
+    recorder.frame += 1;
+}
+
+pub fn replay_system<E: Event + Clone>(
+    time: CameraTime,
+    status: Option<Res<CameraControllerStatus>>,
+    mut recorder: ResMut<ControlEventRecorder<E>>,
+    mut events: EventWriter<E>,
+    mut targeted_events: EventWriter<TargetedControlEvent<E>>,
+    mut active: ResMut<ControlEventReplayActive>,
+) {
+    let RecorderState::Replaying(timing) = recorder.state else {
+        return;
+    };
+    active.0 = true;
+
+    let recorder = recorder.as_mut();
+    recorder.elapsed += input_camera_delta_secs(&time, status.as_deref());
+    while let Some(recorded) = recorder.log.events.get(recorder.next_event) {
+        let due = match timing {
+            ReplayTiming::Frames => recorded.frame <= recorder.frame,
+            ReplayTiming::Time => recorded.time <= recorder.elapsed,
+        };
+        if !due {
+            break;
+        }
+        match recorded.camera {
+            Some(camera) => {
+                targeted_events.send(TargetedControlEvent::new(camera, recorded.event.clone()));
+            }
+            None => {
+                events.send(recorded.event.clone());
+            }
+        }
+        recorder.next_event += 1;
+    }
+    recorder.frame += 1;
+
+    let finished = match timing {
+        ReplayTiming::Frames => recorder.frame as usize >= recorder.log.frame_times.len(),
+        ReplayTiming::Time => recorder.elapsed >= recorder.log.duration(),
+    };
+    if finished && recorder.next_event >= recorder.log.events.len() {
+        recorder.stop();
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::CameraClock;
+    use bevy::time::{Real, Time};
+    use std::time::Duration;
+
+    #[derive(Clone, Debug, Event, PartialEq)]
+    struct TestEvent(u32);
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<Time<Real>>()
+            .add_event::<TestEvent>()
+            .add_plugins(ControlEventRecordingPlugin::<TestEvent>::default());
+        app
+    }
+
+    fn recorder(app: &mut App) -> Mut<ControlEventRecorder<TestEvent>> {
+        app.world_mut()
+            .resource_mut::<ControlEventRecorder<TestEvent>>()
+    }
+
+    #[test]
+    fn test_replay_sends_events_in_recorded_frames() {
+        let mut app = test_app();
+        recorder(&mut app).start_recording();
+        app.world_mut().send_event(TestEvent(0));
+        app.update();
+        app.update();
+        app.world_mut().send_event(TestEvent(2));
+        app.update();
+        let log = recorder(&mut app).take_log();
+
+        let frames: Vec<_> = log.events.iter().map(|e| (e.frame, e.event.0)).collect();
+        assert_eq!(frames, [(0, 0), (2, 2)]);
+        assert_eq!(log.frame_times.len(), 3);
+
+        let mut app = test_app();
+        recorder(&mut app).replay(log, ReplayTiming::Frames);
+        let mut replayed = Vec::new();
+        for _ in 0..3 {
+            app.update();
+            assert!(app.world().resource::<ControlEventReplayActive>().0);
+            let events = app.world().resource::<Events<TestEvent>>();
+            replayed.push(
+                events
+                    .iter_current_update_events()
+                    .cloned()
+                    .collect::<Vec<_>>(),
+            );
+        }
+        assert_eq!(replayed, [vec![TestEvent(0)], vec![], vec![TestEvent(2)]]);
+        assert!(!recorder(&mut app).is_replaying());
+    }
+
+    #[test]
+    fn test_replay_sends_targeted_events_to_their_cameras() {
+        let mut app = test_app();
+        let camera = app.world_mut().spawn_empty().id();
+        recorder(&mut app).start_recording();
+        app.world_mut().send_event(TestEvent(0));
+        app.world_mut()
+            .send_event(TargetedControlEvent::new(camera, TestEvent(1)));
+        app.update();
+        let log = recorder(&mut app).take_log();
+
+        let recorded: Vec<_> = log.events.iter().map(|e| (e.camera, e.event.0)).collect();
+        assert_eq!(recorded, [(None, 0), (Some(camera), 1)]);
+
+        let mut app = test_app();
+        recorder(&mut app).replay(log, ReplayTiming::Frames);
+        app.update();
+        let events = app.world().resource::<Events<TestEvent>>();
+        let plain: Vec<_> = events.iter_current_update_events().cloned().collect();
+        assert_eq!(plain, [TestEvent(0)]);
+        let events = app
+            .world()
+            .resource::<Events<TargetedControlEvent<TestEvent>>>();
+        let targeted: Vec<_> = events.iter_current_update_events().cloned().collect();
+        assert_eq!(targeted, [TargetedControlEvent::new(camera, TestEvent(1))]);
+    }
+
+    #[test]
+    fn test_recording_keeps_the_time_of_the_input_camera() {
+        let mut app = test_app();
+        let camera = app.world_mut().spawn(CameraClock::Real).id();
+        app.insert_resource(CameraControllerStatus {
+            entity: Some(camera),
+            ..Default::default()
+        });
+        recorder(&mut app).start_recording();
+        // The game is paused, so only real time passes.
+        app.world_mut()
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        app.update();
+
+        let log = recorder(&mut app).take_log();
+        assert_eq!(log.frame_times, [0.1]);
+    }
+}
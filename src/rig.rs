@@ -0,0 +1,696 @@
+use crate::{
+    clock::CameraTime,
+    constraints::Constraints,
+    controllers::{
+        fps::{FpsCameraController, HeadBob},
+        isometric::IsometricCameraController,
+        maya::MayaCameraController,
+        orbit::OrbitCameraController,
+        pancam2d::PanCam2dController,
+        unreal::UnrealCameraController,
+    },
+    ease::Ease,
+    occlusion::CameraOcclusion,
+    shake::CameraShake,
+    EyeAt, LookAt, LookTransform, Smoother,
+};
+
+use bevy::{
+    ecs::{prelude::*, system::EntityCommands},
+    hierarchy::Parent,
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    render::camera::{PerspectiveProjection, Projection},
+    transform::components::Transform,
+};
+
+use std::fmt;
+
+/// A validated description of a camera's components, created with [`CameraRig::builder`].
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use smooth_bevy_cameras::{controllers::orbit::OrbitCameraController, rig::CameraRig, LookAt};
+/// fn spawn_camera(mut commands: Commands, player: Entity) {
+///     let rig = CameraRig::builder()
+///         .orbit(OrbitCameraController::default())
+///         .eye(Vec3::new(0.0, 2.0, 5.0))
+///         .look_at(LookAt::new(player))
+///         .build()
+///         .unwrap();
+///     rig.insert_into(&mut commands.spawn(Camera3d::default()));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CameraRig {
+    controller: Option<RigController>,
+    look_transform: LookTransform,
+    smoother: Smoother,
+    look_at: Option<LookAt>,
+    eye_at: Option<EyeAt>,
+    head_bob: Option<HeadBob>,
+    bounds: Option<Constraints>,
+    collision: Option<CameraOcclusion>,
+    shake: Option<CameraShake>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum RigController {
+    Fps(FpsCameraController),
+    Isometric(IsometricCameraController),
+    Maya(MayaCameraController),
+    Orbit(OrbitCameraController),
+    PanCam2d(PanCam2dController),
+    Unreal(UnrealCameraController),
+}
+
+impl RigController {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Fps(_) => "FPS",
+            Self::Isometric(_) => "isometric",
+            Self::Maya(_) => "Maya",
+            Self::Orbit(_) => "orbit",
+            Self::PanCam2d(_) => "2D pan",
+            Self::Unreal(_) => "Unreal",
+        }
+    }
+
+    fn smoothing_weight(&self) -> f32 {
+        match self {
+            Self::Fps(c) => c.smoothing_weight,
+            Self::Isometric(c) => c.smoothing_weight,
+            Self::Maya(c) => c.smoothing_weight,
+            Self::Orbit(c) => c.smoothing_weight,
+            Self::PanCam2d(c) => c.smoothing_weight,
+            Self::Unreal(c) => c.smoothing_weight,
+        }
+    }
+
+    /// Whether the controller moves the target on its own, rather than orbiting around it.
+    fn drives_target(&self) -> bool {
+        !matches!(self, Self::Isometric(_) | Self::Maya(_) | Self::Orbit(_))
+    }
+}
+
+impl CameraRig {
+    pub fn builder() -> CameraRigBuilder {
+        CameraRigBuilder::default()
+    }
+
+    /// Inserts all of the rig's components, including a `Transform` that is consistent with the `LookTransform`.
+    pub fn insert_into(self, entity: &mut EntityCommands) {
+        // A followed target may not be known yet, in which case the eye and target can coincide.
+        let transform = match self.look_transform.look_direction() {
+            Some(_) => Transform::from(self.look_transform),
+            None => Transform::from_translation(self.look_transform.eye),
+        };
+        entity.insert((self.look_transform, self.smoother, transform));
+        match self.controller {
+            Some(RigController::Fps(c)) => {
+                entity.insert(c);
+            }
+            Some(RigController::Isometric(c)) => {
+                entity.insert(c);
+            }
+            Some(RigController::Maya(c)) => {
+                entity.insert(c);
+            }
+            Some(RigController::Orbit(c)) => {
+                entity.insert(c);
+            }
+            Some(RigController::PanCam2d(c)) => {
+                entity.insert(c);
+            }
+            Some(RigController::Unreal(c)) => {
+                entity.insert(c);
+            }
+            None => (),
+        }
+        if let Some(look_at) = self.look_at {
+            entity.insert(look_at);
+        }
+        if let Some(eye_at) = self.eye_at {
+            entity.insert(eye_at);
+        }
+        if let Some(head_bob) = self.head_bob {
+            entity.insert(head_bob);
+        }
+        if let Some(bounds) = self.bounds {
+            entity.insert(bounds);
+        }
+        if let Some(collision) = self.collision {
+            entity.insert(collision);
+        }
+        if let Some(shake) = self.shake {
+            entity.insert(shake);
+        }
+    }
+}
+
+/// Assembles a [`CameraRig`]. Incompatible combinations are reported by [`CameraRigBuilder::build`].
+#[derive(Debug)]
+pub struct CameraRigBuilder {
+    controllers: Vec<RigController>,
+    eye: Vec3,
+    target: Vec3,
+    up: Vec3,
+    smoother: Option<Smoother>,
+    look_at: Option<LookAt>,
+    eye_at: Option<EyeAt>,
+    head_bob: Option<HeadBob>,
+    bounds: Option<Constraints>,
+    collision: Option<CameraOcclusion>,
+    shake: Option<CameraShake>,
+}
+
+impl Default for CameraRigBuilder {
+    fn default() -> Self {
+        Self {
+            controllers: Vec::new(),
+            eye: Vec3::new(0.0, 0.0, 1.0),
+            target: Vec3::ZERO,
+            up: Vec3::Y,
+            smoother: None,
+            look_at: None,
+            eye_at: None,
+            head_bob: None,
+            bounds: None,
+            collision: None,
+            shake: None,
+        }
+    }
+}
+
+impl CameraRigBuilder {
+    pub fn fps(mut self, controller: FpsCameraController) -> Self {
+        self.controllers.push(RigController::Fps(controller));
+        self
+    }
+
+    pub fn isometric(mut self, controller: IsometricCameraController) -> Self {
+        self.controllers.push(RigController::Isometric(controller));
+        self
+    }
+
+    pub fn maya(mut self, controller: MayaCameraController) -> Self {
+        self.controllers.push(RigController::Maya(controller));
+        self
+    }
+
+    pub fn orbit(mut self, controller: OrbitCameraController) -> Self {
+        self.controllers.push(RigController::Orbit(controller));
+        self
+    }
+
+    pub fn pancam2d(mut self, controller: PanCam2dController) -> Self {
+        self.controllers.push(RigController::PanCam2d(controller));
+        self
+    }
+
+    pub fn unreal(mut self, controller: UnrealCameraController) -> Self {
+        self.controllers.push(RigController::Unreal(controller));
+        self
+    }
+
+    pub fn eye(mut self, eye: Vec3) -> Self {
+        self.eye = eye;
+        self
+    }
+
+    pub fn target(mut self, target: Vec3) -> Self {
+        self.target = target;
+        self
+    }
+
+    pub fn up(mut self, up: Vec3) -> Self {
+        self.up = up;
+        self
+    }
+
+    /// Overrides the `Smoother` that is otherwise created from the controller's `smoothing_weight`.
+    pub fn smoother(mut self, smoother: Smoother) -> Self {
+        self.smoother = Some(smoother);
+        self
+    }
+
+    pub fn look_at(mut self, look_at: LookAt) -> Self {
+        self.look_at = Some(look_at);
+        self
+    }
+
+    pub fn eye_at(mut self, eye_at: EyeAt) -> Self {
+        self.eye_at = Some(eye_at);
+        self
+    }
+
+    pub fn with_head_bob(mut self, head_bob: HeadBob) -> Self {
+        self.head_bob = Some(head_bob);
+        self
+    }
+
+    /// Keeps the camera within `bounds`, e.g. `PitchLimits` or `AboveGround`, whichever controller moves it.
+    pub fn with_bounds(mut self, bounds: Constraints) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Keeps scenery from blocking the view, once the app fills in the camera's
+    /// [`OcclusionHits`](crate::occlusion::OcclusionHits).
+    pub fn with_collision(mut self, collision: CameraOcclusion) -> Self {
+        self.collision = Some(collision);
+        self
+    }
+
+    pub fn with_shake(mut self, shake: CameraShake) -> Self {
+        self.shake = Some(shake);
+        self
+    }
+
+    pub fn build(self) -> Result<CameraRig, CameraRigError> {
+        if self.controllers.len() > 1 {
+            return Err(CameraRigError::MultipleControllers);
+        }
+        let controller = self.controllers.first().copied();
+
+        // Without a followed target, the eye and target must be distinct to define a look direction.
+        if self.look_at.is_none() && self.eye_at.is_none() && self.eye == self.target {
+            return Err(CameraRigError::DegenerateLookTransform);
+        }
+        if let Some(controller) = controller {
+            if self.eye_at.is_some() {
+                return Err(CameraRigError::ConflictingBinding {
+                    binding: "EyeAt",
+                    controller: controller.name(),
+                });
+            }
+            if self.look_at.is_some() && controller.drives_target() {
+                return Err(CameraRigError::ConflictingBinding {
+                    binding: "LookAt",
+                    controller: controller.name(),
+                });
+            }
+        }
+        if self.head_bob.is_some() && !matches!(controller, Some(RigController::Fps(_))) {
+            return Err(CameraRigError::HeadBobRequiresFps);
+        }
+
+        let smoother = self
+            .smoother
+            .unwrap_or_else(|| Smoother::new(controller.map_or(0.9, |c| c.smoothing_weight())));
+
+        Ok(CameraRig {
+            controller,
+            look_transform: LookTransform::new(self.eye, self.target, self.up),
+            smoother,
+            look_at: self.look_at,
+            eye_at: self.eye_at,
+            head_bob: self.head_bob,
+            bounds: self.bounds,
+            collision: self.collision,
+            shake: self.shake,
+        })
+    }
+}
+
+/// Why a [`CameraRigBuilder`] could not build a [`CameraRig`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CameraRigError {
+    /// Only one controller can drive a rig.
+    MultipleControllers,
+    /// The eye and target are the same point, so there is no look direction.
+    DegenerateLookTransform,
+    /// An entity binding would overwrite the part of the `LookTransform` that the controller drives.
+    ConflictingBinding {
+        binding: &'static str,
+        controller: &'static str,
+    },
+    /// `HeadBob` only works with the FPS controller.
+    HeadBobRequiresFps,
+}
+
+impl fmt::Display for CameraRigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MultipleControllers => write!(f, "a camera rig can only have one controller"),
+            Self::DegenerateLookTransform => {
+                write!(f, "the eye and target of a camera rig must be different")
+            }
+            Self::ConflictingBinding {
+                binding,
+                controller,
+            } => write!(
+                f,
+                "{binding} conflicts with the {controller} controller, which drives the same point"
+            ),
+            Self::HeadBobRequiresFps => write!(f, "HeadBob requires the FPS controller"),
+        }
+    }
+}
+
+impl std::error::Error for CameraRigError {}
+
+/// Places a child camera relative to a parent "rig" entity that owns the `LookTransform`, e.g. over the shoulder of a
+/// third-person rig, or at the end of a boom.
+///
+/// The rig is controlled and smoothed like any other camera, and the offset is applied to the smoothed view afterward, so
+/// the child keeps its position on screen rather than lagging behind. The child only needs a `Transform` and the `Camera`:
+/// giving it its own `LookTransform` would make it fight the rig, since `LookTransform`s are synchronized in world space.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[require(Transform)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct RigOffset {
+    /// The camera position relative to the rig's eye, along its right (X), up (Y), and backward (Z) axes.
+    pub offset: Vec3,
+    /// Turns the camera toward the rig's target, instead of looking parallel to the rig, like a boom camera.
+    pub look_at_target: bool,
+    /// With `look_at_target`, where the target appears in the viewport, from `(-1, -1)` at the bottom left to `(1, 1)` at
+    /// the top right. This needs a perspective `Projection` on the camera, and is ignored otherwise.
+    pub anchor: Vec2,
+}
+
+impl Default for RigOffset {
+    fn default() -> Self {
+        Self::new(Vec3::ZERO)
+    }
+}
+
+impl RigOffset {
+    pub fn new(offset: Vec3) -> Self {
+        Self {
+            offset,
+            look_at_target: false,
+            anchor: Vec2::ZERO,
+        }
+    }
+
+    /// Over the right shoulder, `side` units to the right and `height` units up, looking parallel to the rig.
+    pub fn shoulder(side: f32, height: f32) -> Self {
+        Self::new(Vec3::new(side, height, 0.0))
+    }
+
+    pub fn with_look_at_target(mut self, look_at_target: bool) -> Self {
+        self.look_at_target = look_at_target;
+        self
+    }
+
+    /// Looks at the target and frames it at `anchor` in the viewport.
+    pub fn with_anchor(mut self, anchor: Vec2) -> Self {
+        self.look_at_target = true;
+        self.anchor = anchor;
+        self
+    }
+
+    /// The child camera's `Transform`, relative to a rig whose target is `radius` units ahead.
+    pub fn local_transform(
+        &self,
+        radius: f32,
+        projection: Option<&PerspectiveProjection>,
+    ) -> Transform {
+        let transform = Transform::from_translation(self.offset);
+        let direction = Vec3::new(0.0, 0.0, -radius) - self.offset;
+        if !self.look_at_target || direction.length_squared() <= f32::EPSILON {
+            return transform;
+        }
+        let looking = transform.looking_to(direction, Vec3::Y);
+        let Some(projection) = projection.filter(|_| self.anchor != Vec2::ZERO) else {
+            return looking;
+        };
+
+        // Turn away from the target until it's at the anchor, by the angle between the view direction and the anchor.
+        let half_height = (0.5 * projection.fov).tan();
+        let anchor_direction = Vec3::new(
+            self.anchor.x * half_height * projection.aspect_ratio,
+            self.anchor.y * half_height,
+            -1.0,
+        );
+        looking.with_rotation(
+            looking.rotation * Quat::from_rotation_arc(anchor_direction.normalize(), Vec3::NEG_Z),
+        )
+    }
+}
+
+/// Moves a [`RigOffset`] camera over the right or left shoulder of its rig, with a smooth swap between the two. The target
+/// framing `anchor` is mirrored along with the camera.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[require(RigOffset)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct ShoulderOffset {
+    /// How far to the side of the rig's eye the camera is, in world units.
+    pub side: f32,
+    pub height: f32,
+    /// How far behind the rig's eye the camera is, in world units.
+    pub distance: f32,
+    /// Where the rig's target appears in the viewport over the right shoulder, as for [`RigOffset::anchor`].
+    pub anchor: Vec2,
+    /// How long [`ShoulderOffset::swap_shoulder`] takes to move the camera across, in seconds.
+    pub swap_duration: f32,
+    pub swap_easing: Ease,
+    right: bool,
+    /// From `0.0` over the left shoulder to `1.0` over the right, before easing.
+    progress: f32,
+}
+
+impl Default for ShoulderOffset {
+    fn default() -> Self {
+        Self::new(0.6, 0.3)
+    }
+}
+
+impl ShoulderOffset {
+    /// Over the right shoulder.
+    pub fn new(side: f32, height: f32) -> Self {
+        Self {
+            side,
+            height,
+            distance: 0.0,
+            anchor: Vec2::ZERO,
+            swap_duration: 0.3,
+            swap_easing: Ease::default(),
+            right: true,
+            progress: 1.0,
+        }
+    }
+
+    pub fn with_distance(mut self, distance: f32) -> Self {
+        self.distance = distance;
+        self
+    }
+
+    pub fn with_anchor(mut self, anchor: Vec2) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Starts right over the left shoulder, without a swap.
+    pub fn left(mut self) -> Self {
+        self.right = false;
+        self.progress = 0.0;
+        self
+    }
+
+    /// Moves the camera to the other shoulder over `swap_duration`. Swapping again mid-swap turns back from where the
+    /// camera is.
+    pub fn swap_shoulder(&mut self) {
+        self.right = !self.right;
+    }
+
+    pub fn set_right_shoulder(&mut self, right: bool) {
+        self.right = right;
+    }
+
+    /// The shoulder that the camera is over, or is swapping to.
+    pub fn is_right_shoulder(&self) -> bool {
+        self.right
+    }
+
+    pub fn is_swapping(&self) -> bool {
+        self.progress != if self.right { 1.0 } else { 0.0 }
+    }
+
+    /// From `-1.0` over the left shoulder to `1.0` over the right, eased.
+    fn side_factor(&self) -> f32 {
+        2.0 * self.swap_easing.sample(self.progress) - 1.0
+    }
+
+    fn rig_offset(&self) -> RigOffset {
+        let side = self.side_factor();
+        RigOffset::new(Vec3::new(side * self.side, self.height, self.distance))
+            .with_anchor(Vec2::new(side * self.anchor.x, self.anchor.y))
+    }
+}
+
+/// Advances [`ShoulderOffset`] swaps and writes them into the camera's [`RigOffset`].
+pub fn shoulder_offset_system(
+    time: CameraTime,
+    mut cameras: Query<(Entity, &mut ShoulderOffset, &mut RigOffset)>,
+) {
+    for (entity, mut shoulder, mut rig_offset) in cameras.iter_mut() {
+        if shoulder.is_swapping() {
+            let goal = if shoulder.right { 1.0 } else { 0.0 };
+            let step = if shoulder.swap_duration > 0.0 {
+                time.delta_secs(entity) / shoulder.swap_duration
+            } else {
+                1.0
+            };
+            let progress = shoulder.progress;
+            shoulder.progress = progress + (goal - progress).clamp(-step, step);
+        }
+        rig_offset.set_if_neq(shoulder.rig_offset());
+    }
+}
+
+/// Writes the `Transform` of each [`RigOffset`] camera from its parent rig's smoothed `LookTransform`.
+pub fn rig_offset_system(
+    rigs: Query<(&LookTransform, Option<&Smoother>)>,
+    mut cameras: Query<(&RigOffset, &Parent, &mut Transform, Option<&Projection>)>,
+) {
+    for (rig_offset, parent, mut transform, projection) in cameras.iter_mut() {
+        let Ok((look_transform, smoother)) = rigs.get(parent.get()) else {
+            continue;
+        };
+        let radius = smoother
+            .filter(|s| s.is_enabled())
+            .and_then(Smoother::smoothed)
+            .unwrap_or(*look_transform)
+            .radius();
+        let perspective = match projection {
+            Some(Projection::Perspective(perspective)) => Some(perspective),
+            _ => None,
+        };
+        // Leave an unchanged `Transform` alone, so that it doesn't need to be propagated.
+        transform.set_if_neq(rig_offset.local_transform(radius, perspective));
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints::{ConstraintAnchor, PitchLimits};
+
+    fn build_error(builder: CameraRigBuilder) -> CameraRigError {
+        builder.build().unwrap_err()
+    }
+
+    #[test]
+    fn test_rig_rejects_multiple_controllers() {
+        let builder = CameraRig::builder()
+            .orbit(OrbitCameraController::default())
+            .maya(MayaCameraController::default());
+        assert_eq!(build_error(builder), CameraRigError::MultipleControllers);
+    }
+
+    #[test]
+    fn test_rig_rejects_degenerate_look_transform() {
+        let builder = CameraRig::builder().eye(Vec3::ONE).target(Vec3::ONE);
+        assert_eq!(
+            build_error(builder),
+            CameraRigError::DegenerateLookTransform
+        );
+        // A followed target can fill it in later.
+        let entity = Entity::from_raw(0);
+        let builder = CameraRig::builder()
+            .eye(Vec3::ONE)
+            .target(Vec3::ONE)
+            .look_at(LookAt::new(entity));
+        assert!(builder.build().is_ok());
+    }
+
+    #[test]
+    fn test_rig_rejects_bindings_that_fight_the_controller() {
+        let entity = Entity::from_raw(0);
+        let builder = CameraRig::builder()
+            .fps(FpsCameraController::default())
+            .look_at(LookAt::new(entity));
+        assert_eq!(
+            build_error(builder),
+            CameraRigError::ConflictingBinding {
+                binding: "LookAt",
+                controller: "FPS",
+            }
+        );
+        let builder = CameraRig::builder()
+            .isometric(IsometricCameraController::default())
+            .eye_at(EyeAt::new(entity));
+        assert_eq!(
+            build_error(builder),
+            CameraRigError::ConflictingBinding {
+                binding: "EyeAt",
+                controller: "isometric",
+            }
+        );
+        // Orbiting around a followed target is fine.
+        let builder = CameraRig::builder()
+            .orbit(OrbitCameraController::default())
+            .look_at(LookAt::new(entity));
+        assert!(builder.build().is_ok());
+    }
+
+    #[test]
+    fn test_rig_rejects_head_bob_without_fps() {
+        let builder = CameraRig::builder()
+            .unreal(UnrealCameraController::default())
+            .with_head_bob(HeadBob::default());
+        assert_eq!(build_error(builder), CameraRigError::HeadBobRequiresFps);
+    }
+
+    #[test]
+    fn test_rig_inserts_bounds_collision_and_shake() {
+        let pitch_limits = PitchLimits {
+            min: -1.0,
+            max: 1.0,
+            anchor: ConstraintAnchor::Target,
+        };
+        let rig = CameraRig::builder()
+            .pancam2d(PanCam2dController::default())
+            .with_bounds(Constraints::new().with(pitch_limits))
+            .with_collision(CameraOcclusion::default())
+            .with_shake(CameraShake::default())
+            .build()
+            .unwrap();
+
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+        rig.insert_into(&mut world.commands().entity(entity));
+        world.flush();
+
+        let entity = world.entity(entity);
+        assert!(entity.contains::<PanCam2dController>());
+        assert_eq!(entity.get::<Constraints>().unwrap().0.len(), 1);
+        assert!(entity.contains::<CameraOcclusion>());
+        assert!(entity.contains::<CameraShake>());
+    }
+
+    #[test]
+    fn test_rig_offset_frames_target_at_anchor() {
+        let projection = PerspectiveProjection {
+            aspect_ratio: 16.0 / 9.0,
+            ..Default::default()
+        };
+        let anchor = Vec2::new(-0.3, 0.2);
+        let radius = 10.0;
+        let transform = RigOffset::shoulder(0.6, 0.3)
+            .with_anchor(anchor)
+            .local_transform(radius, Some(&projection));
+
+        // Project the target into the camera's view.
+        let target = Vec3::new(0.0, 0.0, -radius);
+        let view = transform.rotation.inverse() * (target - transform.translation);
+        let half_height = (0.5 * projection.fov).tan();
+        let ndc = Vec2::new(
+            view.x / (-view.z * half_height * projection.aspect_ratio),
+            view.y / (-view.z * half_height),
+        );
+        assert!(ndc.abs_diff_eq(anchor, 1e-4), "{ndc}");
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{clock::CameraTime, drift::gradient_noise, xr::XrDriven};
+
+use bevy::{
+    ecs::prelude::*, math::prelude::*, prelude::ReflectDefault, reflect::Reflect,
+    transform::components::Transform,
+};
+
+/// Trauma based camera shake, for hits, explosions, and landings.
+///
+/// Call [`CameraShake::add_trauma`] when something happens. The camera shakes with the square of the trauma, which wears
+/// off at `decay` per second, so big hits shake hard and settle quickly. Like a [`CameraDrift`](crate::drift::CameraDrift),
+/// the shake is added to the smoothed `Transform`, so it never feeds back into the `LookTransform`.
+#[derive(Clone, Component, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct CameraShake {
+    /// From `0.0` for no shake to `1.0` for the most.
+    pub trauma: f32,
+    /// How much trauma wears off per second.
+    pub decay: f32,
+    /// The largest rotation at full trauma, in radians, for yaw (X), pitch (Y), and roll (Z).
+    pub max_angles: Vec3,
+    /// The largest offset of the eye at full trauma, along its right (X), up (Y), and backward (Z) axes, in world units.
+    pub max_offset: Vec3,
+    /// How many times per second, roughly, the shake changes direction.
+    pub frequency: f32,
+    pub seed: u32,
+    phase: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    applied: Option<(Transform, Transform)>,
+}
+
+impl Default for CameraShake {
+    fn default() -> Self {
+        Self::new(Vec3::new(0.05, 0.05, 0.1), Vec3::ZERO)
+    }
+}
+
+impl CameraShake {
+    pub fn new(max_angles: Vec3, max_offset: Vec3) -> Self {
+        Self {
+            trauma: 0.0,
+            decay: 1.0,
+            max_angles,
+            max_offset,
+            frequency: 15.0,
+            seed: 0,
+            phase: 0.0,
+            applied: None,
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Adds to the trauma, up to `1.0`.
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
+    }
+
+    pub fn is_shaking(&self) -> bool {
+        self.trauma > 0.0
+    }
+
+    /// The rotation and eye offset at the current phase and trauma.
+    pub fn offsets(&self) -> (Quat, Vec3) {
+        let noise =
+            |axis: u32| gradient_noise(self.phase, self.seed.wrapping_mul(6).wrapping_add(axis));
+        let shake = self.trauma * self.trauma;
+        let angles = shake * self.max_angles * Vec3::new(noise(0), noise(1), noise(2));
+        let offset = shake * self.max_offset * Vec3::new(noise(3), noise(4), noise(5));
+        (
+            Quat::from_euler(EulerRot::YXZ, angles.x, angles.y, angles.z),
+            offset,
+        )
+    }
+}
+
+/// Puts the unshaken `Transform` back in place of last frame's shake, so a sleeping smoother and the offsets after it
+/// start from the steady view.
+pub fn restore_camera_shake_system(
+    mut cameras: Query<(&mut CameraShake, &mut Transform), Without<XrDriven>>,
+) {
+    for (mut shake, mut transform) in cameras.iter_mut() {
+        let shake = shake.bypass_change_detection();
+        match shake.applied {
+            Some((base, shaken)) if *transform == shaken => {
+                // Only a real change should trigger propagation, which the shake system decides.
+                *transform.bypass_change_detection() = base;
+            }
+            _ => shake.applied = None,
+        }
+    }
+}
+
+/// Adds each [`CameraShake`] to the smoothed `Transform` of its camera, and wears off its trauma.
+pub fn camera_shake_system(
+    time: CameraTime,
+    mut cameras: Query<(Entity, &mut CameraShake, &mut Transform), Without<XrDriven>>,
+) {
+    for (entity, mut shake, mut transform) in cameras.iter_mut() {
+        let previous = shake.bypass_change_detection().applied.take();
+        if !shake.is_shaking() {
+            if previous.is_some() {
+                // The camera was quietly steadied, so propagate it.
+                transform.set_changed();
+            }
+            continue;
+        }
+        let dt = time.delta_secs(entity);
+        shake.phase += dt * shake.frequency;
+
+        let base = *transform;
+        let (rotation, offset) = shake.offsets();
+        let shaken = Transform {
+            translation: base.translation + base.rotation * offset,
+            rotation: base.rotation * rotation,
+            scale: base.scale,
+        };
+        shake.trauma = (shake.trauma - dt * shake.decay).max(0.0);
+
+        *transform = shaken;
+        shake.applied = Some((base, shaken));
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::{
+        app::prelude::*,
+        time::{Real, Time},
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_shake_wears_off_and_restores_the_transform() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<Time<Real>>()
+            .add_systems(
+                Update,
+                (restore_camera_shake_system, camera_shake_system).chain(),
+            );
+        let steady = Transform::from_xyz(1.0, 2.0, 3.0);
+        let mut shake = CameraShake::default();
+        shake.add_trauma(0.5);
+        let camera = app.world_mut().spawn((shake, steady)).id();
+
+        let mut shook = false;
+        for _ in 0..40 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(16));
+            app.update();
+            shook |= *app.world().get::<Transform>(camera).unwrap() != steady;
+        }
+        assert!(shook);
+        assert!(!app.world().get::<CameraShake>(camera).unwrap().is_shaking());
+        assert_eq!(*app.world().get::<Transform>(camera).unwrap(), steady);
+    }
+}
@@ -0,0 +1,308 @@
+use crate::{
+    controllers::{
+        fps::{self, FpsCameraController},
+        isometric::{self, IsometricCameraController},
+        maya::{self, MayaCameraController},
+        orbit::{self, OrbitCameraController},
+        pancam2d::{self, PanCam2dController},
+        unreal::{self, UnrealCameraController},
+        CameraControlMode, CameraController,
+    },
+    LookTransform, Smoother,
+};
+
+use bevy::transform::components::Transform;
+
+/// A [`CameraController`] whose control math can run without an `App`, through its module's `apply_events` function.
+pub trait SimulatedController: CameraController {
+    /// Applies one frame of `events`, `dt` seconds long, to `transform`, and returns the kind of motion.
+    fn apply_events(
+        &mut self,
+        transform: &mut LookTransform,
+        events: &[Self::Event],
+        dt: f32,
+    ) -> CameraControlMode;
+}
+
+impl SimulatedController for FpsCameraController {
+    fn apply_events(
+        &mut self,
+        transform: &mut LookTransform,
+        events: &[Self::Event],
+        _dt: f32,
+    ) -> CameraControlMode {
+        fps::apply_events(self, transform, events)
+    }
+}
+
+impl SimulatedController for IsometricCameraController {
+    fn apply_events(
+        &mut self,
+        transform: &mut LookTransform,
+        events: &[Self::Event],
+        dt: f32,
+    ) -> CameraControlMode {
+        isometric::apply_events(self, transform, events, dt)
+    }
+}
+
+impl SimulatedController for MayaCameraController {
+    fn apply_events(
+        &mut self,
+        transform: &mut LookTransform,
+        events: &[Self::Event],
+        _dt: f32,
+    ) -> CameraControlMode {
+        maya::apply_events(self, transform, events)
+    }
+}
+
+/// Pans along the axes of the `LookTransform` rather than the smoothed view. View snaps aren't animated.
+impl SimulatedController for OrbitCameraController {
+    fn apply_events(
+        &mut self,
+        transform: &mut LookTransform,
+        events: &[Self::Event],
+        dt: f32,
+    ) -> CameraControlMode {
+        let view_rotation = Transform::from(*transform).rotation;
+        orbit::apply_events(self, transform, view_rotation, false, events, dt)
+    }
+}
+
+impl SimulatedController for PanCam2dController {
+    fn apply_events(
+        &mut self,
+        transform: &mut LookTransform,
+        events: &[Self::Event],
+        _dt: f32,
+    ) -> CameraControlMode {
+        pancam2d::apply_events(self, transform, events)
+    }
+}
+
+/// Simulates a perspective projection.
+impl SimulatedController for UnrealCameraController {
+    fn apply_events(
+        &mut self,
+        transform: &mut LookTransform,
+        events: &[Self::Event],
+        _dt: f32,
+    ) -> CameraControlMode {
+        unreal::apply_events(self, transform, false, events)
+    }
+}
+
+/// A control event that a [`CameraSimulation`] applies in the step that contains `time`, in seconds since the start.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimedControlEvent<E> {
+    pub time: f32,
+    pub event: E,
+}
+
+impl<E> TimedControlEvent<E> {
+    pub fn new(time: f32, event: E) -> Self {
+        Self { time, event }
+    }
+}
+
+/// The state of a [`CameraSimulation`] after a step.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SimulatedFrame {
+    /// The time at the end of the step, in seconds since the start.
+    pub time: f32,
+    pub mode: CameraControlMode,
+    /// The `LookTransform` that the controller wrote.
+    pub look_transform: LookTransform,
+    /// The `Transform` that the `LookTransform` was smoothed into.
+    pub transform: Transform,
+}
+
+/// Advances a controller, its `LookTransform`, and its [`Smoother`] like the plugins do, but without Bevy's scheduling, so
+/// camera tuning can be checked with deterministic golden tests in CI.
+///
+/// Only the controller's control math and the smoothing run. Input maps, [`Constraints`](crate::constraints::Constraints),
+/// entity following, and post-smoothing offsets don't, since they need the rest of the world.
+#[derive(Clone, Debug)]
+pub struct CameraSimulation<C> {
+    pub controller: C,
+    pub look_transform: LookTransform,
+    pub smoother: Smoother,
+    /// Seconds since the start.
+    pub time: f32,
+}
+
+impl<C: SimulatedController> CameraSimulation<C> {
+    /// Starts at `look_transform`, smoothed like the controller's bundle.
+    pub fn new(controller: C, look_transform: LookTransform) -> Self {
+        Self {
+            smoother: Smoother::new(controller.smoothing_weight()),
+            controller,
+            look_transform,
+            time: 0.0,
+        }
+    }
+
+    pub fn with_smoother(mut self, smoother: Smoother) -> Self {
+        self.smoother = smoother;
+        self
+    }
+
+    /// Applies one frame of `events`, `dt` seconds long, and smooths the result.
+    pub fn step(&mut self, events: &[C::Event], dt: f32) -> SimulatedFrame {
+        // Like the plugins, a disabled controller ignores its events and doesn't smooth.
+        let enabled = self.controller.enabled();
+        self.smoother.set_enabled(enabled);
+        let mode = if enabled {
+            self.controller
+                .apply_events(&mut self.look_transform, events, dt)
+        } else {
+            CameraControlMode::Idle
+        };
+        let smoothed = if self.smoother.is_enabled() {
+            self.smoother.smooth_transform_dt(&self.look_transform, dt)
+        } else {
+            self.look_transform
+        };
+        self.time += dt;
+
+        SimulatedFrame {
+            time: self.time,
+            mode,
+            look_transform: self.look_transform,
+            transform: smoothed.into(),
+        }
+    }
+
+    /// Runs `steps` steps of `dt` seconds, applying each of the `events` in the step that contains its time, and returns
+    /// every step's frame.
+    pub fn run(
+        &mut self,
+        events: &[TimedControlEvent<C::Event>],
+        dt: f32,
+        steps: usize,
+    ) -> Vec<SimulatedFrame> {
+        (0..steps)
+            .map(|_| {
+                let (start, end) = (self.time, self.time + dt);
+                let step_events: Vec<_> = events
+                    .iter()
+                    .filter(|e| e.time >= start && e.time < end)
+                    .map(|e| e.event.clone())
+                    .collect();
+                self.step(&step_events, dt)
+            })
+            .collect()
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::controllers::orbit::ControlEvent;
+    use bevy::math::prelude::*;
+
+    #[test]
+    fn test_simulation_is_deterministic_and_settles() {
+        let events = [
+            TimedControlEvent::new(0.0, ControlEvent::Orbit(Vec2::new(1.0, 0.0))),
+            TimedControlEvent::new(0.06, ControlEvent::Zoom(0.5)),
+        ];
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y);
+        let simulate = || {
+            CameraSimulation::new(OrbitCameraController::default(), start).run(
+                &events,
+                1.0 / 60.0,
+                600,
+            )
+        };
+
+        let frames = simulate();
+        assert_eq!(frames, simulate());
+        assert_eq!(frames[0].mode, CameraControlMode::Orbit);
+        assert_eq!(frames[3].mode, CameraControlMode::Zoom);
+
+        // The camera ends up where the controller put it, at half the distance.
+        let last = frames.last().unwrap();
+        assert!((last.look_transform.radius() - 5.0).abs() < 1e-3);
+        assert!(last
+            .transform
+            .translation
+            .abs_diff_eq(last.look_transform.eye, 1e-3));
+    }
+
+    #[test]
+    fn test_orbit_pan_coasts_to_a_stop() {
+        let events: Vec<_> = (0..30)
+            .map(|i| {
+                TimedControlEvent::new(
+                    (i as f32 + 0.5) / 60.0,
+                    ControlEvent::TranslateTarget(Vec2::X / 60.0),
+                )
+            })
+            .collect();
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y);
+        let mut controller = OrbitCameraController::default();
+        controller.inertia = Some(orbit::OrbitInertia::default());
+        let frames = CameraSimulation::new(controller, start).run(&events, 1.0 / 60.0, 600);
+
+        // The target keeps moving the same way after the drag, and then stops.
+        let released = frames[29].look_transform.target;
+        let last = frames.last().unwrap().look_transform.target;
+        assert!(last.x < released.x - 0.05);
+        assert_eq!(frames[598].look_transform.target, last);
+        assert_eq!(frames.last().unwrap().mode, CameraControlMode::Idle);
+    }
+
+    #[test]
+    fn test_orbit_zoom_takes_as_long_at_any_frame_rate() {
+        let events = [TimedControlEvent::new(0.0, ControlEvent::Zoom(0.5))];
+        let start = LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y);
+        let radius_after = |dt: f32, steps: usize| {
+            let frames = CameraSimulation::new(OrbitCameraController::default(), start)
+                .run(&events, dt, steps);
+            frames.last().unwrap().look_transform.radius()
+        };
+        let slow = radius_after(1.0 / 30.0, 3);
+        let fast = radius_after(1.0 / 120.0, 12);
+        assert!(slow > 5.1);
+        assert!((slow - fast).abs() < 1e-3, "{slow} {fast}");
+    }
+
+    #[test]
+    fn test_orbit_zoom_stops_when_the_radius_is_changed_elsewhere() {
+        let mut controller = OrbitCameraController::default();
+        let mut transform = LookTransform::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO, Vec3::Y);
+        let dt = 1.0 / 60.0;
+        let mode = orbit::apply_events(
+            &mut controller,
+            &mut transform,
+            Quat::IDENTITY,
+            false,
+            &[ControlEvent::Zoom(0.5)],
+            dt,
+        );
+        assert_eq!(mode, CameraControlMode::Zoom);
+
+        // Like a constraint that keeps the camera 9 units away.
+        transform.eye = Vec3::new(0.0, 0.0, 9.0);
+        let mode = orbit::apply_events(
+            &mut controller,
+            &mut transform,
+            Quat::IDENTITY,
+            false,
+            &[],
+            dt,
+        );
+        assert_eq!(mode, CameraControlMode::Idle);
+        assert_eq!(transform.radius(), 9.0);
+    }
+}
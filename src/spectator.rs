@@ -0,0 +1,92 @@
+use crate::{
+    clock::CameraTime,
+    controllers::{AutoRotate, CameraControlMode, CameraControllerStatus},
+    occlusion::CameraOcclusion,
+    LookTransform,
+};
+
+use bevy::{
+    ecs::prelude::*, math::prelude::*, reflect::Reflect, transform::components::GlobalTransform,
+};
+
+/// Turns a camera into a spectator or "killcam" that orbits around a moving entity.
+///
+/// The target is kept on the entity's translation, plus `offset`, and the eye is carried along with it, so an orbit
+/// controller on the same camera can still orbit and zoom around the entity while it moves. Use this instead of
+/// [`LookAt`](crate::LookAt), which only moves the target.
+///
+/// Scenery between the entity and the camera is handled by the required [`CameraOcclusion`], which pulls the camera in
+/// front of it once the [`CameraOcclusionPlugin`](crate::occlusion::CameraOcclusionPlugin) is added and the app fills in
+/// the hits from its own raycasts. With `auto_rotate` set, the camera slowly circles the entity while nobody is
+/// controlling it.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[require(CameraOcclusion)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct Spectate {
+    pub entity: Entity,
+    pub offset: Vec3,
+    pub auto_rotate: Option<AutoRotate>,
+    idle_time: f32,
+}
+
+impl Spectate {
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            offset: Vec3::ZERO,
+            auto_rotate: None,
+            idle_time: 0.0,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_auto_rotate(mut self, auto_rotate: AutoRotate) -> Self {
+        self.auto_rotate = Some(auto_rotate);
+        self
+    }
+
+    /// How long the camera has gone without input, in seconds.
+    pub fn idle_time(&self) -> f32 {
+        self.idle_time
+    }
+}
+
+/// Moves each [`Spectate`] camera along with its entity, and circles it while idle.
+pub fn spectate_system(
+    time: CameraTime,
+    status: Option<Res<CameraControllerStatus>>,
+    mut cameras: Query<(Entity, &mut LookTransform, &mut Spectate)>,
+    anchors: Query<&GlobalTransform>,
+) {
+    for (entity, mut look_transform, mut spectate) in cameras.iter_mut() {
+        let dt = time.delta_secs(entity);
+        let Ok(anchor) = anchors.get(spectate.entity) else {
+            continue;
+        };
+        let has_input = status
+            .as_ref()
+            .is_some_and(|s| s.entity == Some(entity) && s.mode != CameraControlMode::Idle);
+        if has_input {
+            spectate.idle_time = 0.0;
+        } else {
+            spectate.idle_time += dt;
+        }
+
+        let target = anchor.translation() + spectate.offset;
+        let mut offset = look_transform.eye - look_transform.target;
+        if let Some(auto_rotate) = spectate.auto_rotate {
+            let angle = auto_rotate.angle(spectate.idle_time, dt);
+            offset = Quat::from_axis_angle(look_transform.up.normalize_or(Vec3::Y), angle) * offset;
+        }
+        // Only write on change, so idle cameras can stay asleep.
+        let eye = target + offset;
+        if look_transform.target != target || look_transform.eye != eye {
+            look_transform.target = target;
+            look_transform.eye = eye;
+        }
+    }
+}
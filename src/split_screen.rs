@@ -0,0 +1,257 @@
+use crate::controllers::{CameraBundleBuilder, CameraController, PlayerInput};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    math::prelude::*,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    render::camera::{Camera, CameraUpdateSystem, NormalizedRenderTarget, Viewport},
+    window::{PrimaryWindow, Window},
+};
+
+/// Keeps the `Camera::viewport` of each [`SplitScreenViewport`] camera in its cell of the window, as the window is resized.
+pub struct SplitScreenPlugin;
+
+impl Plugin for SplitScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            split_screen_viewport_system.before(CameraUpdateSystem),
+        );
+    }
+}
+
+/// How the viewports of a split screen are arranged in the window. Cells are filled left to right, then top to bottom.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub enum SplitScreenArrangement {
+    /// Side by side for two players, and the smallest square grid that fits more, like most couch co-op games.
+    #[default]
+    Auto,
+    /// One column per player, side by side.
+    Columns,
+    /// One row per player, stacked.
+    Rows,
+    Grid {
+        columns: u32,
+    },
+}
+
+impl SplitScreenArrangement {
+    /// The number of columns (X) and rows (Y) for `count` viewports.
+    pub fn grid(&self, count: u32) -> UVec2 {
+        let count = count.max(1);
+        let columns = match *self {
+            Self::Auto => (count as f32).sqrt().ceil() as u32,
+            Self::Columns => count,
+            Self::Rows => 1,
+            Self::Grid { columns } => columns.clamp(1, count),
+        };
+        UVec2::new(columns, count.div_ceil(columns))
+    }
+
+    /// The physical rectangle of viewport `index` out of `count`, in a render target of `size` physical pixels, with `gap`
+    /// pixels between neighboring viewports. The viewports tile the whole target, apart from the gaps.
+    pub fn viewport_rect(&self, index: u32, count: u32, size: UVec2, gap: u32) -> URect {
+        let grid = self.grid(count);
+        let cell = UVec2::new(index % grid.x, index / grid.x);
+        let available = size.saturating_sub((grid - 1) * gap);
+        URect::from_corners(
+            cell * available / grid + cell * gap,
+            (cell + 1) * available / grid + cell * gap,
+        )
+    }
+}
+
+/// The players of a split screen and how their viewports are arranged, for [`spawn_layout`].
+#[derive(Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub struct SplitScreenLayout {
+    /// The input device of each player, in viewport order.
+    pub players: Vec<PlayerInput>,
+    pub arrangement: SplitScreenArrangement,
+    /// Physical pixels between neighboring viewports.
+    pub gap: u32,
+}
+
+impl SplitScreenLayout {
+    pub fn new(players: impl IntoIterator<Item = PlayerInput>) -> Self {
+        Self {
+            players: players.into_iter().collect(),
+            arrangement: SplitScreenArrangement::default(),
+            gap: 0,
+        }
+    }
+
+    pub fn with_arrangement(mut self, arrangement: SplitScreenArrangement) -> Self {
+        self.arrangement = arrangement;
+        self
+    }
+
+    pub fn with_gap(mut self, gap: u32) -> Self {
+        self.gap = gap;
+        self
+    }
+}
+
+/// Places a camera's viewport in a cell of a split screen. The [`SplitScreenPlugin`] keeps `Camera::viewport` up to date with
+/// the size of the camera's window.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct SplitScreenViewport {
+    pub arrangement: SplitScreenArrangement,
+    pub index: u32,
+    pub count: u32,
+    pub gap: u32,
+}
+
+/// Spawns a camera for each player of the `layout`, from the `controller_template`, and returns them in viewport order.
+///
+/// Each camera gets its own `LookTransform` and controller, a [`SplitScreenViewport`], the player's [`PlayerInput`], and a
+/// `Camera` whose `order` is its index. Add the camera type to the template, e.g. with
+/// `.with(Camera3d::default())`, along with anything else every player's camera needs.
+///
+/// The built-in input maps only read the keyboard and mouse, and skip the cameras of gamepad players. Give those players
+/// an input map of your own that sends each gamepad's input to its camera with a
+/// [`TargetedControlEvent`](crate::controllers::TargetedControlEvent):
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use smooth_bevy_cameras::{
+/// #     controllers::{
+/// #         orbit::{ControlEvent, OrbitCameraBundle},
+/// #         PlayerInput, TargetedControlEvent,
+/// #     },
+/// #     split_screen::{spawn_layout, SplitScreenLayout},
+/// # };
+/// fn spawn_cameras(mut commands: Commands, gamepads: Query<Entity, With<Gamepad>>) {
+///     let players = std::iter::once(PlayerInput::KeyboardMouse)
+///         .chain(gamepads.iter().map(PlayerInput::Gamepad));
+///     let template = OrbitCameraBundle::builder()
+///         .eye(Vec3::new(0.0, 2.0, 5.0))
+///         .with(Camera3d::default());
+///     spawn_layout(&mut commands, &SplitScreenLayout::new(players), template);
+/// }
+///
+/// fn gamepad_orbit_input_map(
+///     time: Res<Time>,
+///     mut events: EventWriter<TargetedControlEvent<ControlEvent>>,
+///     cameras: Query<(Entity, &PlayerInput)>,
+///     gamepads: Query<&Gamepad>,
+/// ) {
+///     for (camera, player) in cameras.iter() {
+///         let Some(gamepad) = player.gamepad().and_then(|g| gamepads.get(g).ok()) else {
+///             continue;
+///         };
+///         let stick = gamepad.right_stick();
+///         if stick != Vec2::ZERO {
+///             let orbit = 2.0 * time.delta_secs() * Vec2::new(-stick.x, stick.y);
+///             events.send(TargetedControlEvent::new(camera, ControlEvent::Orbit(orbit)));
+///         }
+///     }
+/// }
+/// ```
+pub fn spawn_layout<C: CameraController + Clone, B: Bundle + Clone>(
+    commands: &mut Commands,
+    layout: &SplitScreenLayout,
+    controller_template: CameraBundleBuilder<C, B>,
+) -> Vec<Entity> {
+    let count = layout.players.len() as u32;
+    (0..count)
+        .zip(&layout.players)
+        .map(|(index, &player)| {
+            let mut entity = commands.spawn((
+                Camera {
+                    order: index as isize,
+                    ..Default::default()
+                },
+                SplitScreenViewport {
+                    arrangement: layout.arrangement,
+                    index,
+                    count,
+                    gap: layout.gap,
+                },
+                player,
+            ));
+            controller_template.clone().insert_into(&mut entity);
+            entity.id()
+        })
+        .collect()
+}
+
+pub fn split_screen_viewport_system(
+    mut cameras: Query<(&mut Camera, &SplitScreenViewport)>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+) {
+    let primary_window = primary_window.get_single().ok();
+    for (mut camera, split) in cameras.iter_mut() {
+        let Some(NormalizedRenderTarget::Window(window_ref)) =
+            camera.target.normalize(primary_window)
+        else {
+            continue;
+        };
+        let Ok(window) = windows.get(window_ref.entity()) else {
+            continue;
+        };
+        let rect = split.arrangement.viewport_rect(
+            split.index,
+            split.count,
+            window.physical_size(),
+            split.gap,
+        );
+        // A minimized window has no room for a viewport.
+        if rect.is_empty() {
+            continue;
+        }
+
+        // Only write on change, since the camera recomputes its projection.
+        let unchanged = camera.viewport.as_ref().is_some_and(|viewport| {
+            viewport.physical_position == rect.min && viewport.physical_size == rect.size()
+        });
+        if !unchanged {
+            let depth = camera
+                .viewport
+                .as_ref()
+                .map_or(0.0..1.0, |viewport| viewport.depth.clone());
+            camera.viewport = Some(Viewport {
+                physical_position: rect.min,
+                physical_size: rect.size(),
+                depth,
+            });
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_viewports_tile_the_window_with_gaps() {
+        let size = UVec2::new(1921, 1081);
+        let gap = 4;
+        let arrangement = SplitScreenArrangement::Auto;
+        assert_eq!(arrangement.grid(2), UVec2::new(2, 1));
+        assert_eq!(arrangement.grid(3), UVec2::new(2, 2));
+
+        let rects: Vec<_> = (0..4)
+            .map(|i| arrangement.viewport_rect(i, 4, size, gap))
+            .collect();
+        assert_eq!(rects[0].min, UVec2::ZERO);
+        assert_eq!(rects[3].max, size);
+        // Neighbors are exactly a gap apart.
+        assert_eq!(rects[1].min.x, rects[0].max.x + gap);
+        assert_eq!(rects[2].min.y, rects[0].max.y + gap);
+        let area: u32 = rects.iter().map(|r| r.size().element_product()).sum();
+        assert_eq!(area, (size.x - gap) * (size.y - gap));
+    }
+}
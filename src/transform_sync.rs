@@ -0,0 +1,57 @@
+use crate::{xr::XrDriven, LookTransform, Smoother};
+
+use bevy::{
+    ecs::prelude::*, prelude::ReflectDefault, reflect::Reflect, transform::components::Transform,
+};
+
+/// Copies edits that other code makes to the camera's `Transform` back into its `LookTransform`, for hybrid control
+/// schemes where e.g. an animation or a gameplay script places the camera directly.
+///
+/// Without it, the `LookTransform` doesn't know about such an edit, and the camera snaps back the next time it moves. With
+/// it, the eye and look direction are taken from the edited `Transform`, the target is kept at the same distance in front of
+/// the eye, and the `Smoother` continues from there. The crate's own writes, including smoothing and offsets, aren't
+/// mistaken for edits.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct SyncFromTransform {
+    /// The `Transform` at the end of the crate's last synchronization.
+    written: Option<Transform>,
+}
+
+/// Cameras whose `Transform` may have been edited.
+type EditedFilter = (Changed<Transform>, Without<XrDriven>);
+
+/// Updates the `LookTransform` of each [`SyncFromTransform`] camera whose `Transform` was edited since the last
+/// synchronization.
+pub fn sync_from_transform_system(
+    mut cameras: Query<(Entity, &SyncFromTransform, &Transform, &mut LookTransform), EditedFilter>,
+    mut smoothers: Query<&mut Smoother>,
+) {
+    for (entity, sync, transform, mut look_transform) in cameras.iter_mut() {
+        if sync.written.is_none_or(|written| written == *transform) {
+            continue;
+        }
+        let radius = look_transform.radius();
+        let radius = if radius > 0.0 { radius } else { 1.0 };
+        let eye = transform.translation;
+        look_transform.set_if_neq(LookTransform::new(
+            eye,
+            eye + radius * transform.forward(),
+            transform.up().into(),
+        ));
+        // Start smoothing from the edited `Transform`, rather than from where the camera was before.
+        if let Ok(mut smoother) = smoothers.get_mut(entity) {
+            smoother.reset();
+        }
+    }
+}
+
+/// Records the `Transform` of each [`SyncFromTransform`] camera, after the crate is done writing it for the frame.
+pub fn record_synced_transform_system(
+    mut cameras: Query<(&mut SyncFromTransform, &Transform), Without<XrDriven>>,
+) {
+    for (mut sync, transform) in cameras.iter_mut() {
+        // This is bookkeeping, not a change anyone needs to react to.
+        sync.bypass_change_detection().written = Some(*transform);
+    }
+}
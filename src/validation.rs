@@ -0,0 +1,186 @@
+use crate::{
+    controllers::{fps, isometric, maya, orbit, pancam2d, unreal},
+    occlusion::occlusion_system,
+    rig::RigOffset,
+    LookTransform, LookTransformSet, Smoother,
+};
+
+use bevy::{
+    app::prelude::*,
+    ecs::{entity::EntityHashMap, prelude::*},
+    log::error,
+    transform::components::Transform,
+};
+
+use std::{collections::VecDeque, fmt::Debug};
+
+/// A debugging aid that catches NaNs and infinities in camera math before they reach the render transform.
+///
+/// Each frame, the `LookTransform` and [`Smoother`] of every camera are checked before they are synchronized, and the
+/// camera `Transform`s are checked after every effect has been applied to them. A non-finite value is logged as an error,
+/// along with the camera entity and the [`RecentControlEvents`], and replaced with the last valid state, so one bad frame
+/// of input doesn't leave the camera broken for good.
+///
+/// The events of the built-in controllers are tracked automatically. Add [`record_recent_events`] for a custom
+/// controller's events.
+pub struct CameraValidationPlugin;
+
+impl Plugin for CameraValidationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RecentControlEvents>().add_systems(
+            PostUpdate,
+            (
+                validate_look_transforms_system
+                    .after(LookTransformSet::Constrain)
+                    .before(LookTransformSet::Sync),
+                validate_transforms_system
+                    .after(LookTransformSet::Sync)
+                    .after(fps::head_bob_system)
+                    .after(fps::strafe_tilt_system)
+                    .after(occlusion_system)
+                    .before(LookTransformSet::Finish),
+            ),
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Only the controller plugins that were added have events to record.
+        fn record_if_added<E: Event + Debug>(app: &mut App) {
+            if app.world().contains_resource::<Events<E>>() {
+                app.add_systems(
+                    PostUpdate,
+                    record_recent_events::<E>.before(validate_look_transforms_system),
+                );
+            }
+        }
+        record_if_added::<fps::ControlEvent>(app);
+        record_if_added::<isometric::ControlEvent>(app);
+        record_if_added::<maya::ControlEvent>(app);
+        record_if_added::<orbit::ControlEvent>(app);
+        record_if_added::<pancam2d::ControlEvent>(app);
+        record_if_added::<unreal::ControlEvent>(app);
+    }
+}
+
+/// The last few control events of any controller, oldest first, for the [`CameraValidationPlugin`] to log.
+#[derive(Resource, Clone, Debug)]
+pub struct RecentControlEvents {
+    /// How many events are kept.
+    pub capacity: usize,
+    events: VecDeque<String>,
+}
+
+impl Default for RecentControlEvents {
+    fn default() -> Self {
+        Self {
+            capacity: 8,
+            events: VecDeque::new(),
+        }
+    }
+}
+
+impl RecentControlEvents {
+    pub fn push(&mut self, event: &impl Debug) {
+        self.events.push_back(format!("{event:?}"));
+        while self.events.len() > self.capacity {
+            self.events.pop_front();
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.events.iter().map(String::as_str)
+    }
+}
+
+/// Keeps the last few `E` events in the [`RecentControlEvents`].
+pub fn record_recent_events<E: Event + Debug>(
+    mut events: EventReader<E>,
+    mut recent: ResMut<RecentControlEvents>,
+) {
+    for event in events.read() {
+        recent.push(event);
+    }
+}
+
+pub fn validate_look_transforms_system(
+    recent: Res<RecentControlEvents>,
+    mut cameras: Query<(Entity, &mut LookTransform, Option<&mut Smoother>)>,
+    mut last_valid: Local<EntityHashMap<LookTransform>>,
+) {
+    last_valid.retain(|entity, _| cameras.contains(*entity));
+    for (entity, mut look_transform, smoother) in cameras.iter_mut() {
+        if look_transform.is_finite() {
+            last_valid.insert(entity, *look_transform);
+        } else {
+            let restored = last_valid.get(&entity).copied();
+            error!(
+                "Camera {entity} has a non-finite LookTransform {:?}, restoring {restored:?}. Recent control events: {:?}",
+                *look_transform,
+                recent.events
+            );
+            if let Some(restored) = restored {
+                *look_transform = restored;
+            }
+        }
+
+        if let Some(mut smoother) = smoother {
+            if smoother.smoothed().is_some_and(|s| !s.is_finite()) {
+                error!(
+                    "Camera {entity} has a non-finite smoothed transform {:?}, resetting its Smoother. Recent control events: {:?}",
+                    smoother.smoothed(),
+                    recent.events
+                );
+                smoother.reset();
+            }
+        }
+    }
+}
+
+/// Cameras driven by a `LookTransform`, directly or through a [`RigOffset`].
+type CameraFilter = Or<(With<LookTransform>, With<RigOffset>)>;
+
+pub fn validate_transforms_system(
+    recent: Res<RecentControlEvents>,
+    mut cameras: Query<(Entity, &mut Transform), CameraFilter>,
+    mut last_valid: Local<EntityHashMap<Transform>>,
+) {
+    last_valid.retain(|entity, _| cameras.contains(*entity));
+    for (entity, mut transform) in cameras.iter_mut() {
+        if transform.is_finite() {
+            last_valid.insert(entity, *transform);
+            continue;
+        }
+        let restored = last_valid.get(&entity).copied().unwrap_or_default();
+        error!(
+            "Camera {entity} has a non-finite Transform {:?}, restoring {restored:?}. Recent control events: {:?}",
+            *transform, recent.events
+        );
+        *transform = restored;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::math::Vec3;
+
+    #[test]
+    fn test_non_finite_look_transform_is_restored() {
+        let mut app = App::new();
+        app.init_resource::<RecentControlEvents>()
+            .add_systems(Update, validate_look_transforms_system);
+        let valid = LookTransform::new(Vec3::new(0.0, 1.0, 5.0), Vec3::ZERO, Vec3::Y);
+        let camera = app.world_mut().spawn(valid).id();
+        app.update();
+
+        app.world_mut()
+            .get_mut::<LookTransform>(camera)
+            .unwrap()
+            .eye
+            .x = f32::NAN;
+        app.update();
+
+        assert_eq!(*app.world().get::<LookTransform>(camera).unwrap(), valid);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::controllers::orbit::{self, ControlEvent, OrbitCameraController};
+
+use bevy::{
+    app::prelude::*,
+    color::{palettes::css, Color},
+    ecs::prelude::*,
+    gizmos::{config::GizmoConfig, prelude::*},
+    input::prelude::*,
+    math::prelude::*,
+    reflect::Reflect,
+    render::camera::Camera,
+    transform::{
+        components::{GlobalTransform, Transform},
+        TransformSystem,
+    },
+    window::{PrimaryWindow, Window},
+};
+
+/// Draws a [`ViewCube`] in the corner of orbit cameras, which snaps the camera to the clicked face, edge, or corner.
+///
+/// Only available with the `view_cube` feature, which enables Bevy's gizmos.
+#[derive(Default)]
+pub struct ViewCubePlugin;
+
+impl Plugin for ViewCubePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_gizmo_config(
+            ViewCubeGizmos,
+            GizmoConfig {
+                // Draw over the scene.
+                depth_bias: -1.0,
+                line_width: 2.0,
+                ..Default::default()
+            },
+        )
+        .add_systems(Update, view_cube_click_system.before(orbit::control_system))
+        // After propagation, so the cube matches the smoothed camera.
+        .add_systems(
+            PostUpdate,
+            view_cube_draw_system.after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+/// The gizmo group that view cubes are drawn with. Its config can be changed in the `GizmoConfigStore`.
+#[derive(Default, Reflect, GizmoConfigGroup)]
+pub struct ViewCubeGizmos;
+
+/// Add this to a camera with an [`OrbitCameraController`] to show an orientation cube in the top right corner of its
+/// viewport.
+///
+/// Clicking the middle of a face looks at that side of the scene, and clicking near an edge or corner looks along the
+/// diagonal, animated like [`OrbitCameraController::snap_to_view`].
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[reflect(Component, Debug)]
+pub struct ViewCube {
+    /// Half of the width of the cube on screen, in logical pixels.
+    pub size: f32,
+    /// The distance from the top right corner of the viewport to the cube, in logical pixels.
+    pub margin: Vec2,
+}
+
+impl Default for ViewCube {
+    fn default() -> Self {
+        Self {
+            size: 30.0,
+            margin: Vec2::splat(20.0),
+        }
+    }
+}
+
+/// Where a view cube is in the world, just in front of the camera.
+struct CubePlacement {
+    center: Vec3,
+    half_size: f32,
+}
+
+impl ViewCube {
+    fn placement(&self, camera: &Camera, transform: &GlobalTransform) -> Option<CubePlacement> {
+        // Close to the eye, but far enough to not be clipped by the near plane.
+        const DEPTH: f32 = 1.0;
+
+        let viewport = camera.logical_viewport_rect()?;
+        let center = Vec2::new(
+            viewport.max.x - self.margin.x - self.size,
+            viewport.min.y + self.margin.y + self.size,
+        );
+        let at_depth = |pixel: Vec2| {
+            let ray = camera.viewport_to_world(transform, pixel).ok()?;
+            let forward = transform.forward();
+            let t = (DEPTH - (ray.origin - transform.translation()).dot(*forward))
+                / ray.direction.dot(*forward);
+            Some(ray.get_point(t))
+        };
+        let world_center = at_depth(center)?;
+        let world_per_pixel = at_depth(center + Vec2::X)?.distance(world_center);
+        Some(CubePlacement {
+            center: world_center,
+            half_size: self.size * world_per_pixel,
+        })
+    }
+}
+
+/// The direction from the pivot toward the camera that a click at `hit` picks, where `hit` is on the surface of the cube
+/// from -1 to 1. The middle of each face picks the face's normal, and the border picks the edge or corner it's next to.
+pub fn pick_eye_direction(hit: Vec3) -> Vec3 {
+    const BORDER: f32 = 0.6;
+    let snap = |x: f32| if x.abs() > BORDER { x.signum() } else { 0.0 };
+    Vec3::new(snap(hit.x), snap(hit.y), snap(hit.z)).normalize_or_zero()
+}
+
+/// Where a ray first enters the cube from -1 to 1, if it does.
+fn ray_cube_hit(origin: Vec3, direction: Vec3) -> Option<Vec3> {
+    let inverse = direction.recip();
+    let t0 = (-Vec3::ONE - origin) * inverse;
+    let t1 = (Vec3::ONE - origin) * inverse;
+    let near = t0.min(t1).max_element();
+    let far = t0.max(t1).min_element();
+    (near <= far && far >= 0.0).then(|| origin + near.max(0.0) * direction)
+}
+
+/// The eye direction under the cursor, if it's over the cube.
+fn hovered_eye_direction(
+    view_cube: &ViewCube,
+    camera: &Camera,
+    transform: &GlobalTransform,
+    cursor: Vec2,
+) -> Option<Vec3> {
+    let placement = view_cube.placement(camera, transform)?;
+    let ray = camera.viewport_to_world(transform, cursor).ok()?;
+    let origin = (ray.origin - placement.center) / placement.half_size;
+    let hit = ray_cube_hit(origin, *ray.direction)?;
+    let direction = pick_eye_direction(hit);
+    (direction != Vec3::ZERO).then_some(direction)
+}
+
+pub fn view_cube_click_system(
+    mut events: EventWriter<ControlEvent>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&ViewCube, &Camera, &GlobalTransform, &OrbitCameraController)>,
+) {
+    if !mouse_buttons.just_pressed(MouseButton::Left) {
+        return;
+    }
+    let Some(cursor) = windows.get_single().ok().and_then(|w| w.cursor_position()) else {
+        return;
+    };
+    for (view_cube, camera, transform, controller) in cameras.iter() {
+        if !controller.enabled {
+            continue;
+        }
+        if let Some(direction) = hovered_eye_direction(view_cube, camera, transform, cursor) {
+            events.send(ControlEvent::SnapToView(direction));
+            return;
+        }
+    }
+}
+
+pub fn view_cube_draw_system(
+    mut gizmos: Gizmos<ViewCubeGizmos>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&ViewCube, &Camera, &GlobalTransform)>,
+) {
+    let cursor = windows.get_single().ok().and_then(|w| w.cursor_position());
+    for (view_cube, camera, transform) in cameras.iter() {
+        let Some(placement) = view_cube.placement(camera, transform) else {
+            continue;
+        };
+        let CubePlacement { center, half_size } = placement;
+
+        gizmos.cuboid(
+            Transform::from_translation(center).with_scale(Vec3::splat(2.0 * half_size)),
+            Color::from(css::GRAY),
+        );
+        for (axis, color) in [
+            (Vec3::X, css::RED),
+            (Vec3::Y, css::LIME),
+            (Vec3::Z, css::BLUE),
+        ] {
+            gizmos.line(center, center + 1.5 * half_size * axis, color);
+        }
+
+        let hovered = cursor.and_then(|c| hovered_eye_direction(view_cube, camera, transform, c));
+        if let Some(direction) = hovered {
+            // Mark the middle of the hovered face, edge, or corner.
+            let on_surface = direction / direction.abs().max_element();
+            gizmos.sphere(
+                Isometry3d::from_translation(center + half_size * on_surface),
+                0.15 * half_size,
+                css::YELLOW,
+            );
+        }
+    }
+}
+
+// ████████╗███████╗███████╗████████╗
+// ╚══██╔══╝██╔════╝██╔════╝╚══██╔══╝
+//    ██║   █████╗  ███████╗   ██║
+//    ██║   ██╔══╝  ╚════██║   ██║
+//    ██║   ███████╗███████║   ██║
+//    ╚═╝   ╚══════╝╚══════╝   ╚═╝
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pick_faces_edges_and_corners() {
+        assert_eq!(pick_eye_direction(Vec3::new(0.1, -0.2, 1.0)), Vec3::Z);
+        assert_eq!(
+            pick_eye_direction(Vec3::new(0.9, 0.0, 1.0)),
+            Vec3::new(1.0, 0.0, 1.0).normalize()
+        );
+        assert_eq!(
+            pick_eye_direction(Vec3::new(-1.0, 0.8, -0.7)),
+            Vec3::new(-1.0, 1.0, -1.0).normalize()
+        );
+
+        let hit = ray_cube_hit(Vec3::new(0.2, 0.3, 5.0), -Vec3::Z).unwrap();
+        assert_eq!(hit, Vec3::new(0.2, 0.3, 1.0));
+        assert_eq!(ray_cube_hit(Vec3::new(2.0, 0.0, 5.0), -Vec3::Z), None);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::xr::XrDriven;
+
+use bevy::{
+    ecs::prelude::*,
+    hierarchy::Parent,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    transform::components::{GlobalTransform, Transform},
+};
+
+/// Keeps the `LookTransform` of a camera that is the child of another entity, like a vehicle or a moving platform, in world
+/// space.
+///
+/// Without it, the `LookTransform` is written straight into the `Transform`, so it's relative to the parent and the camera
+/// moves rigidly with it. With it, the eye, target, [`LookAt`](crate::LookAt) and [`EyeAt`](crate::EyeAt) follow, and
+/// smoothing all happen in world space, and the smoothed view is converted into the parent's space in
+/// [`LookTransformSet::Finish`](crate::LookTransformSet::Finish). The parent's current `Transform`s are used, rather than its
+/// `GlobalTransform`, which is a frame behind until propagation, so the camera doesn't jitter while the parent moves.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct WorldSpaceLookTransform {
+    /// The world space and parent space `Transform`s from the last conversion.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    applied: Option<(Transform, Transform)>,
+}
+
+type Ancestors<'w, 's> =
+    Query<'w, 's, (&'static Transform, Option<&'static Parent>), Without<WorldSpaceLookTransform>>;
+
+/// Puts the world space `Transform` back in place of last frame's conversion, so a sleeping smoother and the offsets after
+/// it keep working in world space.
+pub fn restore_world_space_system(
+    mut cameras: Query<(&mut WorldSpaceLookTransform, &mut Transform), Without<XrDriven>>,
+) {
+    for (mut world_space, mut transform) in cameras.iter_mut() {
+        match world_space.applied {
+            Some((world, local)) if *transform == local => {
+                // Only a real change should trigger propagation, which the conversion decides.
+                *transform.bypass_change_detection() = world;
+            }
+            _ => world_space.applied = None,
+        }
+    }
+}
+
+/// Converts the world space `Transform` of each [`WorldSpaceLookTransform`] camera into its parent's space.
+pub fn world_space_to_parent_system(
+    mut cameras: Query<(&mut WorldSpaceLookTransform, &Parent, &mut Transform), Without<XrDriven>>,
+    ancestors: Ancestors,
+) {
+    for (mut world_space, parent, mut transform) in cameras.iter_mut() {
+        let world = *transform;
+        let local =
+            GlobalTransform::from(world).reparented_to(&global_transform(parent, &ancestors));
+        if world_space.applied == Some((world, local)) {
+            // Neither the camera nor its parent moved.
+            *transform.bypass_change_detection() = local;
+        } else {
+            *transform = local;
+        }
+        world_space.applied = Some((world, local));
+    }
+}
+
+/// The up-to-date `GlobalTransform` of `entity`, from the `Transform`s of it and its ancestors.
+fn global_transform(entity: &Parent, ancestors: &Ancestors) -> GlobalTransform {
+    let mut global = GlobalTransform::IDENTITY;
+    let mut next = Some(entity.get());
+    while let Some((transform, parent)) = next.and_then(|e| ancestors.get(e).ok()) {
+        global = GlobalTransform::from(*transform) * global;
+        next = parent.map(Parent::get);
+    }
+    global
+}
@@ -0,0 +1,67 @@
+use crate::{controllers::AnyController, LookTransform, Smoother};
+
+use bevy::{
+    ecs::prelude::*,
+    hierarchy::Parent,
+    prelude::ReflectDefault,
+    reflect::Reflect,
+    transform::components::{GlobalTransform, Transform},
+};
+
+/// Marks a camera whose pose comes from a VR/XR headset. The XR plugin owns the `Transform`, and the `LookTransform` is
+/// derived from it every frame instead of the other way around: the eye is the headset position, and the target is
+/// `focus_distance` in front of it.
+///
+/// Anything that reads the `LookTransform`, like bookmarks and recording, keeps working. But nothing else may move the
+/// view, since motion the player's head didn't make causes motion sickness: the camera's controllers and `Smoother` are
+/// disabled, writes to its `LookTransform` (including from following and constraints) are overwritten, and the
+/// `Transform` effects like occlusion pull-in and head bob are skipped.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct XrDriven {
+    /// How far in front of the eye the derived target is, in world units.
+    pub focus_distance: f32,
+}
+
+impl Default for XrDriven {
+    fn default() -> Self {
+        Self {
+            focus_distance: 1.0,
+        }
+    }
+}
+
+/// The `LookTransform` of a headset at `pose` in world space.
+pub fn look_transform_from_pose(pose: &GlobalTransform, focus_distance: f32) -> LookTransform {
+    let eye = pose.translation();
+    LookTransform::new(eye, eye + focus_distance * pose.forward(), pose.up().into())
+}
+
+/// Copies the headset pose of each [`XrDriven`] camera into its `LookTransform`, in place of smoothing it into the
+/// `Transform`.
+pub fn xr_driven_system(
+    mut cameras: Query<(
+        &XrDriven,
+        &Transform,
+        Option<&Parent>,
+        &mut LookTransform,
+        AnyController,
+    )>,
+    parents: Query<&GlobalTransform>,
+    mut smoothers: Query<&mut Smoother, With<XrDriven>>,
+) {
+    for (xr, transform, parent, mut look_transform, mut controllers) in cameras.iter_mut() {
+        // The XR plugin poses the camera relative to its tracking space, if it has one.
+        let parent_pose = parent
+            .and_then(|p| parents.get(p.get()).ok())
+            .copied()
+            .unwrap_or_default();
+        let pose = parent_pose.mul_transform(*transform);
+        look_transform.set_if_neq(look_transform_from_pose(&pose, xr.focus_distance));
+        controllers.set_enabled(false);
+    }
+    for mut smoother in smoothers.iter_mut().filter(|s| s.is_enabled()) {
+        smoother.set_enabled(false);
+    }
+}